@@ -1,6 +1,12 @@
 use std::{
-    collections::VecDeque,
-    time::Instant,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use dioxus::prelude::*;
@@ -8,10 +14,15 @@ use freya_elements::{
     self as dioxus_elements,
     events::{
         touch::TouchPhase,
+        PinchData,
+        PinchEvent,
+        TouchData,
         TouchEvent,
     },
 };
 use futures_util::StreamExt;
+use tokio::time::sleep;
+use torin::geometry::CursorPoint;
 
 /// Distance between the first tap and the second tap in [`Gesture::DoubleTap`] gesture.
 const DOUBLE_TAP_DISTANCE: f64 = 100.0;
@@ -25,12 +36,95 @@ const DOUBLE_TAP_MIN: u128 = 40; // 40ms
 /// In-memory events queue maximum size.
 const MAX_EVENTS_QUEUE: usize = 20;
 
+/// Time after which a finger that stopped moving is considered to be released without any velocity.
+const VELOCITY_TIMEOUT: u128 = 100; // 100ms
+
+/// Weight of the previous velocity when smoothing the velocity of a finger.
+const VELOCITY_SMOOTHING: f64 = 0.3;
+
+/// Phase of a continuous gesture such as [`Gesture::Pinch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GesturePhase {
+    Started,
+    Moved,
+    Ended,
+}
+
+/// Direction of a [`Gesture::Swipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
 /// Gesture emitted by the [`GestureArea`] component.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Coordinates are relative to the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Gesture {
     TapUp,
     TapDown,
     DoubleTap,
+    /// A finger was held down without moving for [`GestureThresholds::long_press_duration`].
+    LongPress,
+    /// A single finger is being dragged.
+    Pan {
+        phase: GesturePhase,
+        /// Distance travelled since the finger was pressed.
+        translation: CursorPoint,
+        /// Velocity of the finger in pixels per second.
+        velocity: CursorPoint,
+    },
+    /// A single finger was quickly dragged and released in a direction.
+    Swipe(SwipeDirection),
+    /// Two fingers are moving closer or apart, or the touchpad is being pinched.
+    Pinch {
+        phase: GesturePhase,
+        /// Scale since the gesture started, e.g `2.0` when the fingers are twice as far apart.
+        scale: f64,
+        /// Point between the two fingers, or the cursor for touchpads.
+        focal_point: CursorPoint,
+    },
+    /// Two fingers are rotating around each other.
+    Rotate {
+        phase: GesturePhase,
+        /// Clockwise rotation in degrees since the gesture started.
+        rotation: f64,
+        /// Point between the two fingers.
+        focal_point: CursorPoint,
+    },
+}
+
+/// Thresholds used by the [`GestureArea`] component to recognize gestures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureThresholds {
+    /// Maximum distance a finger can move while still being a tap or a long press, further movements start a [`Gesture::Pan`].
+    pub tap_slop: f64,
+    /// Time a finger must be held down without moving to emit a [`Gesture::LongPress`].
+    pub long_press_duration: Duration,
+    /// Minimum distance a finger must travel to emit a [`Gesture::Swipe`].
+    pub swipe_distance: f64,
+    /// Minimum velocity in pixels per second a finger must be released with to emit a [`Gesture::Swipe`].
+    pub swipe_velocity: f64,
+    /// Minimum scale change between two fingers to start a [`Gesture::Pinch`].
+    pub pinch_scale: f64,
+    /// Minimum angle in degrees between two fingers to start a [`Gesture::Rotate`].
+    pub rotation_angle: f64,
+}
+
+impl Default for GestureThresholds {
+    fn default() -> Self {
+        Self {
+            tap_slop: 10.0,
+            long_press_duration: Duration::from_millis(500),
+            swipe_distance: 50.0,
+            swipe_velocity: 300.0,
+            pinch_scale: 0.05,
+            rotation_angle: 10.0,
+        }
+    }
 }
 
 /// Properties for the [`GestureArea`] component.
@@ -40,11 +134,482 @@ pub struct GestureAreaProps {
     pub children: Element,
     /// Handler for the `ongesture` event.
     pub ongesture: EventHandler<Gesture>,
+    /// Thresholds used to recognize the gestures.
+    #[props(default)]
+    pub thresholds: GestureThresholds,
+}
+
+type EventsQueue = VecDeque<(Instant, TouchData)>;
+
+/// Input processed by the [`GestureRecognizer`].
+enum GestureInput {
+    Touch(TouchData),
+    Pinch(PinchData),
+    /// The long press timer of a finger pressed at `started_at` has finished.
+    LongPressTimeout {
+        finger_id: u64,
+        started_at: Instant,
+    },
+}
+
+/// Finger currently pressed.
+struct TrackedTouch {
+    started_at: Instant,
+    start: CursorPoint,
+    position: CursorPoint,
+    updated_at: Instant,
+    velocity: Option<CursorPoint>,
+    /// Whether it moved further than [`GestureThresholds::tap_slop`].
+    moved: bool,
+    /// Whether it was part of a two fingers gesture.
+    multi_touch: bool,
+}
+
+impl TrackedTouch {
+    fn new(time: Instant, position: CursorPoint) -> Self {
+        Self {
+            started_at: time,
+            start: position,
+            position,
+            updated_at: time,
+            velocity: None,
+            moved: false,
+            multi_touch: false,
+        }
+    }
+
+    fn update(&mut self, time: Instant, position: CursorPoint) {
+        let elapsed = time.duration_since(self.updated_at).as_secs_f64();
+        if elapsed > 0.0 {
+            let velocity = CursorPoint::new(
+                (position.x - self.position.x) / elapsed,
+                (position.y - self.position.y) / elapsed,
+            );
+            self.velocity = Some(match self.velocity {
+                Some(previous) => previous.lerp(velocity, 1.0 - VELOCITY_SMOOTHING),
+                None => velocity,
+            });
+        }
+        self.position = position;
+        self.updated_at = time;
+    }
+
+    fn translation(&self) -> CursorPoint {
+        CursorPoint::new(
+            self.position.x - self.start.x,
+            self.position.y - self.start.y,
+        )
+    }
+
+    /// Velocity of the finger if it was released at the given time.
+    fn release_velocity(&self, time: Instant) -> CursorPoint {
+        if time.duration_since(self.updated_at).as_millis() > VELOCITY_TIMEOUT {
+            CursorPoint::zero()
+        } else {
+            self.velocity.unwrap_or(CursorPoint::zero())
+        }
+    }
+}
+
+/// Pinch and rotation between two fingers.
+struct TwoFingersGesture {
+    fingers: (u64, u64),
+    initial_distance: f64,
+    initial_angle: f64,
+    scale: f64,
+    rotation: f64,
+    focal_point: CursorPoint,
+    pinching: bool,
+    rotating: bool,
+}
+
+/// Distance, angle in degrees and middle point between two fingers.
+fn measure_fingers(a: CursorPoint, b: CursorPoint) -> (f64, f64, CursorPoint) {
+    let distance = a.distance_to(b);
+    let angle = (b.y - a.y).atan2(b.x - a.x).to_degrees();
+    (distance, angle, a.lerp(b, 0.5))
+}
+
+/// Keep an angle in degrees between `-180` and `180`.
+fn normalize_angle(angle: f64) -> f64 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Recognize gestures out of the touch events received by a [`GestureArea`].
+#[derive(Default)]
+struct GestureRecognizer {
+    events: EventsQueue,
+    touches: HashMap<u64, TrackedTouch>,
+    panning: bool,
+    two_fingers: Option<TwoFingersGesture>,
+    touchpad_scale: f64,
 }
 
-type EventsQueue = VecDeque<(Instant, TouchEvent)>;
+impl GestureRecognizer {
+    fn process(
+        &mut self,
+        time: Instant,
+        input: GestureInput,
+        thresholds: &GestureThresholds,
+    ) -> Vec<Gesture> {
+        let mut gestures = Vec::new();
+
+        match input {
+            GestureInput::Touch(touch) => {
+                self.process_touch(time, touch, thresholds, &mut gestures)
+            }
+            GestureInput::Pinch(pinch) => self.process_pinch(pinch, &mut gestures),
+            GestureInput::LongPressTimeout {
+                finger_id,
+                started_at,
+            } => {
+                let is_still_pressed = self.touches.get(&finger_id).is_some_and(|touch| {
+                    touch.started_at == started_at && !touch.moved && !touch.multi_touch
+                });
+                if is_still_pressed && self.touches.len() == 1 {
+                    gestures.push(Gesture::LongPress);
+                }
+            }
+        }
+
+        gestures
+    }
+
+    fn process_touch(
+        &mut self,
+        time: Instant,
+        touch: TouchData,
+        thresholds: &GestureThresholds,
+        gestures: &mut Vec<Gesture>,
+    ) {
+        let finger_id = touch.get_finger_id();
+        let position = touch.get_screen_coordinates();
+        let phase = touch.get_touch_phase();
+
+        self.events.push_back((time, touch));
+
+        // Keep the touch events queue under a certain size
+        if self.events.len() > MAX_EVENTS_QUEUE {
+            self.events.pop_front();
+        }
+
+        match phase {
+            TouchPhase::Started => {
+                // TapDown
+                gestures.push(Gesture::TapDown);
+
+                // DoubleTap
+                if self.is_double_tap(time, position) {
+                    gestures.push(Gesture::DoubleTap);
+                }
+
+                // A second finger turns the pan into a two fingers gesture
+                if self.touches.len() == 1 {
+                    self.end_pan(time, false, gestures);
+                }
+
+                self.touches
+                    .insert(finger_id, TrackedTouch::new(time, position));
+
+                if self.touches.len() > 1 {
+                    for tracked in self.touches.values_mut() {
+                        tracked.multi_touch = true;
+                    }
+                    if self.two_fingers.is_none() {
+                        self.start_two_fingers();
+                    }
+                }
+            }
+            TouchPhase::Moved => {
+                let Some(tracked) = self.touches.get_mut(&finger_id) else {
+                    return;
+                };
+                tracked.update(time, position);
+                if tracked.start.distance_to(position) > thresholds.tap_slop {
+                    tracked.moved = true;
+                }
+
+                if self.two_fingers.is_some() {
+                    self.update_two_fingers(thresholds, gestures);
+                } else if self.touches.len() == 1 {
+                    self.update_pan(gestures);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let is_ended = phase == TouchPhase::Ended;
+
+                // TapUp
+                if is_ended {
+                    gestures.push(Gesture::TapUp);
+                }
+
+                let is_two_fingers = self.two_fingers.as_ref().is_some_and(|two_fingers| {
+                    two_fingers.fingers.0 == finger_id || two_fingers.fingers.1 == finger_id
+                });
+                if is_two_fingers {
+                    self.end_two_fingers(gestures);
+                } else if self.touches.len() == 1 {
+                    if let Some(tracked) = self
+                        .touches
+                        .get_mut(&finger_id)
+                        .filter(|tracked| tracked.position != position)
+                    {
+                        tracked.update(time, position);
+                    }
+                    self.end_pan(time, is_ended, gestures);
+                    if is_ended {
+                        self.detect_swipe(time, thresholds, gestures);
+                    }
+                }
+
+                self.touches.remove(&finger_id);
+            }
+        }
+    }
+
+    /// Find the first event with the `target_phase` that happened before the `start_time`
+    fn find_previous_event(
+        &self,
+        start_time: &Instant,
+        target_phase: TouchPhase,
+    ) -> Option<&(Instant, TouchData)> {
+        let mut start = false;
+        for event in self.events.iter().rev() {
+            if &event.0 == start_time {
+                start = true;
+                continue;
+            }
+            if event.1.phase == target_phase && start {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    fn is_double_tap(&self, time: Instant, position: CursorPoint) -> bool {
+        let last_ended_event = self.find_previous_event(&time, TouchPhase::Ended);
+        let last_started_event = self.find_previous_event(&time, TouchPhase::Started);
+
+        if let Some(((ended_time, ended_event), (started_time, _))) =
+            last_ended_event.zip(last_started_event)
+        {
+            // Has the latest `touchend` event went too far?
+            let is_ended_close =
+                position.distance_to(ended_event.get_screen_coordinates()) < DOUBLE_TAP_DISTANCE;
+            // Is the latest `touchend` mature enough?
+            let is_ended_mature = time.duration_since(*ended_time).as_millis() >= DOUBLE_TAP_MIN;
+
+            // Hast the latest `touchstart` event expired?
+            let is_started_recent =
+                time.duration_since(*started_time).as_millis() <= DOUBLE_TAP_TIMEOUT;
+
+            is_ended_close && is_ended_mature && is_started_recent
+        } else {
+            false
+        }
+    }
+
+    fn update_pan(&mut self, gestures: &mut Vec<Gesture>) {
+        let Some(tracked) = self.touches.values().next() else {
+            return;
+        };
+        if !tracked.moved || tracked.multi_touch {
+            return;
+        }
+
+        let phase = if self.panning {
+            GesturePhase::Moved
+        } else {
+            GesturePhase::Started
+        };
+        self.panning = true;
+
+        gestures.push(Gesture::Pan {
+            phase,
+            translation: tracked.translation(),
+            velocity: tracked.velocity.unwrap_or(CursorPoint::zero()),
+        });
+    }
+
+    fn end_pan(&mut self, time: Instant, is_released: bool, gestures: &mut Vec<Gesture>) {
+        if !self.panning {
+            return;
+        }
+        self.panning = false;
+
+        let Some(tracked) = self.touches.values().next() else {
+            return;
+        };
+
+        gestures.push(Gesture::Pan {
+            phase: GesturePhase::Ended,
+            translation: tracked.translation(),
+            velocity: if is_released {
+                tracked.release_velocity(time)
+            } else {
+                CursorPoint::zero()
+            },
+        });
+    }
+
+    fn detect_swipe(
+        &self,
+        time: Instant,
+        thresholds: &GestureThresholds,
+        gestures: &mut Vec<Gesture>,
+    ) {
+        let Some(tracked) = self.touches.values().next() else {
+            return;
+        };
+        if !tracked.moved || tracked.multi_touch {
+            return;
+        }
+
+        let translation = tracked.translation();
+        let velocity = tracked.release_velocity(time);
+
+        // Only the main axis of the movement is considered
+        let (distance, speed, direction) = if translation.x.abs() >= translation.y.abs() {
+            let direction = if translation.x > 0.0 {
+                SwipeDirection::Right
+            } else {
+                SwipeDirection::Left
+            };
+            (translation.x, velocity.x, direction)
+        } else {
+            let direction = if translation.y > 0.0 {
+                SwipeDirection::Down
+            } else {
+                SwipeDirection::Up
+            };
+            (translation.y, velocity.y, direction)
+        };
+
+        let is_far = distance.abs() >= thresholds.swipe_distance;
+        let is_fast =
+            speed.abs() >= thresholds.swipe_velocity && speed.signum() == distance.signum();
+
+        if is_far && is_fast {
+            gestures.push(Gesture::Swipe(direction));
+        }
+    }
+
+    fn start_two_fingers(&mut self) {
+        let mut fingers = self.touches.keys().copied().collect::<Vec<_>>();
+        fingers.sort();
+        let (Some(a), Some(b)) = (self.touches.get(&fingers[0]), self.touches.get(&fingers[1]))
+        else {
+            return;
+        };
+
+        let (distance, angle, focal_point) = measure_fingers(a.position, b.position);
+
+        self.two_fingers = Some(TwoFingersGesture {
+            fingers: (fingers[0], fingers[1]),
+            initial_distance: distance,
+            initial_angle: angle,
+            scale: 1.0,
+            rotation: 0.0,
+            focal_point,
+            pinching: false,
+            rotating: false,
+        });
+    }
+
+    fn update_two_fingers(&mut self, thresholds: &GestureThresholds, gestures: &mut Vec<Gesture>) {
+        let Some(two_fingers) = self.two_fingers.as_mut() else {
+            return;
+        };
+        let (Some(a), Some(b)) = (
+            self.touches.get(&two_fingers.fingers.0),
+            self.touches.get(&two_fingers.fingers.1),
+        ) else {
+            return;
+        };
+
+        let (distance, angle, focal_point) = measure_fingers(a.position, b.position);
+
+        two_fingers.focal_point = focal_point;
+        if two_fingers.initial_distance > 0.0 {
+            two_fingers.scale = distance / two_fingers.initial_distance;
+        }
+        two_fingers.rotation = normalize_angle(angle - two_fingers.initial_angle);
 
-/// Detect complex touch gestures such as [`Gesture::DoubleTap`].
+        // Pinch
+        if two_fingers.pinching || (two_fingers.scale - 1.0).abs() >= thresholds.pinch_scale {
+            let phase = if two_fingers.pinching {
+                GesturePhase::Moved
+            } else {
+                GesturePhase::Started
+            };
+            two_fingers.pinching = true;
+            gestures.push(Gesture::Pinch {
+                phase,
+                scale: two_fingers.scale,
+                focal_point,
+            });
+        }
+
+        // Rotate
+        if two_fingers.rotating || two_fingers.rotation.abs() >= thresholds.rotation_angle {
+            let phase = if two_fingers.rotating {
+                GesturePhase::Moved
+            } else {
+                GesturePhase::Started
+            };
+            two_fingers.rotating = true;
+            gestures.push(Gesture::Rotate {
+                phase,
+                rotation: two_fingers.rotation,
+                focal_point,
+            });
+        }
+    }
+
+    fn end_two_fingers(&mut self, gestures: &mut Vec<Gesture>) {
+        let Some(two_fingers) = self.two_fingers.take() else {
+            return;
+        };
+
+        if two_fingers.pinching {
+            gestures.push(Gesture::Pinch {
+                phase: GesturePhase::Ended,
+                scale: two_fingers.scale,
+                focal_point: two_fingers.focal_point,
+            });
+        }
+
+        if two_fingers.rotating {
+            gestures.push(Gesture::Rotate {
+                phase: GesturePhase::Ended,
+                rotation: two_fingers.rotation,
+                focal_point: two_fingers.focal_point,
+            });
+        }
+    }
+
+    fn process_pinch(&mut self, pinch: PinchData, gestures: &mut Vec<Gesture>) {
+        let phase = match pinch.get_pinch_phase() {
+            TouchPhase::Started => {
+                self.touchpad_scale = 1.0;
+                GesturePhase::Started
+            }
+            TouchPhase::Moved => GesturePhase::Moved,
+            TouchPhase::Ended | TouchPhase::Cancelled => GesturePhase::Ended,
+        };
+        self.touchpad_scale *= 1.0 + pinch.get_delta();
+
+        gestures.push(Gesture::Pinch {
+            phase,
+            scale: self.touchpad_scale,
+            focal_point: pinch.get_screen_coordinates(),
+        });
+    }
+}
+
+/// Detect complex touch gestures such as [`Gesture::DoubleTap`], [`Gesture::Pinch`] or [`Gesture::Swipe`].
+///
+/// Touchpad pinches are also reported as [`Gesture::Pinch`].
 ///
 /// # Example
 ///
@@ -64,99 +629,70 @@ type EventsQueue = VecDeque<(Instant, TouchEvent)>;
 /// ```
 #[allow(non_snake_case)]
 pub fn GestureArea(props: GestureAreaProps) -> Element {
-    let event_emitter = use_coroutine(
-        move |mut rx: UnboundedReceiver<(Instant, TouchEvent)>| async move {
-            let mut touch_events = VecDeque::<(Instant, TouchEvent)>::new();
-
-            while let Some(new_event) = rx.next().await {
-                touch_events.push_back(new_event);
+    let thresholds = use_memo(use_reactive(&props.thresholds, |thresholds| thresholds));
 
-                // Keep the touch events queue under a certain size
-                if touch_events.len() > MAX_EVENTS_QUEUE {
-                    touch_events.pop_front();
-                }
+    let event_emitter = use_coroutine(
+        move |mut rx: UnboundedReceiver<(Instant, GestureInput)>| async move {
+            let mut recognizer = GestureRecognizer {
+                touchpad_scale: 1.0,
+                ..Default::default()
+            };
 
-                // Find the first event with the `target_phase` that happened before the `start_time`
-                let find_previous_event = |start_time: &Instant,
-                                           events: &EventsQueue,
-                                           target_phase: TouchPhase|
-                 -> Option<(Instant, TouchEvent)> {
-                    let mut start = false;
-                    for (time, event) in events.iter().rev() {
-                        if time == start_time {
-                            start = true;
-                            continue;
-                        }
-                        if event.phase == target_phase && start {
-                            return Some((*time, event.clone()));
-                        }
-                    }
-                    None
-                };
+            while let Some((time, input)) = rx.next().await {
+                let gestures = recognizer.process(time, input, &thresholds.peek());
 
-                // Process the most recent event
-                let event = touch_events.iter().last();
-
-                if let Some((time, event)) = event {
-                    let phase = event.get_touch_phase();
-
-                    match phase {
-                        TouchPhase::Started => {
-                            // TapDown
-                            props.ongesture.call(Gesture::TapDown);
-
-                            let last_ended_event =
-                                find_previous_event(time, &touch_events, TouchPhase::Ended);
-                            let last_started_event =
-                                find_previous_event(time, &touch_events, TouchPhase::Started);
-
-                            // DoubleTap
-                            if let Some(((ended_time, ended_event), (started_time, _))) =
-                                last_ended_event.zip(last_started_event)
-                            {
-                                // Has the latest `touchend` event went too far?
-                                let is_ended_close = event
-                                    .get_screen_coordinates()
-                                    .distance_to(ended_event.get_screen_coordinates())
-                                    < DOUBLE_TAP_DISTANCE;
-                                // Is the latest `touchend` mature enough?
-                                let is_ended_mature =
-                                    ended_time.elapsed().as_millis() >= DOUBLE_TAP_MIN;
-
-                                // Hast the latest `touchstart` event expired?
-                                let is_started_recent =
-                                    started_time.elapsed().as_millis() <= DOUBLE_TAP_TIMEOUT;
-
-                                if is_ended_close && is_ended_mature && is_started_recent {
-                                    props.ongesture.call(Gesture::DoubleTap);
-                                }
-                            }
-                        }
-                        TouchPhase::Ended => {
-                            // TapUp
-                            props.ongesture.call(Gesture::TapUp);
-                        }
-                        _ => {}
-                    }
+                for gesture in gestures {
+                    props.ongesture.call(gesture);
                 }
             }
         },
     );
 
     let ontouchcancel = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((
+            Instant::now(),
+            GestureInput::Touch(e.data().as_ref().clone()),
+        ));
     };
 
     let ontouchend = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((
+            Instant::now(),
+            GestureInput::Touch(e.data().as_ref().clone()),
+        ));
     };
 
     let ontouchmove = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((
+            Instant::now(),
+            GestureInput::Touch(e.data().as_ref().clone()),
+        ));
     };
 
     let ontouchstart = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        let started_at = Instant::now();
+        let finger_id = e.get_finger_id();
+        event_emitter.send((started_at, GestureInput::Touch(e.data().as_ref().clone())));
+
+        // LongPress
+        let long_press_duration = thresholds.peek().long_press_duration;
+        spawn(async move {
+            sleep(long_press_duration).await;
+            event_emitter.send((
+                Instant::now(),
+                GestureInput::LongPressTimeout {
+                    finger_id,
+                    started_at,
+                },
+            ));
+        });
+    };
+
+    let onpinch = move |e: PinchEvent| {
+        event_emitter.send((
+            Instant::now(),
+            GestureInput::Pinch(e.data().as_ref().clone()),
+        ));
     };
 
     rsx!(
@@ -165,6 +701,7 @@ pub fn GestureArea(props: GestureAreaProps) -> Element {
             ontouchend: ontouchend,
             ontouchmove: ontouchmove,
             ontouchstart: ontouchstart,
+            onpinch: onpinch,
             {props.children}
         }
     )
@@ -180,6 +717,55 @@ mod test {
 
     use crate::gesture_area::DOUBLE_TAP_MIN;
 
+    #[component]
+    fn GesturesRecorder(thresholds: GestureThresholds) -> Element {
+        let mut value = use_signal(|| "EMPTY".to_string());
+
+        let ongesture = move |e: Gesture| {
+            let text = match e {
+                Gesture::Pan {
+                    phase, translation, ..
+                } => format!("Pan {phase:?} {} {}", translation.x, translation.y),
+                Gesture::Pinch { phase, scale, .. } => format!("Pinch {phase:?} {scale:.2}"),
+                Gesture::Rotate {
+                    phase, rotation, ..
+                } => format!("Rotate {phase:?} {rotation:.0}"),
+                e => format!("{e:?}"),
+            };
+            value.set(text);
+        };
+
+        rsx!(
+            GestureArea {
+                ongesture,
+                thresholds,
+                rect {
+                    width: "100%",
+                    height: "100%",
+                }
+            }
+            label {
+                "{value}"
+            }
+        )
+    }
+
+    fn touch(utils: &mut TestingHandler, location: (f64, f64), phase: TouchPhase, finger_id: u64) {
+        let name = match phase {
+            TouchPhase::Started => TouchEventName::TouchStart,
+            TouchPhase::Moved => TouchEventName::TouchMove,
+            TouchPhase::Ended => TouchEventName::TouchEnd,
+            TouchPhase::Cancelled => TouchEventName::TouchCancel,
+        };
+        utils.push_event(TestEvent::Touch {
+            name,
+            location: location.into(),
+            phase,
+            finger_id,
+            force: None,
+        });
+    }
+
     /// This test simulates a `DoubleTap` gesture in this order:
     /// 1. Touch start
     /// 2. Touch end
@@ -309,4 +895,158 @@ mod test {
 
         assert_eq!(utils.root().get(1).get(0).text(), Some("TapUp"));
     }
+
+    /// Moves two fingers apart and closer.
+    #[tokio::test]
+    pub async fn pinch() {
+        let mut utils = launch_test(|| {
+            rsx!(GesturesRecorder {
+                thresholds: GestureThresholds::default()
+            })
+        });
+        utils.wait_for_update().await;
+
+        touch(&mut utils, (100.0, 100.0), TouchPhase::Started, 0);
+        touch(&mut utils, (200.0, 100.0), TouchPhase::Started, 1);
+        touch(&mut utils, (300.0, 100.0), TouchPhase::Moved, 1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("Pinch Started 2.00")
+        );
+
+        touch(&mut utils, (250.0, 100.0), TouchPhase::Moved, 1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Pinch Moved 1.50"));
+
+        touch(&mut utils, (250.0, 100.0), TouchPhase::Ended, 1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Pinch Ended 1.50"));
+
+        // The remaining finger does not start a pan
+        touch(&mut utils, (300.0, 300.0), TouchPhase::Moved, 0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Pinch Ended 1.50"));
+    }
+
+    /// Rotates a finger around another one.
+    #[tokio::test]
+    pub async fn rotate() {
+        let mut utils = launch_test(|| {
+            rsx!(GesturesRecorder {
+                thresholds: GestureThresholds::default()
+            })
+        });
+        utils.wait_for_update().await;
+
+        touch(&mut utils, (200.0, 200.0), TouchPhase::Started, 0);
+        touch(&mut utils, (300.0, 200.0), TouchPhase::Started, 1);
+        touch(&mut utils, (200.0, 300.0), TouchPhase::Moved, 1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Rotate Started 90"));
+
+        touch(&mut utils, (200.0, 300.0), TouchPhase::Cancelled, 1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Rotate Ended 90"));
+    }
+
+    /// Drags a finger and quickly releases it.
+    #[tokio::test]
+    pub async fn pan_and_swipe() {
+        let mut utils = launch_test(|| {
+            rsx!(GesturesRecorder {
+                thresholds: GestureThresholds::default()
+            })
+        });
+        utils.wait_for_update().await;
+
+        touch(&mut utils, (100.0, 100.0), TouchPhase::Started, 0);
+        touch(&mut utils, (105.0, 100.0), TouchPhase::Moved, 0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Still within the tap slop
+        assert_eq!(utils.root().get(1).get(0).text(), Some("TapDown"));
+
+        touch(&mut utils, (300.0, 110.0), TouchPhase::Moved, 0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("Pan Started 200 10")
+        );
+
+        touch(&mut utils, (300.0, 110.0), TouchPhase::Ended, 0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Swipe(Right)"));
+    }
+
+    /// Holds a finger down without moving it.
+    #[tokio::test]
+    pub async fn long_press() {
+        let mut utils = launch_test(|| {
+            rsx!(GesturesRecorder {
+                thresholds: GestureThresholds {
+                    long_press_duration: Duration::from_millis(50),
+                    ..Default::default()
+                }
+            })
+        });
+        utils.wait_for_update().await;
+
+        touch(&mut utils, (100.0, 100.0), TouchPhase::Started, 0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("TapDown"));
+
+        sleep(Duration::from_millis(60)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("LongPress"));
+    }
+
+    /// Pinches the touchpad.
+    #[tokio::test]
+    pub async fn touchpad_pinch() {
+        let mut utils = launch_test(|| {
+            rsx!(GesturesRecorder {
+                thresholds: GestureThresholds::default()
+            })
+        });
+        utils.wait_for_update().await;
+
+        for (delta, phase) in [
+            (0.0, TouchPhase::Started),
+            (0.5, TouchPhase::Moved),
+            (0.0, TouchPhase::Ended),
+        ] {
+            utils.push_event(TestEvent::Pinch {
+                name: PinchEventName::Pinch,
+                cursor: (100.0, 100.0).into(),
+                delta,
+                phase,
+            });
+        }
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Pinch Ended 1.50"));
+    }
 }
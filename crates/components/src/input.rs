@@ -14,6 +14,7 @@ use freya_elements::{
     self as dioxus_elements,
     events::{
         keyboard::Key,
+        ImeEvent,
         KeyboardData,
         MouseEvent,
     },
@@ -259,6 +260,11 @@ pub fn Input(
         editable.process_event(&EditableEvent::KeyUp(e.data));
     };
 
+    let onime = move |e: ImeEvent| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::Ime(e.data));
+    };
+
     let oninputmousedown = move |e: MouseEvent| {
         if !display_placeholder {
             editable.process_event(&EditableEvent::MouseDown(e.data, 0));
@@ -323,11 +329,15 @@ pub fn Input(
     let a11y_id = focus.attribute();
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
+    let preedit = editable.preedit_attr(0);
 
     let (background, cursor_char) = if focus.is_focused() {
         (
             hover_background,
-            editable.editor().read().cursor_pos().to_string(),
+            editable
+                .cursor_pos_with_preedit(0)
+                .unwrap_or_default()
+                .to_string(),
         )
     } else {
         (background, "none".to_string())
@@ -347,6 +357,9 @@ pub fn Input(
     let text = match (mode, &*placeholder) {
         (_, Some(placeholder)) if display_placeholder => Cow::Borrowed(placeholder.as_str()),
         (InputMode::Hidden(ch), _) => Cow::Owned(ch.to_string().repeat(value.len())),
+        (InputMode::Shown, _) if editable.preedit().read().is_some() => {
+            Cow::Owned(editable.text_with_preedit(0, &value))
+        }
         (InputMode::Shown, _) => Cow::Borrowed(value.as_str()),
     };
 
@@ -367,6 +380,7 @@ pub fn Input(
             a11y_value: "{text}",
             onkeydown,
             onkeyup,
+            onime,
             overflow: "clip",
             onmousedown: oninputmousedown,
             onmouseenter,
@@ -388,6 +402,7 @@ pub fn Input(
                     cursor_color: "{color}",
                     max_lines: "1",
                     highlights,
+                    preedit,
                     text {
                         "{text}"
                     }
@@ -450,6 +465,74 @@ mod test {
         assert_eq!(text.get(0).text(), Some("Hello, World"));
     }
 
+    #[tokio::test]
+    pub async fn ime_composition() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "Hello, ".to_string());
+
+            rsx!(Input {
+                value,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Compose "ni"
+        utils.push_event(TestEvent::Ime {
+            name: ImeEventName::Ime,
+            data: ImeData::Preedit {
+                text: "ni".to_string(),
+                cursor: Some((2, 2)),
+            },
+        });
+        utils.wait_for_update().await;
+
+        // The composed text is shown but not part of the value yet
+        assert_eq!(text.get(0).text(), Some("Hello, ni"));
+
+        // Keys are handled by the IME while composing
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("i".to_string()),
+            code: Code::KeyI,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(text.get(0).text(), Some("Hello, ni"));
+
+        // Commit "に"
+        utils.push_event(TestEvent::Ime {
+            name: ImeEventName::Ime,
+            data: ImeData::Commit("に".to_string()),
+        });
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("に".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(text.get(0).text(), Some("Hello, に"));
+    }
+
     #[tokio::test]
     pub async fn validate() {
         fn input_app() -> Element {
@@ -20,7 +20,9 @@ pub enum ScrollPosition {
     #[default]
     Start,
     End,
-    // Specific
+    /// Start of the item with the given index, only supported by the [VirtualScrollView](crate::VirtualScrollView).
+    /// Other scroll views ignore it and log a warning.
+    Item(usize),
 }
 
 #[derive(Default, PartialEq, Eq)]
@@ -92,7 +94,21 @@ impl ScrollController {
         self.layout
     }
 
+    /// Apply the pending scroll requests given the size of the scrollable content.
+    ///
+    /// [ScrollPosition::Item] requests can't be resolved without knowing where the items are, so they are ignored with a warning.
+    /// Use [ScrollController::use_apply_with_items] instead, as the [VirtualScrollView](crate::VirtualScrollView) does.
     pub fn use_apply(&mut self, width: f32, height: f32) {
+        self.use_apply_with_items(width, height, |_| None)
+    }
+
+    /// Same as [ScrollController::use_apply] but also resolving [ScrollPosition::Item] requests with the given offset of each item.
+    pub fn use_apply_with_items(
+        &mut self,
+        width: f32,
+        height: f32,
+        item_offset: impl Fn(usize) -> Option<f32>,
+    ) {
         let scope_id = current_scope_id().unwrap();
 
         if !self.requests_subscribers.peek().contains(&scope_id) {
@@ -149,6 +165,22 @@ impl ScrollController {
                     }
                     *self.x.write() = -width as i32;
                 }
+                ScrollRequest {
+                    position: ScrollPosition::Item(index),
+                    direction,
+                    ..
+                } => {
+                    if let Some(offset) = item_offset(*index) {
+                        match direction {
+                            ScrollDirection::Vertical => *self.y.write() = -offset as i32,
+                            ScrollDirection::Horizontal => *self.x.write() = -offset as i32,
+                        }
+                    } else {
+                        tracing::warn!(
+                            "Could not scroll to item {index}, only a VirtualScrollView can scroll to its existing items"
+                        );
+                    }
+                }
             }
 
             request.applied_by.insert(scope_id);
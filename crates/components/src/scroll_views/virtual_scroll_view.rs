@@ -16,6 +16,7 @@ use freya_hooks::{
     use_applied_theme,
     use_focus,
     use_node,
+    use_node_signal,
     ScrollBarThemeWith,
 };
 
@@ -56,7 +57,13 @@ pub struct VirtualScrollViewProps<
     /// Quantity of items in the VirtualScrollView.
     pub length: usize,
     /// Size of the items, height for vertical direction and width for horizontal.
+    /// Used as the estimated size of the items not measured yet if `variable_item_sizes` is enabled.
     pub item_size: f32,
+    /// Measure the items on their first render instead of assuming they all have `item_size`.
+    /// The measured sizes are forgotten when `length` or `builder_args` change, as items might have moved.
+    /// Default is `false`.
+    #[props(default = false, into)]
+    pub variable_item_sizes: bool,
     /// The item builder function.
     pub builder: Builder,
     /// The values for the item builder function.
@@ -94,6 +101,7 @@ impl<
             && self.padding == other.padding
             && self.length == other.length
             && self.item_size == other.item_size
+            && self.variable_item_sizes == other.variable_item_sizes
             && self.direction == other.direction
             && self.show_scrollbar == other.show_scrollbar
             && self.scroll_with_arrows == other.scroll_with_arrows
@@ -122,6 +130,155 @@ fn get_render_range(
     render_index_start as usize..(render_index_end as usize)
 }
 
+/// Sizes of the items of a [`VirtualScrollView`], cached by index once measured and estimated otherwise.
+///
+/// The differences between the measured and the estimated sizes are kept in a Fenwick tree,
+/// so measuring an item and getting the offset of an item are both `O(log n)`.
+struct ItemSizes<BuilderArgs> {
+    /// Length, estimated size and builder args of the items that were measured.
+    items: (usize, f32, Option<BuilderArgs>),
+    measured: Vec<Option<f32>>,
+    differences: Vec<f32>,
+}
+
+impl<BuilderArgs: PartialEq> ItemSizes<BuilderArgs> {
+    fn new(length: usize, estimated_size: f32, builder_args: Option<BuilderArgs>) -> Self {
+        Self {
+            items: (length, estimated_size, builder_args),
+            measured: Vec::new(),
+            differences: Vec::new(),
+        }
+    }
+
+    /// Whether the sizes were measured for the given items.
+    /// As they are cached by index, they are wrong once items are added, removed or changed.
+    fn is_for(
+        &self,
+        length: usize,
+        estimated_size: f32,
+        builder_args: &Option<BuilderArgs>,
+    ) -> bool {
+        self.items.0 == length && self.items.1 == estimated_size && self.items.2 == *builder_args
+    }
+
+    fn length(&self) -> usize {
+        self.items.0
+    }
+
+    fn size(&self, index: usize) -> f32 {
+        self.measured
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(self.items.1)
+    }
+
+    /// Cache the measured size of an item, returning its previous size if it changed.
+    fn measure(&mut self, index: usize, size: f32) -> Option<f32> {
+        let length = self.length();
+        if index >= length || self.measured.get(index).copied().flatten() == Some(size) {
+            return None;
+        }
+        if self.measured.is_empty() {
+            self.measured = vec![None; length];
+            self.differences = vec![0.; length];
+        }
+
+        let previous_size = self.size(index);
+        self.measured[index] = Some(size);
+
+        let difference = size - previous_size;
+        let mut node = index + 1;
+        while node <= length {
+            self.differences[node - 1] += difference;
+            node += node & node.wrapping_neg();
+        }
+
+        Some(previous_size)
+    }
+
+    /// Start offset of an item, or the end offset of the last one for `length`.
+    fn offset(&self, index: usize) -> f32 {
+        let index = index.min(self.length());
+        let mut offset = index as f32 * self.items.1;
+        let mut node = index.min(self.differences.len());
+        while node > 0 {
+            offset += self.differences[node - 1];
+            node &= node - 1;
+        }
+        offset
+    }
+
+    /// Amount of offsets, from the start of the first item to the end of the last one, that match the predicate.
+    /// As offsets only grow, the predicate must be true for all of them until it is false for the rest.
+    fn count_offsets(&self, predicate: impl Fn(f32) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.length() + 1);
+        while low < high {
+            let middle = (low + high) / 2;
+            if predicate(self.offset(middle)) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+}
+
+fn get_variable_render_range<BuilderArgs: PartialEq>(
+    viewport_size: f32,
+    scroll_position: f32,
+    item_sizes: &ItemSizes<BuilderArgs>,
+) -> Range<usize> {
+    let length = item_sizes.length();
+    let render_index_start = item_sizes
+        .count_offsets(|offset| offset <= -scroll_position)
+        .saturating_sub(1)
+        .min(length);
+    let render_index_end = item_sizes
+        .count_offsets(|offset| offset < -scroll_position + viewport_size)
+        .min(length);
+
+    render_index_start..render_index_end.max(render_index_start)
+}
+
+#[component]
+fn VirtualScrollViewItem(
+    index: usize,
+    is_vertical: bool,
+    onmeasure: EventHandler<(usize, f32)>,
+    children: Element,
+) -> Element {
+    let (reference, layout) = use_node_signal();
+
+    use_effect(move || {
+        let area = layout.read().area;
+        let size = if is_vertical {
+            area.height()
+        } else {
+            area.width()
+        };
+        if size > 0. {
+            onmeasure.call((index, size));
+        }
+    });
+
+    let (width, height) = if is_vertical {
+        ("fill", "auto")
+    } else {
+        ("auto", "fill")
+    };
+
+    rsx!(
+        rect {
+            reference,
+            width,
+            height,
+            {children}
+        }
+    )
+}
+
 /// One-direction scrollable area that dynamically builds and renders items based in their size and current available size,
 /// this is intended for apps using large sets of data that need good performance.
 ///
@@ -157,6 +314,29 @@ fn get_render_range(
 /// # }, (250., 250.).into(), "./images/gallery_virtual_scroll_view.png");
 /// ```
 ///
+/// # With variable item sizes
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(VirtualScrollView {
+///         length: 35,
+///         item_size: 40.0,
+///         variable_item_sizes: true,
+///         direction: "vertical",
+///         builder: move |i, _other_args: &Option<()>| {
+///             rsx! {
+///                 label {
+///                     key: "{i}",
+///                     height: "{20 + (i % 3) * 20}",
+///                     "Number {i}"
+///                 }
+///             }
+///         }
+///     })
+/// }
+/// ```
+///
 /// # With a Scroll Controller
 ///
 /// ```no_run
@@ -175,7 +355,7 @@ fn get_render_range(
 ///                     key: "{i}",
 ///                     height: "20",
 ///                     onclick: move |_| {
-///                          scroll_controller.scroll_to(ScrollPosition::Start, ScrollDirection::Vertical);
+///                          scroll_controller.scroll_to(ScrollPosition::Item(20), ScrollDirection::Vertical);
 ///                     },
 ///                     "Number {i}"
 ///                 }
@@ -202,6 +382,7 @@ pub fn VirtualScrollView<
         scrollbar_theme,
         length,
         item_size,
+        variable_item_sizes,
         builder,
         builder_args,
         direction,
@@ -221,13 +402,53 @@ pub fn VirtualScrollView<
     let (node_ref, size) = use_node();
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
+    let mut item_sizes = use_signal(|| ItemSizes::new(length, item_size, builder_args.clone()));
+
+    let is_vertical = direction == "vertical";
+
+    // The sizes are cached by index, so forget them when the items change
+    if variable_item_sizes && !item_sizes.peek().is_for(length, item_size, &builder_args) {
+        let _allow_write_in_component_body =
+            ::warnings::Allow::new(warnings::signal_write_in_component_body::ID);
+        item_sizes.set(ItemSizes::new(length, item_size, builder_args.clone()));
+    }
+
+    // Sizes of the items, only needed when they can vary
+    let sizes = variable_item_sizes.then(|| item_sizes.read());
+
+    let content_size = match &sizes {
+        Some(sizes) => sizes.offset(length),
+        None => item_size * length as f32,
+    };
 
-    let (inner_width, inner_height) = match direction.as_str() {
-        "vertical" => (size.inner.width, item_size * length as f32),
-        _ => (item_size * length as f32, size.inner.height),
+    let (inner_width, inner_height) = if is_vertical {
+        (size.inner.width, content_size)
+    } else {
+        (content_size, size.inner.height)
     };
 
-    scroll_controller.use_apply(inner_width, inner_height);
+    scroll_controller.use_apply_with_items(inner_width, inner_height, |index| {
+        let index = index.min(length);
+        match &sizes {
+            Some(sizes) => Some(sizes.offset(index)),
+            None => Some(item_size * index as f32),
+        }
+    });
+
+    // Cache the size of a measured item
+    let onmeasure = move |(index, size): (usize, f32)| {
+        let Some(previous_size) = item_sizes.write().measure(index, size) else {
+            return;
+        };
+
+        // Keep the visible items in place when an item that starts above them changes its size
+        let item_offset = item_sizes.peek().offset(index);
+        let mut scrolled = if is_vertical { scrolled_y } else { scrolled_x };
+        let scroll_position = -*scrolled.peek() as f32;
+        if item_offset < scroll_position {
+            *scrolled.write() -= (size - previous_size).round() as i32;
+        }
+    };
 
     let vertical_scrollbar_is_visible =
         is_scrollbar_visible(show_scrollbar, inner_height, size.area.height());
@@ -401,11 +622,14 @@ pub fn VirtualScrollView<
     };
 
     // Calculate from what to what items must be rendered
-    let render_range = get_render_range(viewport_size, scroll_position, item_size, length as f32);
+    let render_range = match &sizes {
+        Some(sizes) => get_variable_render_range(viewport_size, scroll_position, sizes),
+        None => get_render_range(viewport_size, scroll_position, item_size, length as f32),
+    };
 
     let children = if cache_elements {
         let children = use_memo(use_reactive(
-            &(render_range, builder_args),
+            &(render_range.clone(), builder_args),
             move |(render_range, builder_args)| {
                 render_range
                     .clone()
@@ -413,10 +637,31 @@ pub fn VirtualScrollView<
                     .collect::<Vec<Element>>()
             },
         ));
-        rsx!({ children.read().iter() })
+        children()
     } else {
-        let children = render_range.map(|i| (builder)(i, &builder_args));
+        render_range
+            .clone()
+            .map(|i| (builder)(i, &builder_args))
+            .collect::<Vec<Element>>()
+    };
+
+    let children = if variable_item_sizes {
+        // Wrap the items so they can be measured
+        let children = children
+            .into_iter()
+            .zip(render_range.clone())
+            .map(|(child, i)| {
+                rsx!(VirtualScrollViewItem {
+                    key: "{i}",
+                    index: i,
+                    is_vertical,
+                    onmeasure,
+                    {child}
+                })
+            });
         rsx!({ children })
+    } else {
+        rsx!({ children.into_iter() })
     };
 
     let is_scrolling_x = clicking_scrollbar
@@ -430,20 +675,29 @@ pub fn VirtualScrollView<
         .map(|f| f.0 == Axis::Y)
         .unwrap_or_default();
 
-    let (offset_x, offset_y) = match direction.as_str() {
-        "vertical" => {
+    let (offset_x, offset_y) = match (direction.as_str(), &sizes) {
+        ("vertical", Some(sizes)) => (
+            corrected_scrolled_x,
+            corrected_scrolled_y + sizes.offset(render_range.start),
+        ),
+        (_, Some(sizes)) => (
+            corrected_scrolled_x + sizes.offset(render_range.start),
+            corrected_scrolled_y,
+        ),
+        ("vertical", None) => {
             let offset_y_min = (-corrected_scrolled_y / item_size).floor() * item_size;
             let offset_y = -(-corrected_scrolled_y - offset_y_min);
 
             (corrected_scrolled_x, offset_y)
         }
-        _ => {
+        (_, None) => {
             let offset_x_min = (-corrected_scrolled_x / item_size).floor() * item_size;
             let offset_x = -(-corrected_scrolled_x - offset_x_min);
 
             (offset_x, corrected_scrolled_y)
         }
     };
+    drop(sizes);
     let a11y_id = focus.attribute();
 
     rsx!(
@@ -517,6 +771,11 @@ mod test {
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
+    use super::{
+        get_variable_render_range,
+        ItemSizes,
+    };
+
     #[tokio::test]
     pub async fn virtual_scroll_view_wheel() {
         fn virtual_scroll_view_wheel_app() -> Element {
@@ -704,4 +963,141 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_variable_sizes() {
+        fn virtual_scroll_view_variable_sizes_app() -> Element {
+            rsx!(VirtualScrollView {
+                length: 30,
+                item_size: 50.0,
+                variable_item_sizes: true,
+                direction: "vertical",
+                builder: move |index, _: &Option<()>| {
+                    let height = if index % 2 == 0 { 50 } else { 100 };
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "{height}",
+                            "{index}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_variable_sizes_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Once measured, only the items from 0 to 6 fit in the 500 pixels of the viewport
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+        for (n, i) in (0..7).enumerate() {
+            assert_eq!(
+                content.get(n).get(0).get(0).text(),
+                Some(i.to_string().as_str())
+            );
+        }
+
+        utils.push_event(TestEvent::Wheel {
+            name: WheelEventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // It has scrolled 300 pixels, which is where the item 4 starts
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+        for (n, i) in (4..11).enumerate() {
+            assert_eq!(
+                content.get(n).get(0).get(0).text(),
+                Some(i.to_string().as_str())
+            );
+        }
+    }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_scroll_to_item() {
+        fn virtual_scroll_view_scroll_to_item_app() -> Element {
+            let scroll_controller = use_hook(|| {
+                ScrollController::new(
+                    0,
+                    0,
+                    vec![ScrollRequest::new(
+                        ScrollPosition::Item(10),
+                        ScrollDirection::Vertical,
+                    )],
+                )
+            });
+
+            rsx!(VirtualScrollView {
+                scroll_controller,
+                length: 30,
+                item_size: 50.0,
+                variable_item_sizes: true,
+                direction: "vertical",
+                builder: move |index, _: &Option<()>| {
+                    let height = if index % 2 == 0 { 50 } else { 100 };
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "{height}",
+                            "{index}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_scroll_to_item_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The item 10 is the first visible item
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+        for (n, i) in (10..17).enumerate() {
+            assert_eq!(
+                content.get(n).get(0).get(0).text(),
+                Some(i.to_string().as_str())
+            );
+        }
+    }
+
+    #[test]
+    pub fn item_sizes() {
+        let mut item_sizes = ItemSizes::new(10, 50., Some(0));
+        assert_eq!(item_sizes.offset(10), 500.);
+
+        assert_eq!(item_sizes.measure(3, 100.), Some(50.));
+        assert_eq!(item_sizes.measure(3, 100.), None);
+        assert_eq!(item_sizes.measure(7, 20.), Some(50.));
+        assert_eq!(item_sizes.measure(10, 20.), None);
+
+        let offsets = (0..=10)
+            .map(|index| item_sizes.offset(index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            offsets,
+            vec![0., 50., 100., 150., 250., 300., 350., 400., 420., 470., 520.]
+        );
+
+        // Items 3 to 5 are visible from 200 to 350
+        assert_eq!(get_variable_render_range(150., -200., &item_sizes), 3..6);
+
+        // Adding, removing or changing items invalidates the cached sizes
+        assert!(item_sizes.is_for(10, 50., &Some(0)));
+        assert!(!item_sizes.is_for(11, 50., &Some(0)));
+        assert!(!item_sizes.is_for(10, 50., &Some(1)));
+    }
 }
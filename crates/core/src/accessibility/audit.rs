@@ -0,0 +1,360 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+};
+
+use accesskit::{
+    Action,
+    Role,
+};
+use freya_native_core::{
+    events::EventName,
+    node::NodeType,
+    real_dom::NodeImmutable,
+    tags::TagName,
+    NodeId,
+};
+
+use crate::{
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+    },
+    states::{
+        AccessibilityState,
+        FontStyleState,
+        StyleState,
+    },
+    types::AccessibilityId,
+    values::{
+        Color,
+        Fill,
+        FontWeight,
+    },
+};
+
+/// Minimum contrast ratio for normal text, as defined by WCAG AA.
+pub const MIN_TEXT_CONTRAST: f32 = 4.5;
+
+/// Minimum contrast ratio for large text, as defined by WCAG AA.
+pub const MIN_LARGE_TEXT_CONTRAST: f32 = 3.0;
+
+/// Events that make an element behave like a control.
+const ACTIVATION_EVENTS: [EventName; 2] = [EventName::Click, EventName::PointerPress];
+
+/// Every action that assistive technologies can request from a node.
+pub const ACCESSIBILITY_ACTIONS: [Action; 24] = [
+    Action::Click,
+    Action::Focus,
+    Action::Blur,
+    Action::Collapse,
+    Action::Expand,
+    Action::CustomAction,
+    Action::Decrement,
+    Action::Increment,
+    Action::HideTooltip,
+    Action::ShowTooltip,
+    Action::ReplaceSelectedText,
+    Action::ScrollBackward,
+    Action::ScrollDown,
+    Action::ScrollForward,
+    Action::ScrollLeft,
+    Action::ScrollRight,
+    Action::ScrollUp,
+    Action::ScrollIntoView,
+    Action::ScrollToPoint,
+    Action::SetScrollOffset,
+    Action::SetTextSelection,
+    Action::SetSequentialFocusNavigationStartingPoint,
+    Action::SetValue,
+    Action::ShowContextMenu,
+];
+
+/// Accessibility issue found by [`audit_accessibility`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccessibilityIssue {
+    /// The node can be focused but screen readers have nothing to announce.
+    FocusableWithoutName,
+    /// The node has the same `a11y_id` as an earlier node.
+    DuplicateId { first_node_id: NodeId },
+    /// The image has no `a11y_name` or `a11y_description` describing it.
+    ImageWithoutAlt,
+    /// The element reacts to clicks or presses but has no `a11y_role`, so it is announced as a plain container.
+    InteractiveWithoutRole,
+    /// The node has the role of a control but supports no action, so assistive technologies can't operate it.
+    InteractiveWithoutActions,
+    /// The text is hard to read over its background.
+    LowContrast {
+        foreground: Color,
+        background: Color,
+        ratio: f32,
+        min_ratio: f32,
+    },
+}
+
+impl Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FocusableWithoutName => f.write_str("Focusable node without a name"),
+            Self::DuplicateId { first_node_id } => write!(
+                f,
+                "Accessibility ID already used by node {}",
+                first_node_id.index()
+            ),
+            Self::ImageWithoutAlt => f.write_str("Image without an alternative text"),
+            Self::InteractiveWithoutRole => f.write_str("Interactive element without a role"),
+            Self::InteractiveWithoutActions => f.write_str("Interactive role without any action"),
+            Self::LowContrast {
+                foreground,
+                background,
+                ratio,
+                min_ratio,
+            } => write!(
+                f,
+                "Text contrast of {ratio:.2}:1 between rgb({}, {}, {}) and rgb({}, {}, {}) is below {min_ratio}:1",
+                foreground.r(),
+                foreground.g(),
+                foreground.b(),
+                background.r(),
+                background.g(),
+                background.b()
+            ),
+        }
+    }
+}
+
+/// An [`AccessibilityIssue`] and the node where it was found.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibilityFinding {
+    pub node_id: NodeId,
+    pub a11y_id: Option<AccessibilityId>,
+    pub issue: AccessibilityIssue,
+}
+
+impl Display for AccessibilityFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in node {}", self.issue, self.node_id.index())?;
+        if let Some(a11y_id) = self.a11y_id {
+            write!(f, " (a11y_id {})", a11y_id.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Look for common accessibility mistakes in the DOM, in the same order as its nodes.
+///
+/// Texts are checked against the backgrounds of their ancestors, painted over the given window background.
+pub fn audit_accessibility(
+    rdom: &DioxusDOM,
+    window_background: Color,
+) -> Vec<AccessibilityFinding> {
+    let mut findings = Vec::new();
+    let mut a11y_ids = HashMap::<AccessibilityId, NodeId>::new();
+
+    rdom.traverse_depth_first(|node| {
+        let Some(tag) = node.node_type().tag().copied() else {
+            return;
+        };
+        if tag == TagName::Root {
+            return;
+        }
+
+        let node_id = node.id();
+        let accessibility = node.get::<AccessibilityState>();
+        let a11y_id = accessibility
+            .as_ref()
+            .and_then(|accessibility| accessibility.a11y_id);
+        let mut report = |issue| {
+            findings.push(AccessibilityFinding {
+                node_id,
+                a11y_id,
+                issue,
+            })
+        };
+
+        if let Some(builder) = accessibility
+            .as_ref()
+            .and_then(|accessibility| accessibility.builder.as_ref())
+            .filter(|builder| !builder.is_hidden())
+        {
+            let accessibility = accessibility.as_ref().unwrap();
+
+            if accessibility.a11y_focusable.is_enabled() && !has_name(&node) {
+                report(AccessibilityIssue::FocusableWithoutName);
+            }
+
+            if tag == TagName::Image
+                && builder.label().is_none()
+                && builder.class_name().is_none()
+                && builder.description().is_none()
+            {
+                report(AccessibilityIssue::ImageWithoutAlt);
+            }
+
+            if tag == TagName::Rect
+                && builder.role() == Role::GenericContainer
+                && ACTIVATION_EVENTS
+                    .iter()
+                    .any(|event| rdom.is_node_listening(&node_id, event))
+            {
+                report(AccessibilityIssue::InteractiveWithoutRole);
+            }
+
+            // Focusable nodes get the focus action once they are added to the accessibility tree
+            if is_interactive(builder.role())
+                && !accessibility.a11y_focusable.is_enabled()
+                && !ACCESSIBILITY_ACTIONS
+                    .iter()
+                    .any(|action| builder.supports_action(*action))
+            {
+                report(AccessibilityIssue::InteractiveWithoutActions);
+            }
+        }
+
+        if let Some(a11y_id) = a11y_id {
+            if let Some(first_node_id) = a11y_ids.get(&a11y_id) {
+                report(AccessibilityIssue::DuplicateId {
+                    first_node_id: *first_node_id,
+                });
+            } else {
+                a11y_ids.insert(a11y_id, node_id);
+            }
+        }
+
+        if has_text_children(&node) {
+            let font_style = node.get::<FontStyleState>().unwrap();
+            if let Some(background) = background_color(&node, window_background) {
+                let foreground = blend(font_style.color, background);
+                let ratio = contrast_ratio(foreground, background);
+                let is_large = font_style.font_size >= 24.
+                    || (font_style.font_size >= 18.66
+                        && font_style.font_weight >= FontWeight::BOLD);
+                let min_ratio = if is_large {
+                    MIN_LARGE_TEXT_CONTRAST
+                } else {
+                    MIN_TEXT_CONTRAST
+                };
+                if ratio < min_ratio {
+                    report(AccessibilityIssue::LowContrast {
+                        foreground: font_style.color,
+                        background,
+                        ratio,
+                        min_ratio,
+                    });
+                }
+            }
+        }
+    });
+
+    findings
+}
+
+/// Contrast ratio between two opaque colors, from `1.0` to `21.0`, as defined by WCAG.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+fn relative_luminance(color: Color) -> f32 {
+    let channel = |value: u8| {
+        let value = value as f32 / 255.;
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(color.r()) + 0.7152 * channel(color.g()) + 0.0722 * channel(color.b())
+}
+
+/// Paint `color` over the opaque `background`.
+fn blend(color: Color, background: Color) -> Color {
+    let alpha = color.a() as f32 / 255.;
+    let channel =
+        |top: u8, bottom: u8| (top as f32 * alpha + bottom as f32 * (1. - alpha)).round() as u8;
+    Color::from_rgb(
+        channel(color.r(), background.r()),
+        channel(color.g(), background.g()),
+        channel(color.b(), background.b()),
+    )
+}
+
+/// Opaque color behind a node, blending the translucent backgrounds of its ancestors over the window background.
+/// Returns `None` if there is a gradient.
+fn background_color(node: &DioxusNode, window_background: Color) -> Option<Color> {
+    let mut layers = Vec::new();
+    let mut opaque = window_background.with_a(255);
+    let mut current = Some(*node);
+
+    while let Some(node) = current {
+        let style = node.get::<StyleState>().unwrap();
+        match &style.background {
+            Fill::Color(color) => {
+                let alpha = style
+                    .background_opacity
+                    .map(|opacity| (color.a() as u16 * opacity as u16 / 255) as u8)
+                    .unwrap_or(color.a());
+                if alpha == 255 {
+                    opaque = *color;
+                    break;
+                } else if alpha > 0 {
+                    layers.push(color.with_a(alpha));
+                }
+            }
+            _ => return None,
+        }
+        current = node.parent();
+    }
+
+    Some(
+        layers
+            .into_iter()
+            .rev()
+            .fold(opaque, |background, layer| blend(layer, background)),
+    )
+}
+
+fn is_interactive(role: Role) -> bool {
+    matches!(
+        role,
+        Role::Button
+            | Role::CheckBox
+            | Role::ComboBox
+            | Role::Link
+            | Role::MenuItem
+            | Role::MenuItemCheckBox
+            | Role::MenuItemRadio
+            | Role::RadioButton
+            | Role::Slider
+            | Role::SpinButton
+            | Role::Switch
+            | Role::Tab
+            | Role::TextInput
+    )
+}
+
+/// Whether any descendant text can describe the node, or it has a label, `a11y_name` or value.
+fn has_name(node: &DioxusNode) -> bool {
+    let has_own_name = node
+        .get::<AccessibilityState>()
+        .is_some_and(|accessibility| {
+            accessibility.builder.as_ref().is_some_and(|builder| {
+                builder.label().is_some()
+                    || builder.class_name().is_some()
+                    || builder.value().is_some()
+            })
+        });
+    has_own_name
+        || node
+            .children()
+            .iter()
+            .any(|child| has_text_children(child) || has_name(child))
+}
+
+fn has_text_children(node: &DioxusNode) -> bool {
+    node.children()
+        .iter()
+        .any(|child| matches!(&*child.node_type(), NodeType::Text(text) if !text.trim().is_empty()))
+}
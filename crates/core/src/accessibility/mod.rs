@@ -1,4 +1,6 @@
+mod audit;
 mod tree;
+pub use audit::*;
 use freya_native_core::{
     node::NodeType,
     real_dom::NodeImmutable,
@@ -470,11 +470,7 @@ impl AccessibilityTree {
         }
 
         // Rotation transform
-        if let Some((_, rotation)) = transform_state
-            .rotations
-            .iter()
-            .find(|(id, _)| id == &node_ref.id())
-        {
+        if let Some(rotation) = transform_state.rotation_of(node_ref.id()) {
             let rotation = rotation.to_radians() as f64;
             let (s, c) = rotation.sin_cos();
             builder.set_transform(Affine::new([c, s, -s, c, 0.0, 0.0]));
@@ -532,8 +528,12 @@ impl AccessibilityTree {
             TextAlign::Right | TextAlign::End => accesskit::TextAlign::Right,
         });
 
-        // TODO: Adjust this once text direction support other than RTL is properly added
-        builder.set_text_direction(TextDirection::LeftToRight);
+        // Text direction
+        builder.set_text_direction(if font_style_state.layout_direction.is_rtl() {
+            TextDirection::RightToLeft
+        } else {
+            TextDirection::LeftToRight
+        });
 
         // Set italic property for italic/oblique font slants
         match font_style_state.font_slant {
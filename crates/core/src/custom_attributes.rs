@@ -25,6 +25,8 @@ use torin::geometry::{
     Size2D,
 };
 
+use crate::dom::ImageDecodeStatus;
+
 /// Image Reference
 #[derive(Clone, Debug)]
 pub struct ImageReference(pub Arc<Mutex<Option<Bytes>>>);
@@ -41,6 +43,34 @@ impl Display for ImageReference {
     }
 }
 
+/// Image Status Reference, used by `use_image_status`.
+#[derive(Clone, Debug)]
+pub struct ImageStatusReference(pub Arc<watch::Sender<Option<ImageDecodeStatus>>>);
+
+impl ImageStatusReference {
+    /// Notify the new status, if it changed.
+    pub fn report(&self, status: ImageDecodeStatus) {
+        self.0.send_if_modified(|current| {
+            let changed = *current != Some(status);
+            *current = Some(status);
+            changed
+        });
+    }
+}
+
+impl PartialEq for ImageStatusReference {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Display for ImageStatusReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageStatusReference")
+            .finish_non_exhaustive()
+    }
+}
+
 /// Layout info of a certain Node, used by `use_node`.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct NodeReferenceLayout {
@@ -60,6 +90,7 @@ impl NodeReferenceLayout {
 pub enum CursorLayoutResponse {
     CursorPosition { position: usize, id: usize },
     TextSelection { from: usize, to: usize, id: usize },
+    CursorArea { area: Area, id: usize },
 }
 
 /// Node Reference
@@ -146,6 +177,7 @@ pub enum CustomAttributeValues {
     CursorReference(CursorReference),
     Bytes(AttributesBytes),
     ImageReference(ImageReference),
+    ImageStatus(ImageStatusReference),
     AccessibilityId(AccessibilityId),
     TextHighlights(Vec<(usize, usize)>),
     Canvas(CanvasReference),
@@ -158,6 +190,7 @@ impl Debug for CustomAttributeValues {
             Self::CursorReference(_) => f.debug_tuple("CursorReference").finish(),
             Self::Bytes(_) => f.debug_tuple("Bytes").finish(),
             Self::ImageReference(_) => f.debug_tuple("ImageReference").finish(),
+            Self::ImageStatus(_) => f.debug_tuple("ImageStatus").finish(),
             Self::AccessibilityId(_) => f.debug_tuple("AccessibilityId").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
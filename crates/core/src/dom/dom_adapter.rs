@@ -9,7 +9,10 @@ use torin::prelude::*;
 
 use crate::{
     dom::DioxusDOM,
-    states::LayoutState,
+    states::{
+        FontStyleState,
+        LayoutState,
+    },
 };
 
 /// RealDOM adapter for Torin.
@@ -40,6 +43,9 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
 
         let mut layout = node.get::<LayoutState>()?.clone();
 
+        // The layout direction is inherited so it lives along the other inherited text properties
+        let layout_direction = node.get::<FontStyleState>()?.layout_direction;
+
         // The root node expands by default
         if *node_id == self.rdom.root_id() {
             layout.width = Size::Percentage(Length::new(100.0));
@@ -67,6 +73,12 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             content: layout.content,
             contains_text,
             spacing: layout.spacing,
+            wrap_spacing: layout.wrap_spacing,
+            grid_columns: layout.grid_columns,
+            grid_rows: layout.grid_rows,
+            grid_column: layout.grid_column,
+            grid_row: layout.grid_row,
+            layout_direction,
         };
 
         node.scale_if_needed(self.scale_factor);
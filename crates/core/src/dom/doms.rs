@@ -15,18 +15,23 @@ use std::{
 
 use dioxus_core::VirtualDom;
 use freya_native_core::{
+    attributes::AttributeName,
+    node::OwnedAttributeValue,
     prelude::{
         DioxusState,
         State,
     },
     real_dom::{
         NodeRef,
+        NodeTypeMut,
         RealDom,
     },
     NodeId,
     SendAnyMap,
 };
+use rustc_hash::FxHashMap;
 use torin::prelude::*;
+use tracing::Dispatch;
 
 use super::{
     mutations_writer::MutationsWriter,
@@ -44,9 +49,14 @@ use crate::{
     elements::ParagraphElement,
     event_loop_messages::TextGroupMeasurement,
     layers::Layers,
+    profiling::{
+        render_counter,
+        FrameStats,
+    },
     render::{
         CompositorCache,
         CompositorDirtyArea,
+        LayerCache,
     },
     states::{
         AccessibilityState,
@@ -67,6 +77,12 @@ use crate::{
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
 pub type DioxusNode<'a> = NodeRef<'a, CustomAttributeValues>;
 
+pub type DioxusAttributeValue = OwnedAttributeValue<CustomAttributeValues>;
+
+/// Original values of the overridden attributes, see [FreyaDOM::override_attribute].
+pub(crate) type AttributeOverrides =
+    FxHashMap<NodeId, FxHashMap<AttributeName, Option<DioxusAttributeValue>>>;
+
 /// Tiny wrapper over [FreyaDOM] to make it thread-safe if desired.
 /// This is primarily used by the Devtools and Testing renderer.
 pub struct SafeDOM {
@@ -143,7 +159,11 @@ pub struct FreyaDOM {
     accessibility_dirty_nodes: Arc<Mutex<AccessibilityDirtyNodes>>,
     accessibility_generator: Arc<AccessibilityGenerator>,
     images_cache: Arc<Mutex<ImagesCache>>,
+    layer_cache: Arc<Mutex<LayerCache>>,
+    frame_stats: Arc<Mutex<FrameStats>>,
+    render_counter: Option<Dispatch>,
     animation_clock: AnimationClock,
+    attribute_overrides: AttributeOverrides,
 }
 
 impl Default for FreyaDOM {
@@ -175,7 +195,11 @@ impl Default for FreyaDOM {
             accessibility_dirty_nodes: Arc::default(),
             accessibility_generator: Arc::default(),
             images_cache: Arc::default(),
+            layer_cache: Arc::default(),
+            frame_stats: Arc::default(),
+            render_counter: None,
             animation_clock: AnimationClock::default(),
+            attribute_overrides: FxHashMap::default(),
         }
     }
 }
@@ -217,6 +241,32 @@ impl FreyaDOM {
         self.images_cache.lock().unwrap()
     }
 
+    pub fn layer_cache(&self) -> MutexGuard<LayerCache> {
+        self.layer_cache.lock().unwrap()
+    }
+
+    /// Work done since the stats were last taken, see [FrameStats].
+    pub fn frame_stats(&self) -> MutexGuard<FrameStats> {
+        self.frame_stats.lock().unwrap()
+    }
+
+    /// Count the component renders in the [FrameStats], which requires tracing the VirtualDOM renders.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.render_counter = enabled.then(|| render_counter(self.frame_stats.clone()));
+    }
+
+    pub fn is_profiling(&self) -> bool {
+        self.render_counter.is_some()
+    }
+
+    /// Run a VirtualDOM render, counting its component renders if profiling.
+    fn count_renders(render_counter: Option<Dispatch>, render: impl FnOnce()) {
+        match render_counter {
+            Some(render_counter) => tracing::dispatcher::with_default(&render_counter, render),
+            None => render(),
+        }
+    }
+
     pub fn animation_clock(&self) -> &AnimationClock {
         &self.animation_clock
     }
@@ -224,19 +274,23 @@ impl FreyaDOM {
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         // Build the RealDOM
-        vdom.rebuild(&mut MutationsWriter {
-            native_writer: self
-                .dioxus_integration_state
-                .create_mutation_writer(&mut self.rdom),
-            layout: &mut self.torin.lock().unwrap(),
-            layers: &mut self.layers.lock().unwrap(),
-            paragraphs: &mut self.paragraphs.lock().unwrap(),
-            scale_factor,
-            compositor_dirty_nodes: &mut self.compositor_dirty_nodes.lock().unwrap(),
-            compositor_dirty_area: &mut self.compositor_dirty_area.lock().unwrap(),
-            compositor_cache: &mut self.compositor_cache.lock().unwrap(),
-            accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
-            images_cache: &mut self.images_cache.lock().unwrap(),
+        let render_counter = self.render_counter.clone();
+        Self::count_renders(render_counter, || {
+            vdom.rebuild(&mut MutationsWriter {
+                native_writer: self
+                    .dioxus_integration_state
+                    .create_mutation_writer(&mut self.rdom),
+                layout: &mut self.torin.lock().unwrap(),
+                layers: &mut self.layers.lock().unwrap(),
+                paragraphs: &mut self.paragraphs.lock().unwrap(),
+                scale_factor,
+                compositor_dirty_nodes: &mut self.compositor_dirty_nodes.lock().unwrap(),
+                compositor_dirty_area: &mut self.compositor_dirty_area.lock().unwrap(),
+                compositor_cache: &mut self.compositor_cache.lock().unwrap(),
+                accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
+                images_cache: &mut self.images_cache.lock().unwrap(),
+                attribute_overrides: &mut self.attribute_overrides,
+            })
         });
 
         let mut ctx = SendAnyMap::new();
@@ -255,22 +309,30 @@ impl FreyaDOM {
     /// Process the given mutations from the [`VirtualDOM`](dioxus_core::VirtualDom).
     pub fn render_mutations(&mut self, vdom: &mut VirtualDom, scale_factor: f32) -> (bool, bool) {
         // Update the RealDOM
-        vdom.render_immediate(&mut MutationsWriter {
-            native_writer: self
-                .dioxus_integration_state
-                .create_mutation_writer(&mut self.rdom),
-            layout: &mut self.torin.lock().unwrap(),
-            layers: &mut self.layers.lock().unwrap(),
-            paragraphs: &mut self.paragraphs.lock().unwrap(),
-            scale_factor,
-            compositor_dirty_nodes: &mut self.compositor_dirty_nodes.lock().unwrap(),
-            compositor_dirty_area: &mut self.compositor_dirty_area.lock().unwrap(),
-            compositor_cache: &mut self.compositor_cache.lock().unwrap(),
-            accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
-            images_cache: &mut self.images_cache.lock().unwrap(),
+        let render_counter = self.render_counter.clone();
+        Self::count_renders(render_counter, || {
+            vdom.render_immediate(&mut MutationsWriter {
+                native_writer: self
+                    .dioxus_integration_state
+                    .create_mutation_writer(&mut self.rdom),
+                layout: &mut self.torin.lock().unwrap(),
+                layers: &mut self.layers.lock().unwrap(),
+                paragraphs: &mut self.paragraphs.lock().unwrap(),
+                scale_factor,
+                compositor_dirty_nodes: &mut self.compositor_dirty_nodes.lock().unwrap(),
+                compositor_dirty_area: &mut self.compositor_dirty_area.lock().unwrap(),
+                compositor_cache: &mut self.compositor_cache.lock().unwrap(),
+                accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
+                images_cache: &mut self.images_cache.lock().unwrap(),
+                attribute_overrides: &mut self.attribute_overrides,
+            })
         });
 
-        // Update the Nodes states
+        self.update_states()
+    }
+
+    /// Update the states of the nodes that changed. Returns whether it must repaint and relayout.
+    fn update_states(&mut self) -> (bool, bool) {
         let mut ctx = SendAnyMap::new();
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
@@ -299,6 +361,68 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Override an attribute of an element with the given value, until its component sets it again
+    /// or the overrides get reset with [FreyaDOM::reset_attribute_overrides].
+    /// Returns whether it must repaint and relayout.
+    pub fn override_attribute(
+        &mut self,
+        node_id: NodeId,
+        name: AttributeName,
+        value: String,
+    ) -> (bool, bool) {
+        let original = {
+            let Some(mut node) = self.rdom.get_mut(node_id) else {
+                return (false, false);
+            };
+            let NodeTypeMut::Element(mut element) = node.node_type_mut() else {
+                return (false, false);
+            };
+            element.set_attribute(name, OwnedAttributeValue::Text(value))
+        };
+
+        // Only keep the value the element had before being overridden for the first time
+        self.attribute_overrides
+            .entry(node_id)
+            .or_default()
+            .entry(name)
+            .or_insert(original);
+
+        self.update_states()
+    }
+
+    /// Restore the original values of the overridden attributes of an element.
+    /// Returns whether it must repaint and relayout.
+    pub fn reset_attribute_overrides(&mut self, node_id: NodeId) -> (bool, bool) {
+        let Some(overrides) = self.attribute_overrides.remove(&node_id) else {
+            return (false, false);
+        };
+        {
+            let Some(mut node) = self.rdom.get_mut(node_id) else {
+                return (false, false);
+            };
+            let NodeTypeMut::Element(mut element) = node.node_type_mut() else {
+                return (false, false);
+            };
+            for (name, original) in overrides {
+                if let Some(original) = original {
+                    element.set_attribute(name, original);
+                } else {
+                    element.remove_attribute(&name);
+                }
+            }
+        }
+
+        self.update_states()
+    }
+
+    /// Names of the overridden attributes of an element.
+    pub fn attribute_overrides(&self, node_id: NodeId) -> Vec<AttributeName> {
+        self.attribute_overrides
+            .get(&node_id)
+            .map(|overrides| overrides.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
@@ -0,0 +1,470 @@
+use std::{
+    fmt,
+    sync::{
+        mpsc::{
+            channel,
+            Sender,
+        },
+        Arc,
+        Mutex,
+        OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Codec,
+    CodecOptions,
+    Data,
+    FilterMode,
+    Image,
+    MipmapMode,
+    Paint,
+    Rect,
+    SamplingOptions,
+};
+use freya_native_core::NodeId;
+use rustc_hash::FxHashMap;
+use tokio::time::Instant;
+
+use super::image_bytes;
+use crate::{
+    custom_attributes::AttributesBytes,
+    values::ImageRepeat,
+};
+
+type DecodeJob = Box<dyn FnOnce() + Send>;
+
+/// Get the shared pool of threads used to decode images.
+fn decode_workers() -> &'static Mutex<Sender<DecodeJob>> {
+    static WORKERS: OnceLock<Mutex<Sender<DecodeJob>>> = OnceLock::new();
+    WORKERS.get_or_init(|| {
+        let (sender, receiver) = channel::<DecodeJob>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = thread::available_parallelism()
+            .map(|workers| workers.get())
+            .unwrap_or(1)
+            .min(4);
+
+        for i in 0..workers {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("freya-image-decoder-{i}"))
+                .spawn(move || loop {
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("Failed to spawn an image decoder thread.");
+        }
+
+        Mutex::new(sender)
+    })
+}
+
+/// Frames lasting this or less are played with [DEFAULT_FRAME_DURATION], like browsers do.
+const MIN_FRAME_DURATION: Duration = Duration::from_millis(10);
+const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+/// Scale an image to the given size.
+fn scale_image(image: &Image, (width, height): (i32, i32)) -> Option<Image> {
+    let mut surface = raster_n32_premul((width.max(1), height.max(1)))?;
+    surface.canvas().draw_image_rect_with_sampling_options(
+        image,
+        None,
+        Rect::new(0., 0., width as f32, height as f32),
+        SamplingOptions::new(FilterMode::Linear, MipmapMode::Linear),
+        &Paint::default(),
+    );
+
+    Some(surface.image_snapshot())
+}
+
+/// Decode all the frames of the given encoded image, optionally scaling them down to the given size.
+fn decode_image(bytes: &[u8], size: Option<(i32, i32)>) -> Option<DecodedImage> {
+    let mut codec = Codec::from_data(Data::new_copy(bytes))?;
+    let frames_count = codec.get_frame_count().max(1);
+
+    let mut frames = Vec::with_capacity(frames_count);
+    for frame_index in 0..frames_count {
+        let options = CodecOptions {
+            frame_index,
+            ..Default::default()
+        };
+        let image = codec.get_image(None, &options).ok()?;
+        let image = match size {
+            Some(size) => scale_image(&image, size)?,
+            None => image,
+        };
+
+        let duration = codec
+            .get_frame_info(frame_index)
+            .map(|frame_info| Duration::from_millis(frame_info.duration.max(0) as u64))
+            .filter(|duration| *duration > MIN_FRAME_DURATION)
+            .unwrap_or(DEFAULT_FRAME_DURATION);
+
+        frames.push(ImageFrame { image, duration });
+    }
+
+    Some(DecodedImage {
+        bytes: frames.iter().map(|frame| image_bytes(&frame.image)).sum(),
+        frames,
+        repetition_count: codec.get_repetition_count(),
+    })
+}
+
+/// Information read from the header of an encoded image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageHeader {
+    pub width: i32,
+    pub height: i32,
+    /// Amount of frames, more than one for animated images.
+    pub frames: usize,
+}
+
+impl ImageHeader {
+    /// Read the header of an encoded image, without decoding its pixels.
+    pub fn read(bytes: &[u8]) -> Option<Self> {
+        let mut codec = Codec::from_data(unsafe { Data::new_bytes(bytes) })?;
+        // Encoded images are lazily decoded, so this does not decode its pixels either
+        let image = Image::from_encoded(unsafe { Data::new_bytes(bytes) })?;
+
+        Some(Self {
+            width: image.width(),
+            height: image.height(),
+            frames: codec.get_frame_count().max(1),
+        })
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.frames > 1
+    }
+}
+
+/// State of an image being decoded by the [ImageDecoder].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageDecodeStatus {
+    /// The image is being decoded, the previous one (if any) is painted meanwhile.
+    Decoding,
+    /// The image is decoded and ready to be painted.
+    Decoded,
+    /// The image could not be decoded.
+    Failed,
+}
+
+struct ImageFrame {
+    image: Image,
+    duration: Duration,
+}
+
+struct DecodedImage {
+    frames: Vec<ImageFrame>,
+    /// Decoded bytes of all the frames.
+    bytes: usize,
+    /// How many times the animation repeats, forever if `None`.
+    repetition_count: Option<usize>,
+}
+
+/// Playback state of an animated image.
+#[derive(Default)]
+struct ImageAnimation {
+    frame: usize,
+    elapsed: Duration,
+    loops: usize,
+    last_tick: Option<Instant>,
+    finished: bool,
+}
+
+struct DecodeEntry {
+    size: Option<(i32, i32)>,
+    generation: u64,
+    status: ImageDecodeStatus,
+    image: Option<Arc<DecodedImage>>,
+    animation: ImageAnimation,
+}
+
+impl DecodeEntry {
+    fn current_frame(&self) -> Option<Image> {
+        let image = self.image.as_ref()?;
+        let frame = self.animation.frame.min(image.frames.len() - 1);
+        Some(image.frames[frame].image.clone())
+    }
+
+    fn is_animated(&self) -> bool {
+        self.image
+            .as_ref()
+            .is_some_and(|image| image.frames.len() > 1)
+    }
+}
+
+/// Outcome of advancing the animated images with [ImageDecoder::tick].
+#[derive(Debug, Default)]
+pub struct ImagesTick {
+    /// Nodes whose frame changed.
+    pub changed_nodes: Vec<NodeId>,
+    /// When the next frame of any of the playing images is due, if any is playing.
+    pub next_frame: Option<Instant>,
+}
+
+struct DecodeResult {
+    node_id: NodeId,
+    generation: u64,
+    image: Option<DecodedImage>,
+}
+
+/// Decodes the images of the elements not using `decoding: "sync"` (and all the animated ones)
+/// in a pool of background threads, and plays the animated ones.
+#[derive(Default)]
+pub struct ImageDecoder {
+    entries: FxHashMap<NodeId, DecodeEntry>,
+    headers: FxHashMap<NodeId, ImageHeader>,
+    results: Arc<Mutex<Vec<DecodeResult>>>,
+    generation: u64,
+    on_decoded: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl fmt::Debug for ImageDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageDecoder")
+            .field("entries", &self.entries.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ImageDecoder {
+    /// Set a callback to be called from the worker threads every time an image finishes decoding,
+    /// e.g to request a new frame.
+    pub fn set_on_decoded(&mut self, on_decoded: impl Fn() + Send + Sync + 'static) {
+        self.on_decoded = Some(Arc::new(on_decoded));
+    }
+
+    /// Get the decoding status of the image of the given node.
+    pub fn status(&self, node_id: NodeId) -> Option<ImageDecodeStatus> {
+        self.entries.get(&node_id).map(|entry| entry.status)
+    }
+
+    /// Get the current frame of the last decoded image of the given node.
+    pub fn image(&self, node_id: NodeId) -> Option<Image> {
+        self.entries.get(&node_id)?.current_frame()
+    }
+
+    /// Get the index of the current frame of the given node.
+    pub fn frame(&self, node_id: NodeId) -> Option<usize> {
+        self.entries
+            .get(&node_id)
+            .filter(|entry| entry.image.is_some())
+            .map(|entry| entry.animation.frame)
+    }
+
+    /// Get the header of the image of a node, without decoding its pixels.
+    pub fn image_header(
+        &mut self,
+        node_id: NodeId,
+        bytes: &AttributesBytes,
+    ) -> Option<ImageHeader> {
+        if let Some(header) = self.headers.get(&node_id) {
+            return Some(*header);
+        }
+
+        let header = ImageHeader::read(bytes.as_slice())?;
+        self.headers.insert(node_id, header);
+        Some(header)
+    }
+
+    /// Get the current frame of the decoded image of a node, or start decoding it if it has not been decoded yet
+    /// or if it was decoded with a different size.
+    ///
+    /// While decoding, the previously decoded image (if any) is returned.
+    pub fn get_or_decode(
+        &mut self,
+        node_id: NodeId,
+        bytes: &AttributesBytes,
+        size: Option<(i32, i32)>,
+    ) -> Option<Image> {
+        if let Some(entry) = self.entries.get(&node_id) {
+            if entry.size == size || entry.status == ImageDecodeStatus::Failed {
+                return entry.current_frame();
+            }
+        }
+
+        self.generation += 1;
+        let generation = self.generation;
+        let (image, animation) = self
+            .entries
+            .remove(&node_id)
+            .map(|entry| (entry.image, entry.animation))
+            .unwrap_or_default();
+        let entry = DecodeEntry {
+            size,
+            generation,
+            status: ImageDecodeStatus::Decoding,
+            image,
+            animation,
+        };
+        let current_frame = entry.current_frame();
+        self.entries.insert(node_id, entry);
+
+        let bytes = bytes.clone();
+        let results = self.results.clone();
+        let on_decoded = self.on_decoded.clone();
+        let job = Box::new(move || {
+            let image = decode_image(bytes.as_slice(), size);
+            results.lock().unwrap().push(DecodeResult {
+                node_id,
+                generation,
+                image,
+            });
+            if let Some(on_decoded) = on_decoded {
+                on_decoded();
+            }
+        });
+        decode_workers().lock().unwrap().send(job).ok();
+
+        current_frame
+    }
+
+    /// Store the images decoded since the last call, returning the nodes whose image changed.
+    /// Prefer [`ImagesCache::poll_decoded`](super::ImagesCache::poll_decoded), which also enforces the budget.
+    pub fn poll(&mut self) -> Vec<NodeId> {
+        let results = std::mem::take(&mut *self.results.lock().unwrap());
+        let mut decoded_nodes = Vec::with_capacity(results.len());
+
+        for DecodeResult {
+            node_id,
+            generation,
+            image,
+        } in results
+        {
+            // Ignore results of nodes that were removed or have requested a newer decoding since
+            let Some(entry) = self.entries.get_mut(&node_id) else {
+                continue;
+            };
+            if entry.generation != generation {
+                continue;
+            }
+
+            if let Some(image) = image {
+                entry.status = ImageDecodeStatus::Decoded;
+                entry.image = Some(Arc::new(image));
+            } else {
+                entry.status = ImageDecodeStatus::Failed;
+            }
+            decoded_nodes.push(node_id);
+        }
+
+        decoded_nodes
+    }
+
+    /// Advance the frames of the animated images, returning the nodes whose frame changed
+    /// and when the next frame is due.
+    ///
+    /// `playback` tells how many times a node should repeat its animation, or `None` if it should be paused,
+    /// e.g because it is not visible.
+    ///
+    /// `now` is a tokio [Instant] so the animations follow the paused clock of the tests.
+    pub fn tick(
+        &mut self,
+        now: Instant,
+        mut playback: impl FnMut(NodeId) -> Option<ImageRepeat>,
+    ) -> ImagesTick {
+        let mut tick = ImagesTick::default();
+
+        for (node_id, entry) in &mut self.entries {
+            if !entry.is_animated() || entry.animation.finished {
+                continue;
+            }
+            let Some(image) = &entry.image else {
+                continue;
+            };
+            let animation = &mut entry.animation;
+
+            let Some(repeat) = playback(*node_id) else {
+                // Do not count the paused time
+                animation.last_tick = None;
+                continue;
+            };
+            // Start counting from the first tick it plays in
+            let last_tick = animation.last_tick.replace(now).unwrap_or(now);
+
+            let repetition_count = match repeat {
+                ImageRepeat::Auto => image.repetition_count,
+                ImageRepeat::Infinite => None,
+                ImageRepeat::Count(count) => Some(count),
+            };
+
+            animation.elapsed += now.saturating_duration_since(last_tick);
+            let previous_frame = animation.frame;
+
+            loop {
+                let frame = animation.frame.min(image.frames.len() - 1);
+                let duration = image.frames[frame].duration;
+                if animation.elapsed < duration {
+                    break;
+                }
+
+                if frame + 1 < image.frames.len() {
+                    animation.frame = frame + 1;
+                } else if repetition_count.is_some_and(|count| animation.loops >= count) {
+                    // Stay in the last frame
+                    animation.finished = true;
+                    break;
+                } else {
+                    animation.loops += 1;
+                    animation.frame = 0;
+                }
+                animation.elapsed -= duration;
+            }
+
+            if animation.frame != previous_frame {
+                tick.changed_nodes.push(*node_id);
+            }
+
+            if !animation.finished {
+                let frame = animation.frame.min(image.frames.len() - 1);
+                let next_frame = now
+                    + image.frames[frame]
+                        .duration
+                        .saturating_sub(animation.elapsed);
+                tick.next_frame = Some(
+                    tick.next_frame
+                        .map_or(next_frame, |deadline| deadline.min(next_frame)),
+                );
+            }
+        }
+
+        tick
+    }
+
+    /// Whether there is any animated image playing, which requires new frames to be rendered.
+    pub fn is_animating(&self) -> bool {
+        self.entries.values().any(|entry| {
+            entry.is_animated() && !entry.animation.finished && entry.animation.last_tick.is_some()
+        })
+    }
+
+    /// Forget the image of the given node, ignoring its pending decoding if any.
+    pub fn remove(&mut self, node_id: NodeId) {
+        self.entries.remove(&node_id);
+        self.headers.remove(&node_id);
+    }
+
+    /// Amount of decoded bytes of the frames of all the images.
+    pub fn bytes(&self) -> usize {
+        self.entries
+            .values()
+            .filter_map(|entry| entry.image.as_ref())
+            .map(|image| image.bytes)
+            .sum()
+    }
+
+    /// Whether there is any image being decoded.
+    pub fn is_decoding(&self) -> bool {
+        self.entries
+            .values()
+            .any(|entry| entry.status == ImageDecodeStatus::Decoding)
+    }
+}
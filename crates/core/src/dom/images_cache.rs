@@ -1,29 +1,264 @@
-use std::ops::{
-    Deref,
-    DerefMut,
-};
+use std::collections::BTreeMap;
 
 use freya_engine::prelude::Image;
+use freya_native_core::NodeId;
 use rustc_hash::FxHashMap;
 
+use super::ImageDecoder;
+
 #[derive(Default, PartialEq, Eq, Hash, Clone, Debug)]
 pub struct ImageCacheKey(pub String);
 
+/// Limits of an [ImagesCache]. Once exceeded, the least recently used images are evicted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImagesCacheBudget {
+    /// Maximum amount of cached images. Unlimited if `None`.
+    pub max_entries: Option<usize>,
+    /// Maximum amount of decoded bytes of all the cached images and the frames of the images decoded in background.
+    /// Unlimited if `None`.
+    pub max_bytes: Option<usize>,
+}
+
+impl Default for ImagesCacheBudget {
+    fn default() -> Self {
+        Self {
+            max_entries: None,
+            max_bytes: Some(256 * 1024 * 1024),
+        }
+    }
+}
+
+impl ImagesCacheBudget {
+    /// A budget without any limit.
+    pub fn unlimited() -> Self {
+        Self {
+            max_entries: None,
+            max_bytes: None,
+        }
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+}
+
+/// Usage of an [ImagesCache].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImagesCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    /// Amount of cached images.
+    pub entries: usize,
+    /// Amount of decoded bytes of all the cached images and the frames of the images decoded in background.
+    pub bytes: usize,
+}
+
+/// Amount of bytes taken by the pixels of a decoded image.
+pub(crate) fn image_bytes(image: &Image) -> usize {
+    image.width().max(0) as usize * image.height().max(0) as usize * 4
+}
+
+#[derive(Debug)]
+struct CachedImage {
+    image: Image,
+    bytes: usize,
+    last_used: u64,
+}
+
+/// Decoded images shared by the elements using the same `cache_key`, evicted in LRU order once over its [ImagesCacheBudget].
 #[derive(Default, Debug)]
 pub struct ImagesCache {
-    cache: FxHashMap<ImageCacheKey, Image>,
+    cache: FxHashMap<ImageCacheKey, CachedImage>,
+    /// Keys of the cached images by their `last_used` time, from the least to the most recently used.
+    recency: BTreeMap<u64, ImageCacheKey>,
+    budget: ImagesCacheBudget,
+    stats: ImagesCacheStats,
+    bytes: usize,
+    clock: u64,
+    decoder: ImageDecoder,
 }
 
-impl Deref for ImagesCache {
-    type Target = FxHashMap<ImageCacheKey, Image>;
+impl ImagesCache {
+    pub fn new(budget: ImagesCacheBudget) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.cache
+    pub fn budget(&self) -> ImagesCacheBudget {
+        self.budget
+    }
+
+    /// Change the budget, evicting images if the new one is exceeded.
+    pub fn set_budget(&mut self, budget: ImagesCacheBudget) {
+        self.budget = budget;
+        self.evict(None);
+    }
+
+    /// The decoder of the images of `image_data`, unless they use `decoding: "sync"`.
+    pub fn decoder(&self) -> &ImageDecoder {
+        &self.decoder
+    }
+
+    pub fn decoder_mut(&mut self) -> &mut ImageDecoder {
+        &mut self.decoder
+    }
+
+    pub fn stats(&self) -> ImagesCacheStats {
+        ImagesCacheStats {
+            entries: self.cache.len(),
+            bytes: self.bytes(),
+            ..self.stats
+        }
+    }
+
+    /// Amount of decoded bytes of all the cached images and the frames of the images decoded in background.
+    pub fn bytes(&self) -> usize {
+        self.bytes + self.decoder.bytes()
+    }
+
+    /// Store the images decoded in background since the last call, returning the nodes whose image changed.
+    ///
+    /// Their frames count against the budget, so the least recently used cached images are evicted if it gets exceeded.
+    pub fn poll_decoded(&mut self) -> Vec<NodeId> {
+        let decoded_nodes = self.decoder.poll();
+        if !decoded_nodes.is_empty() {
+            self.evict(None);
+        }
+        decoded_nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &ImageCacheKey) -> bool {
+        self.cache.contains_key(key)
+    }
+
+    /// Iterate over the cached images, without marking them as used.
+    pub fn iter(&self) -> impl Iterator<Item = (&ImageCacheKey, &Image)> {
+        self.cache.iter().map(|(key, cached)| (key, &cached.image))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &ImageCacheKey> {
+        self.cache.keys()
+    }
+
+    /// Get an image without marking it as used.
+    pub fn peek(&self, key: &ImageCacheKey) -> Option<&Image> {
+        self.cache.get(key).map(|cached| &cached.image)
+    }
+
+    /// Get an image, marking it as recently used.
+    pub fn get(&mut self, key: &ImageCacheKey) -> Option<Image> {
+        self.clock += 1;
+        if let Some(cached) = self.cache.get_mut(key) {
+            let key = self
+                .recency
+                .remove(&cached.last_used)
+                .unwrap_or_else(|| key.clone());
+            self.recency.insert(self.clock, key);
+            cached.last_used = self.clock;
+            self.stats.hits += 1;
+            Some(cached.image.clone())
+        } else {
+            self.stats.misses += 1;
+            None
+        }
+    }
+
+    /// Cache an image, evicting the least recently used ones if the budget gets exceeded.
+    pub fn insert(&mut self, key: ImageCacheKey, image: Image) {
+        self.clock += 1;
+        let bytes = image_bytes(&image);
+        let previous = self.cache.insert(
+            key.clone(),
+            CachedImage {
+                image,
+                bytes,
+                last_used: self.clock,
+            },
+        );
+        if let Some(previous) = previous {
+            self.bytes -= previous.bytes;
+            self.recency.remove(&previous.last_used);
+        }
+        self.recency.insert(self.clock, key.clone());
+        self.bytes += bytes;
+        self.evict(Some(&key));
+    }
+
+    /// Remove an image from the cache.
+    pub fn remove(&mut self, key: &ImageCacheKey) -> Option<Image> {
+        let cached = self.cache.remove(key)?;
+        self.bytes -= cached.bytes;
+        self.recency.remove(&cached.last_used);
+        Some(cached.image)
+    }
+
+    /// Keep only the images for which the given function returns `true`.
+    pub fn retain(&mut self, mut f: impl FnMut(&ImageCacheKey, &Image) -> bool) {
+        self.cache.retain(|key, cached| {
+            let keep = f(key, &cached.image);
+            if !keep {
+                self.bytes -= cached.bytes;
+                self.recency.remove(&cached.last_used);
+            }
+            keep
+        });
+    }
+
+    /// Remove all the images whose key starts with the given prefix. Returns how many were removed.
+    pub fn purge_prefix(&mut self, prefix: &str) -> usize {
+        let len = self.cache.len();
+        self.retain(|key, _| !key.0.starts_with(prefix));
+        len - self.cache.len()
+    }
+
+    /// Remove all the images.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.recency.clear();
+        self.bytes = 0;
+    }
+
+    fn is_over_budget(&self) -> bool {
+        self.budget
+            .max_entries
+            .is_some_and(|max_entries| self.cache.len() > max_entries)
+            || self
+                .budget
+                .max_bytes
+                .is_some_and(|max_bytes| self.bytes() > max_bytes)
     }
-}
 
-impl DerefMut for ImagesCache {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.cache
+    /// Evict the least recently used images until the budget is met, except the `keep` one.
+    fn evict(&mut self, keep: Option<&ImageCacheKey>) {
+        while self.is_over_budget() {
+            // The kept image is the most recently used one, so this only skips it when nothing else is left
+            let Some(key) = self
+                .recency
+                .values()
+                .find(|key| Some(*key) != keep)
+                .cloned()
+            else {
+                break;
+            };
+            self.remove(&key);
+            self.stats.evictions += 1;
+        }
     }
 }
@@ -1,6 +1,7 @@
 pub mod compositor_dirty_nodes;
 pub mod dom_adapter;
 pub mod doms;
+pub mod image_decoder;
 pub mod images_cache;
 mod mutations_writer;
 pub mod paragraphs;
@@ -8,5 +9,6 @@ pub mod paragraphs;
 pub use compositor_dirty_nodes::*;
 pub use dom_adapter::*;
 pub use doms::*;
+pub use image_decoder::*;
 pub use images_cache::*;
 pub use paragraphs::*;
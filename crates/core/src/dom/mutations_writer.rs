@@ -1,9 +1,12 @@
+use std::str::FromStr;
+
 use dioxus_core::{
     ElementId,
     Template,
     WriteMutations,
 };
 use freya_native_core::{
+    attributes::AttributeName,
     prelude::{
         DioxusNativeCoreMutationWriter,
         NodeImmutable,
@@ -17,6 +20,7 @@ use torin::torin::{
 };
 
 use super::{
+    AttributeOverrides,
     CompositorDirtyNodes,
     DioxusDOMAdapter,
     ImagesCache,
@@ -52,6 +56,7 @@ pub struct MutationsWriter<'a> {
     pub compositor_cache: &'a mut CompositorCache,
     pub accessibility_dirty_nodes: &'a mut AccessibilityDirtyNodes,
     pub images_cache: &'a mut ImagesCache,
+    pub attribute_overrides: &'a mut AttributeOverrides,
 }
 
 impl MutationsWriter<'_> {
@@ -92,6 +97,13 @@ impl MutationsWriter<'_> {
                 self.layers
                     .remove_node_from_layer(node_id, layer_state.layer);
 
+                // Render again the cached subtree this node was part of
+                if let Some(cached_by) = layer_state.cached_by {
+                    if cached_by != node_id {
+                        self.compositor_dirty_nodes.invalidate(cached_by);
+                    }
+                }
+
                 // Remove from paragraph elements
                 if let Some(cursor_state) = node.get::<CursorState>() {
                     if let Some(cursor_ref) = cursor_state.cursor_ref.as_ref() {
@@ -121,11 +133,15 @@ impl MutationsWriter<'_> {
                 // Remove the node from the compositor cache
                 self.compositor_cache.remove(&node_id);
 
+                // Forget the overridden attributes
+                self.attribute_overrides.remove(&node_id);
+
                 // Remove the image from the images cache
                 if let Some(image_state) = node.get::<ImageState>() {
                     if let Some(image_cache_key) = &image_state.image_cache_key {
                         self.images_cache.remove(image_cache_key);
                     }
+                    self.images_cache.decoder_mut().remove(node_id);
                 }
             }
         }
@@ -210,6 +226,13 @@ impl WriteMutations for MutationsWriter<'_> {
         value: &dioxus_core::AttributeValue,
         id: dioxus_core::ElementId,
     ) {
+        // The new value replaces the overridden one
+        let node_id = self.native_writer.state.element_to_node_id(id);
+        if let Some(overrides) = self.attribute_overrides.get_mut(&node_id) {
+            if let Ok(name) = AttributeName::from_str(name) {
+                overrides.remove(&name);
+            }
+        }
         self.native_writer.set_attribute(name, ns, value, id);
     }
 
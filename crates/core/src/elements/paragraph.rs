@@ -31,7 +31,9 @@ use crate::{
     render::{
         align_main_align_paragraph,
         create_paragraph,
+        cursor_rect,
         draw_cursor,
+        draw_preedit,
         run_cursor_highlights,
         ParagraphData,
     },
@@ -120,6 +122,22 @@ impl ParagraphElement {
                     })
                     .ok();
             }
+
+            if text_measurement.cursor_area {
+                if let Some(rect) = cursor_rect(&layout_node.area, paragraph, node) {
+                    // Notify the cursor area, used to place the IME candidates window
+                    cursor_reference
+                        .cursor_sender
+                        .send(CursorLayoutResponse::CursorArea {
+                            area: Area::new(
+                                (rect.left, rect.top).into(),
+                                (rect.width(), rect.height()).into(),
+                            ),
+                            id: text_measurement.cursor_id,
+                        })
+                        .ok();
+                }
+            }
         }
     }
 }
@@ -156,6 +174,9 @@ impl ElementUtils for ParagraphElement {
             // Draw a cursor if specified
             draw_cursor(&area, paragraph, canvas, node_ref);
 
+            // Underline the IME composition if specified
+            draw_preedit(&area, paragraph, canvas, node_ref, scale_factor);
+
             paragraph.paint(canvas, (x, y));
         };
 
@@ -23,6 +23,10 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
+    render::{
+        get_transform_matrix,
+        map_area,
+    },
     states::{
         StyleState,
         TransformState,
@@ -125,23 +129,13 @@ impl ElementWithUtils {
         let mut drawing_area =
             self.element_drawing_area(layout_node, node_ref, scale_factor, node_style);
 
-        // Apply scale effect
-        for (id, scale_x, scale_y) in &transform_state.scales {
-            let layout_node = layout.get(*id).unwrap();
-            let center = layout_node.area.center();
-            drawing_area = drawing_area.translate(-center.to_vector());
-            drawing_area = drawing_area.scale(*scale_x, *scale_y);
-            drawing_area = drawing_area.translate(center.to_vector());
-            drawing_area = drawing_area.inflate(1.0, 1.0);
+        // Apply the inherited transform effects
+        if transform_state.has_transforms() {
+            let matrix = get_transform_matrix(transform_state, layout, scale_factor);
+            drawing_area = map_area(&matrix, &drawing_area);
         }
 
-        if !transform_state.rotations.is_empty() {
-            // Apply rotation effect
-            let area = layout_node.visible_area();
-            drawing_area.max_area_when_rotated(area.center())
-        } else {
-            drawing_area
-        }
+        drawing_area
     }
 
     /// Just like [Self::get_drawing_area] but only if all the viewports allow the element to be visible.
@@ -188,10 +182,7 @@ impl ElementWithUtils {
     ) -> bool {
         let element_check = self.element_needs_cached_area(node_ref, style_state);
 
-        let rotate_effect = !transform_state.rotations.is_empty();
-        let scales_effect = !transform_state.scales.is_empty();
-
-        element_check || rotate_effect || scales_effect
+        element_check || transform_state.has_transforms()
     }
 
     /// Some elements such as `rect` might always need to rerender as Skia doesnt work well with clipped canvases with applied blur.
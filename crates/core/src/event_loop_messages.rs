@@ -1,4 +1,8 @@
 use cursor_icon::CursorIcon;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
 use torin::prelude::{
     Area,
     CursorPoint,
@@ -19,6 +23,8 @@ pub struct TextGroupMeasurement {
     pub cursor_id: usize,
     pub cursor_position: Option<CursorPoint>,
     pub cursor_selection: Option<(CursorPoint, CursorPoint)>,
+    /// Whether to measure the area of the cursor.
+    pub cursor_area: bool,
 }
 
 pub enum EventLoopMessageAction {
@@ -38,6 +44,14 @@ pub enum EventLoopMessageAction {
     FocusAccessibilityNode(AccessibilityFocusStrategy),
     /// Close the window
     CloseWindow,
+    /// Override an attribute of a node until its component sets it again
+    OverrideAttribute {
+        node_id: NodeId,
+        name: AttributeName,
+        value: String,
+    },
+    /// Restore the overridden attributes of a node
+    ResetAttributeOverrides(NodeId),
     /// Raw platform event, this are low level events.
     PlatformEvent(PlatformEvent),
     /// Accessibility Window Event
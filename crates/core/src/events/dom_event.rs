@@ -8,8 +8,10 @@ use freya_elements::{
         pointer::PointerType,
         ErasedEventData,
         FileData,
+        ImeData,
         KeyboardData,
         MouseData,
+        PinchData,
         PointerData,
         TouchData,
         WheelData,
@@ -122,6 +124,31 @@ impl DomEvent {
                 data: DomEventData::Wheel(WheelData::new(WheelSource::Device, scroll.x, scroll.y)),
                 bubbles,
             },
+            PlatformEvent::Pinch {
+                name: platform_event_name,
+                cursor,
+                delta,
+                phase,
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: DomEventData::Pinch(PinchData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        delta,
+                        phase,
+                    )),
+                    bubbles,
+                }
+            }
             PlatformEvent::Keyboard {
                 name: platform_event_name,
                 ref key,
@@ -136,6 +163,16 @@ impl DomEvent {
                 data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
                 bubbles,
             },
+            PlatformEvent::Ime {
+                name: platform_event_name,
+                data,
+            } => Self {
+                node_id,
+                name,
+                source_event: platform_event_name.into(),
+                data: DomEventData::Ime(data),
+                bubbles,
+            },
             PlatformEvent::Touch {
                 name: platform_event_name,
                 location,
@@ -200,9 +237,11 @@ pub enum DomEventData {
     Mouse(MouseData),
     Keyboard(KeyboardData),
     Wheel(WheelData),
+    Pinch(PinchData),
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Ime(ImeData),
 }
 
 impl DomEventData {
@@ -211,9 +250,11 @@ impl DomEventData {
             DomEventData::Mouse(m) => Rc::new(ErasedEventData::new(Box::new(m))),
             DomEventData::Keyboard(k) => Rc::new(ErasedEventData::new(Box::new(k))),
             DomEventData::Wheel(w) => Rc::new(ErasedEventData::new(Box::new(w))),
+            DomEventData::Pinch(p) => Rc::new(ErasedEventData::new(Box::new(p))),
             DomEventData::Touch(t) => Rc::new(ErasedEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(ErasedEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(ErasedEventData::new(Box::new(fd))),
+            DomEventData::Ime(i) => Rc::new(ErasedEventData::new(Box::new(i))),
         }
     }
 }
@@ -7,6 +7,7 @@ use freya_native_core::{
     events::EventName,
     NodeId,
 };
+use ragnarok::EmitOutcome;
 
 use crate::{
     dom::DioxusDOM,
@@ -19,6 +20,7 @@ use crate::{
 pub struct EventsExecutorAdapter<'a> {
     pub rdom: &'a DioxusDOM,
     pub vdom: &'a mut VirtualDom,
+    pub report_emitted_events: bool,
 }
 
 impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
@@ -27,30 +29,43 @@ impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
     type Source = PlatformEvent;
     type Emmitable = DomEvent;
 
-    fn emit_event(&mut self, event: Self::Emmitable) -> bool {
+    fn emit_event(&mut self, event: Self::Emmitable) -> EmitOutcome {
         let Some(element_id) = self
             .rdom
             .get(event.node_id)
             .and_then(|node| node.mounted_id())
         else {
-            return false;
+            return EmitOutcome {
+                default_action_enabled: false,
+                propagation_stopped: false,
+            };
         };
         let event_name = event.name;
-        let event = Event::new(event.data.clone().any(), event.bubbles);
+        let bubbles = event.bubbles;
+        let event = Event::new(event.data.clone().any(), bubbles);
         let event_clone = event.clone();
 
         #[cfg(debug_assertions)]
         tracing::info!("Running event {event_name:?} in Element {element_id:?}");
 
-        // Call the actual event handler
+        // Call the actual event handler.
+        // Dioxus bubbles the event through the handlers of the ancestors itself, so only
+        // whether one of them stopped the propagation is known, not which one did
         self.vdom
             .runtime()
             .handle_event(event_name.into(), event, element_id);
 
-        event_clone.default_action_enabled()
+        EmitOutcome {
+            default_action_enabled: event_clone.default_action_enabled(),
+            propagation_stopped: bubbles && !event_clone.propagates(),
+        }
     }
 
     fn emitted_events(&mut self) {
         self.vdom.process_events();
     }
+
+    fn report_emitted_events(&self) -> bool {
+        self.report_emitted_events
+    }
 }
@@ -18,8 +18,10 @@ use crate::{
         PlatformEvent,
     },
     layers::Layers,
+    render::get_transform_matrix,
     states::{
         StyleState,
+        TransformState,
         ViewportState,
     },
     values::{
@@ -74,9 +76,23 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
             return false;
         };
 
+        // Map the cursor to the untransformed geometry of the node
+        let node_transform = node_ref.get::<TransformState>().unwrap();
+        let node_cursor = if node_transform.has_transforms() {
+            let matrix =
+                get_transform_matrix(&node_transform, self.layout, self.scale_factor as f32);
+            let Some(inverse) = matrix.invert() else {
+                return false;
+            };
+            let point = inverse.map_point((cursor.x as f32, cursor.y as f32));
+            ragnarok::CursorPoint::new(point.x as f64, point.y as f64)
+        } else {
+            cursor
+        };
+
         // Make sure the cursor is inside the node area
         if !element_utils.is_point_inside_area(
-            &cursor,
+            &node_cursor,
             &node_ref,
             layout_node,
             self.scale_factor as f32,
@@ -1,10 +1,13 @@
 use std::path::PathBuf;
 
 use freya_elements::{
-    events::keyboard::{
-        Code,
-        Key,
-        Modifiers,
+    events::{
+        keyboard::{
+            Code,
+            Key,
+            Modifiers,
+        },
+        ImeData,
     },
     Force,
     MouseButton,
@@ -50,6 +53,19 @@ impl From<WheelEventName> for EventName {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum PinchEventName {
+    Pinch,
+}
+
+impl From<PinchEventName> for EventName {
+    fn from(value: PinchEventName) -> Self {
+        match value {
+            PinchEventName::Pinch => EventName::Pinch,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum KeyboardEventName {
     KeyDown,
@@ -65,6 +81,19 @@ impl From<KeyboardEventName> for EventName {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum ImeEventName {
+    Ime,
+}
+
+impl From<ImeEventName> for EventName {
+    fn from(value: ImeEventName) -> Self {
+        match value {
+            ImeEventName::Ime => EventName::Ime,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum TouchEventName {
     TouchStart,
@@ -115,6 +144,13 @@ pub enum PlatformEvent {
         scroll: CursorPoint,
         cursor: CursorPoint,
     },
+    /// A touchpad Pinch event.
+    Pinch {
+        name: PinchEventName,
+        cursor: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
+    },
     /// A Keyboard event.
     Keyboard {
         name: KeyboardEventName,
@@ -122,6 +158,8 @@ pub enum PlatformEvent {
         code: Code,
         modifiers: Modifiers,
     },
+    /// An Input Method Editor event.
+    Ime { name: ImeEventName, data: ImeData },
     /// A Touch event.
     Touch {
         name: TouchEventName,
@@ -170,7 +208,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
         match self {
             Self::Mouse { name, .. } => (*name).into(),
             Self::Wheel { name, .. } => (*name).into(),
+            Self::Pinch { name, .. } => (*name).into(),
             Self::Keyboard { name, .. } => (*name).into(),
+            Self::Ime { name, .. } => (*name).into(),
             Self::Touch { name, .. } => (*name).into(),
             Self::File { name, .. } => (*name).into(),
         }
@@ -181,7 +221,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
             PlatformEvent::File { cursor, .. } => Some(*cursor),
             PlatformEvent::Mouse { cursor, .. } => Some(*cursor),
             PlatformEvent::Wheel { cursor, .. } => Some(*cursor),
+            PlatformEvent::Pinch { cursor, .. } => Some(*cursor),
             PlatformEvent::Keyboard { .. } => None,
+            PlatformEvent::Ime { .. } => None,
             PlatformEvent::Touch { location, .. } => Some(*location),
         }
     }
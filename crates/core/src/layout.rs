@@ -15,6 +15,7 @@ use crate::{
         NodeAccessibility,
     },
     dom::*,
+    profiling::FrameStats,
     render::{
         Compositor,
         CompositorDirtyArea,
@@ -31,6 +32,7 @@ pub fn process_layout(
     dirty_accessibility_tree: &mut AccessibilityDirtyNodes,
     compositor_dirty_nodes: &mut CompositorDirtyNodes,
     compositor_dirty_area: &mut CompositorDirtyArea,
+    frame_stats: &mut FrameStats,
     area: Area,
     font_collection: &mut FontCollection,
     scale_factor: f32,
@@ -48,6 +50,8 @@ pub fn process_layout(
     // Finds the best Node from where to start measuring
     layout.find_best_root(&mut dom_adapter);
 
+    frame_stats.dirty_layout_nodes += layout.dirty.len();
+
     let mut buffer = layout.dirty.keys().copied().collect_vec();
     while let Some(node_id) = buffer.pop() {
         if let Some(node) = rdom.get(node_id) {
@@ -12,6 +12,7 @@ pub mod parsing;
 pub mod platform;
 pub mod platform_state;
 pub mod plugins;
+pub mod profiling;
 pub mod render;
 pub mod states;
 pub mod style;
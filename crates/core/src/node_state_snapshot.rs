@@ -18,7 +18,7 @@ pub trait NodeStateSnapshot {
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Default)]
 pub struct NodeState {
     pub cursor: CursorState,
     pub font_style: FontStyleState,
@@ -22,6 +22,11 @@ use crate::{
         EventLoopMessageAction,
     },
     events::PlatformEvent,
+    types::{
+        AccessibilityId,
+        DomExecutedEvents,
+    },
+    values::Color,
 };
 
 #[derive(Clone)]
@@ -63,6 +68,22 @@ impl PluginsManager {
         self.plugins.borrow_mut().push(Box::new(plugin))
     }
 
+    /// Whether any plugin wants to receive [PluginEvent::EmittedEvents].
+    pub fn wants_emitted_events(&self) -> bool {
+        self.plugins
+            .borrow()
+            .iter()
+            .any(|plugin| plugin.wants_emitted_events())
+    }
+
+    /// Whether any plugin reads the [FrameStats](crate::profiling::FrameStats) of the frames.
+    pub fn wants_frame_stats(&self) -> bool {
+        self.plugins
+            .borrow()
+            .iter()
+            .any(|plugin| plugin.wants_frame_stats())
+    }
+
     pub fn send(&mut self, event: PluginEvent, handle: PluginHandle) {
         for plugin in self.plugins.borrow_mut().iter_mut() {
             plugin.on_event(&event, handle.clone())
@@ -126,6 +147,24 @@ pub enum PluginEvent<'a> {
         fdom: &'a FreyaDOM,
     },
 
+    /// After calling the event handlers of the processed events.
+    /// Only sent if a plugin asks for it with [FreyaPlugin::wants_emitted_events].
+    EmittedEvents {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+        events: &'a DomExecutedEvents,
+    },
+
+    /// After updating the accessibility tree.
+    FinishedUpdatingAccessibility {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+        /// Accessibility node that is focused.
+        focused_id: AccessibilityId,
+        /// Background color of the window.
+        background: Color,
+    },
+
     StartedUpdatingDOM {
         window: &'a Window,
         fdom: &'a FreyaDOM,
@@ -141,4 +180,15 @@ pub enum PluginEvent<'a> {
 pub trait FreyaPlugin {
     /// React on events emitted by Freya.
     fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle);
+
+    /// Whether this plugin wants to receive [PluginEvent::EmittedEvents].
+    fn wants_emitted_events(&self) -> bool {
+        false
+    }
+
+    /// Whether this plugin reads the [FrameStats](crate::profiling::FrameStats) of the frames in [PluginEvent::AfterRender].
+    /// This enables counting the component renders, see [`FreyaDOM::set_profiling`](crate::dom::FreyaDOM::set_profiling).
+    fn wants_frame_stats(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,319 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use serde::Serialize;
+use serde_json::{
+    json,
+    Value,
+};
+use torin::prelude::Area;
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    level_filters::LevelFilter,
+    span::{
+        Attributes,
+        Current,
+        Id,
+        Record,
+    },
+    subscriber::Interest,
+    Dispatch,
+    Event,
+    Metadata,
+    Subscriber,
+};
+
+use crate::dom::ImagesCacheStats;
+
+/// Work done since the stats were last taken, updated by the layout and rendering steps.
+///
+/// Windows reset them after every frame, so plugins must take them in
+/// [`PluginEvent::AfterRender`](crate::plugins::PluginEvent::AfterRender).
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct FrameStats {
+    /// Nodes whose layout had to be measured again.
+    pub dirty_layout_nodes: usize,
+    /// Nodes painted by the render pipeline.
+    pub painted_nodes: usize,
+    /// Area of the window that was rendered again.
+    pub dirty_area: Option<Area>,
+    /// Renders of every component, by name.
+    /// Only counted while profiling, see [`FreyaDOM::set_profiling`](crate::dom::FreyaDOM::set_profiling).
+    pub component_renders: BTreeMap<String, usize>,
+}
+
+/// Whether the span is the one in which the VirtualDOM renders a component, named after it in its `scope` field.
+fn is_render_span(metadata: &Metadata<'_>) -> bool {
+    metadata.name() == "render" && metadata.target().starts_with("dioxus_core")
+}
+
+struct ComponentName(Option<String>);
+
+impl Visit for ComponentName {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "scope" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// Counts the component renders of the VirtualDOM in the [FrameStats], forwarding everything to the previous subscriber.
+struct RenderCounter {
+    inner: Dispatch,
+    frame_stats: Arc<Mutex<FrameStats>>,
+}
+
+/// Create a dispatcher that counts the component renders in the given [FrameStats] while it is the default one.
+pub(crate) fn render_counter(frame_stats: Arc<Mutex<FrameStats>>) -> Dispatch {
+    let inner = tracing::dispatcher::get_default(Dispatch::clone);
+    Dispatch::new(RenderCounter { inner, frame_stats })
+}
+
+impl Subscriber for RenderCounter {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if is_render_span(metadata) {
+            Interest::always()
+        } else {
+            self.inner.register_callsite(metadata)
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        is_render_span(metadata) || self.inner.enabled(metadata)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::TRACE)
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        if is_render_span(span.metadata()) {
+            let mut name = ComponentName(None);
+            span.record(&mut name);
+            if let Some(name) = name.0 {
+                *self
+                    .frame_stats
+                    .lock()
+                    .unwrap()
+                    .component_renders
+                    .entry(name)
+                    .or_default() += 1;
+            }
+        }
+        self.inner.new_span(span)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        self.inner.record(span, values)
+    }
+
+    fn record_follows_from(&self, span: &Id, follows: &Id) {
+        self.inner.record_follows_from(span, follows)
+    }
+
+    fn event(&self, event: &Event<'_>) {
+        self.inner.event(event)
+    }
+
+    fn enter(&self, span: &Id) {
+        self.inner.enter(span)
+    }
+
+    fn exit(&self, span: &Id) {
+        self.inner.exit(span)
+    }
+
+    fn clone_span(&self, id: &Id) -> Id {
+        self.inner.clone_span(id)
+    }
+
+    fn try_close(&self, id: Id) -> bool {
+        self.inner.try_close(id)
+    }
+
+    fn current_span(&self) -> Current {
+        self.inner.current_span()
+    }
+}
+
+/// Timed step of a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileSpan {
+    pub name: &'static str,
+    /// Time since the profiling started.
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+/// Everything recorded for a frame of a window.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct FrameProfile {
+    /// Number of the frame in its window.
+    pub index: u64,
+    /// Identifier of the window.
+    pub window: u64,
+    pub spans: Vec<ProfileSpan>,
+    pub stats: FrameStats,
+    /// Usage of the images cache of the window after the frame.
+    pub images_cache: ImagesCacheStats,
+}
+
+impl FrameProfile {
+    /// Time from the start of the first span until the end of the last one.
+    pub fn duration(&self) -> Duration {
+        let start = self.spans.iter().map(|span| span.start).min();
+        let end = self
+            .spans
+            .iter()
+            .map(|span| span.start + span.duration)
+            .max();
+        match (start, end) {
+            (Some(start), Some(end)) => end - start,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Trace in the Chrome trace event format.
+#[derive(Serialize)]
+struct ChromeTrace {
+    #[serde(rename = "traceEvents")]
+    trace_events: Vec<TraceEvent>,
+}
+
+/// Span (`"X"`) or counter (`"C"`) event of a [ChromeTrace], with its times in microseconds.
+#[derive(Serialize)]
+struct TraceEvent {
+    name: Cow<'static, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cat: Option<&'static str>,
+    ph: &'static str,
+    ts: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<f64>,
+    pid: u32,
+    tid: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Value>,
+}
+
+impl TraceEvent {
+    fn span(
+        name: impl Into<Cow<'static, str>>,
+        cat: &'static str,
+        ts: f64,
+        dur: f64,
+        tid: u64,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            cat: Some(cat),
+            ph: "X",
+            ts,
+            dur: Some(dur),
+            pid: 1,
+            tid,
+            args: None,
+        }
+    }
+
+    fn counter(name: &'static str, ts: f64, tid: u64, args: Value) -> Self {
+        Self {
+            name: name.into(),
+            cat: None,
+            ph: "C",
+            ts,
+            dur: None,
+            pid: 1,
+            tid,
+            args: Some(args),
+        }
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000.
+}
+
+/// Serialize the given frames in the Chrome trace event format, which can be opened with
+/// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+///
+/// Each window is shown as a separate thread, with a span for every frame and its steps,
+/// and counters for the frame stats and the component renders.
+pub fn chrome_trace<'a>(frames: impl IntoIterator<Item = &'a FrameProfile>) -> String {
+    let mut trace_events = Vec::new();
+
+    for frame in frames {
+        let Some(start) = frame.spans.iter().map(|span| span.start).min() else {
+            continue;
+        };
+        let ts = micros(start);
+        let tid = frame.window;
+
+        trace_events.push(TraceEvent::span(
+            format!("Frame {}", frame.index),
+            "frame",
+            ts,
+            micros(frame.duration()),
+            tid,
+        ));
+
+        for span in &frame.spans {
+            trace_events.push(TraceEvent::span(
+                span.name,
+                "step",
+                micros(span.start),
+                micros(span.duration),
+                tid,
+            ));
+        }
+
+        let dirty_area = frame
+            .stats
+            .dirty_area
+            .map(|area| area.width() * area.height())
+            .unwrap_or_default();
+        trace_events.push(TraceEvent::counter(
+            "Frame stats",
+            ts,
+            tid,
+            json!({
+                "dirty_layout_nodes": frame.stats.dirty_layout_nodes,
+                "painted_nodes": frame.stats.painted_nodes,
+                "dirty_area": dirty_area,
+            }),
+        ));
+
+        trace_events.push(TraceEvent::counter(
+            "Images cache",
+            ts,
+            tid,
+            json!({
+                "entries": frame.images_cache.entries,
+                "bytes": frame.images_cache.bytes,
+            }),
+        ));
+
+        if !frame.stats.component_renders.is_empty() {
+            trace_events.push(TraceEvent::counter(
+                "Component renders",
+                ts,
+                tid,
+                json!(frame.stats.component_renders),
+            ));
+        }
+    }
+
+    serde_json::to_string(&ChromeTrace { trace_events }).expect("Chrome traces are valid JSON")
+}
@@ -0,0 +1,85 @@
+use freya_engine::prelude::Image;
+use freya_native_core::NodeId;
+use rustc_hash::FxHashMap;
+use torin::prelude::Area;
+
+/// Usage counters of a [LayerCache].
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayerCacheStats {
+    /// Times a cached subtree was painted again without rasterizing it.
+    pub hits: u64,
+    /// Times a subtree had to be rasterized.
+    pub misses: u64,
+}
+
+#[derive(Debug)]
+pub struct CachedLayer {
+    pub image: Image,
+    pub area: Area,
+}
+
+/// Rasterized subtrees of the elements using `layer_cache: "true"`, keyed by their root.
+///
+/// A subtree is rasterized again once any of its nodes is marked as dirty.
+#[derive(Default, Debug)]
+pub struct LayerCache {
+    layers: FxHashMap<NodeId, CachedLayer>,
+    stats: LayerCacheStats,
+    scale_factor: f32,
+}
+
+impl LayerCache {
+    pub fn stats(&self) -> LayerCacheStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    pub fn contains(&self, node_id: &NodeId) -> bool {
+        self.layers.contains_key(node_id)
+    }
+
+    /// Get the cached layer of the given subtree root, counting it as a hit or miss.
+    pub fn get(&mut self, node_id: &NodeId) -> Option<&CachedLayer> {
+        let cached_layer = self.layers.get(node_id);
+        if cached_layer.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        cached_layer
+    }
+
+    pub fn insert(&mut self, node_id: NodeId, cached_layer: CachedLayer) {
+        self.layers.insert(node_id, cached_layer);
+    }
+
+    /// Discard the cached layer of the given subtree root.
+    pub fn invalidate(&mut self, node_id: &NodeId) {
+        self.layers.remove(node_id);
+    }
+
+    /// Discard the cached layers whose root does not match the predicate.
+    pub fn retain(&mut self, mut keep: impl FnMut(&NodeId) -> bool) {
+        self.layers.retain(|node_id, _| keep(node_id));
+    }
+
+    /// Discard all the cached layers.
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Discard all the cached layers if they were rasterized with a different scale factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        if self.scale_factor != scale_factor {
+            self.scale_factor = scale_factor;
+            self.clear();
+        }
+    }
+}
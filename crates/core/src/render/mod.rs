@@ -1,9 +1,11 @@
 pub mod compositor;
+pub mod layer_cache;
 pub mod pipeline;
 pub mod skia_measurer;
 pub mod utils;
 
 pub use compositor::*;
+pub use layer_cache::*;
 pub use pipeline::*;
 pub use skia_measurer::*;
 pub use utils::*;
@@ -1,13 +1,12 @@
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     FontCollection,
     FontMgr,
-    Matrix,
     Paint,
     Rect,
     SamplingOptions,
     SaveLayerRec,
-    SkPoint,
     Surface,
 };
 use freya_native_core::{
@@ -17,9 +16,11 @@ use freya_native_core::{
     },
     real_dom::NodeImmutable,
     tags::TagName,
+    tree::TreeRef,
     NodeId,
 };
 use itertools::sorted;
+use tokio::time::Instant;
 use torin::prelude::{
     Area,
     LayoutNode,
@@ -27,9 +28,13 @@ use torin::prelude::{
 };
 
 use super::{
+    get_transform_matrix,
+    report_image_status,
+    CachedLayer,
     Compositor,
     CompositorCache,
     CompositorDirtyArea,
+    LayerCache,
 };
 use crate::{
     dom::{
@@ -43,7 +48,10 @@ use crate::{
         ElementUtilsResolver,
     },
     layers::Layers,
+    profiling::FrameStats,
     states::{
+        ImageState,
+        LayerState,
         TransformState,
         ViewportState,
     },
@@ -64,6 +72,8 @@ pub struct RenderPipeline<'a> {
     pub font_collection: &'a mut FontCollection,
     pub font_manager: &'a FontMgr,
     pub images_cache: &'a mut ImagesCache,
+    pub layer_cache: &'a mut LayerCache,
+    pub frame_stats: &'a mut FrameStats,
     pub canvas_area: Area,
     pub background: Color,
     pub scale_factor: f32,
@@ -71,9 +81,63 @@ pub struct RenderPipeline<'a> {
 }
 
 impl RenderPipeline<'_> {
-    pub fn run(&mut self) {
+    /// Render the dirty parts of the canvas, returning when the next frame of the playing animated images is due.
+    pub fn run(&mut self) -> Option<Instant> {
         let mut dirty_layers = Layers::default();
 
+        // Render again the images that finished decoding in background
+        for node_id in self.images_cache.poll_decoded() {
+            if let (Some(node_ref), Some(status)) = (
+                self.rdom.get(node_id),
+                self.images_cache.decoder().status(node_id),
+            ) {
+                report_image_status(&node_ref, status);
+            }
+            self.compositor_dirty_nodes.invalidate(node_id);
+        }
+
+        // Advance the animated images, pausing those that are not visible
+        let images_tick = self
+            .images_cache
+            .decoder_mut()
+            .tick(Instant::now(), |node_id| {
+                let node_ref = self.rdom.get(node_id)?;
+                let image_state = node_ref.get::<ImageState>()?;
+                if !image_state.playing {
+                    return None;
+                }
+                let drawing_area = Compositor::get_drawing_area(
+                    node_id,
+                    self.layout,
+                    self.rdom,
+                    self.scale_factor,
+                )?;
+                drawing_area
+                    .intersects(&self.canvas_area)
+                    .then_some(image_state.repeat)
+            });
+        for node_id in images_tick.changed_nodes {
+            self.compositor_dirty_nodes.invalidate(node_id);
+        }
+
+        // Discard the cached subtrees that changed
+        self.layer_cache.set_scale_factor(self.scale_factor);
+        for node_id in self.compositor_dirty_nodes.iter() {
+            let cached_by = self
+                .rdom
+                .get(*node_id)
+                .and_then(|node_ref| node_ref.get::<LayerState>()?.cached_by);
+            if let Some(cached_by) = cached_by {
+                self.layer_cache.invalidate(&cached_by);
+            }
+        }
+        self.layer_cache.retain(|node_id| {
+            self.rdom
+                .get(*node_id)
+                .and_then(|node_ref| node_ref.get::<LayerState>().map(|state| state.layer_cache))
+                .unwrap_or_default()
+        });
+
         // Process what nodes need to be rendered
         let rendering_layers = self.compositor.run(
             self.compositor_dirty_nodes,
@@ -116,6 +180,12 @@ impl RenderPipeline<'_> {
         // Clear using the the background only, but only the dirty
         // area in which it will render the intersected nodes again
         if let Some(dirty_area) = self.compositor_dirty_area.take() {
+            self.frame_stats.dirty_area = Some(
+                self.frame_stats
+                    .dirty_area
+                    .map_or(dirty_area, |area| area.union(&dirty_area)),
+            );
+
             #[cfg(debug_assertions)]
             tracing::info!("Marked {dirty_area:?} as dirty area");
 
@@ -132,34 +202,57 @@ impl RenderPipeline<'_> {
             self.dirty_surface.canvas().clear(self.background);
         }
 
-        #[cfg(debug_assertions)]
-        // Counter of painted nodes for debugging purposes
+        // Counter of painted nodes for profiling purposes
         let mut painted = 0;
 
+        // Nodes of cached subtrees are painted by the root of the subtree, in its layer
+        let mut painting_layers = Layers::default();
+        for (layer, nodes) in rendering_layers.iter() {
+            for node_id in nodes {
+                let node_ref = self.rdom.get(*node_id).unwrap();
+                match node_ref
+                    .get::<LayerState>()
+                    .and_then(|state| state.cached_by)
+                {
+                    Some(cached_by) => {
+                        let layer = self
+                            .rdom
+                            .get(cached_by)
+                            .and_then(|node_ref| Some(node_ref.get::<LayerState>()?.layer))
+                            .unwrap_or(*layer);
+                        painting_layers.insert_node_in_layer(cached_by, layer);
+                    }
+                    None => painting_layers.insert_node_in_layer(*node_id, *layer),
+                }
+            }
+        }
+
         // Render the dirty nodes
-        for (_, nodes) in sorted(rendering_layers.iter()) {
-            'elements: for node_id in sorted(nodes) {
+        for (_, nodes) in sorted(painting_layers.iter()) {
+            for node_id in sorted(nodes) {
                 let node_ref = self.rdom.get(*node_id).unwrap();
-                let node_viewports = node_ref.get::<ViewportState>().unwrap();
-                let layout_node = self.layout.get(*node_id);
+                let Some(layout_node) = self.layout.get(*node_id) else {
+                    continue;
+                };
+
+                let is_cached = node_ref
+                    .get::<LayerState>()
+                    .is_some_and(|state| state.cached_by == Some(*node_id));
 
-                if let Some(layout_node) = layout_node {
+                if is_cached {
+                    // Render the whole subtree from the cache
+                    self.render_cached_layer(*node_id);
+                } else {
                     // Skip elements that are completely out of any their parent's viewport
-                    for viewport_id in &node_viewports.viewports {
-                        let viewport = self.layout.get(*viewport_id).unwrap().visible_area();
-                        if !viewport.intersects(&layout_node.area) {
-                            continue 'elements;
-                        }
+                    if self.is_out_of_viewports(node_ref, layout_node) {
+                        continue;
                     }
 
                     // Render the element
                     self.render(node_ref, layout_node);
-
-                    #[cfg(debug_assertions)]
-                    {
-                        painted += 1;
-                    }
                 }
+
+                painted += 1;
             }
         }
 
@@ -170,6 +263,8 @@ impl RenderPipeline<'_> {
             }
         }
 
+        self.frame_stats.painted_nodes += painted;
+
         // Copy the dirty canvas into the main canvas
         self.dirty_surface.canvas().restore();
         self.surface.canvas().clear(self.background);
@@ -181,10 +276,142 @@ impl RenderPipeline<'_> {
         );
 
         self.compositor_dirty_nodes.clear();
+
+        images_tick.next_frame
+    }
+
+    fn is_out_of_viewports(&self, node_ref: DioxusNode, layout_node: &LayoutNode) -> bool {
+        let node_viewports = node_ref.get::<ViewportState>().unwrap();
+        node_viewports.viewports.iter().any(|viewport_id| {
+            let viewport = self.layout.get(*viewport_id).unwrap().visible_area();
+            !viewport.intersects(&layout_node.area)
+        })
+    }
+
+    /// Paint a cached subtree, rendering it into a new layer first if it was not cached yet.
+    fn render_cached_layer(&mut self, root_id: NodeId) {
+        if let Some(cached_layer) = self.layer_cache.get(&root_id) {
+            self.dirty_surface.canvas().draw_image(
+                &cached_layer.image,
+                (cached_layer.area.min_x(), cached_layer.area.min_y()),
+                None,
+            );
+            return;
+        }
+
+        // Collect the nodes of the subtree and the area they paint
+        let mut subtree_layers = Layers::default();
+        let mut subtree_area: Option<Area> = None;
+        let tree = self.rdom.tree_ref();
+        let mut stack = vec![root_id];
+        while let Some(node_id) = stack.pop() {
+            let Some(node_ref) = self.rdom.get(node_id) else {
+                continue;
+            };
+            if !node_ref.node_type().is_visible_element() {
+                continue;
+            }
+
+            let traverse_children = node_ref
+                .node_type()
+                .tag()
+                .map(|tag| tag.has_children_with_intrinsic_layout())
+                .unwrap_or_default();
+            if traverse_children {
+                stack.extend(tree.children_ids_advanced(node_id, false));
+            }
+
+            let Some(layer_state) = node_ref.get::<LayerState>() else {
+                continue;
+            };
+            subtree_layers.insert_node_in_layer(node_id, layer_state.layer);
+
+            if let Some(area) =
+                Compositor::get_drawing_area(node_id, self.layout, self.rdom, self.scale_factor)
+            {
+                subtree_area =
+                    Some(subtree_area.map_or(area, |subtree_area| subtree_area.union(&area)));
+            }
+        }
+
+        let Some(area) = subtree_area
+            .and_then(|area| area.intersection(&self.canvas_area))
+            .map(|area| area.round_out())
+        else {
+            return;
+        };
+
+        let Some(mut layer_surface) = self
+            .dirty_surface
+            .new_surface_with_dimensions((area.width() as i32, area.height() as i32))
+        else {
+            return;
+        };
+
+        let layer_canvas = layer_surface.canvas();
+        layer_canvas.clear(Color::TRANSPARENT);
+        layer_canvas.translate((-area.min_x(), -area.min_y()));
+
+        for (_, nodes) in sorted(subtree_layers.iter()) {
+            for node_id in sorted(nodes) {
+                let node_ref = self.rdom.get(*node_id).unwrap();
+                let Some(layout_node) = self.layout.get(*node_id) else {
+                    continue;
+                };
+                if self.is_out_of_viewports(node_ref, layout_node) {
+                    continue;
+                }
+                Self::render_node(
+                    node_ref,
+                    layout_node,
+                    layer_canvas,
+                    self.rdom,
+                    self.layout,
+                    self.font_collection,
+                    self.font_manager,
+                    self.fallback_fonts,
+                    self.images_cache,
+                    self.scale_factor,
+                );
+            }
+        }
+
+        let image = layer_surface.image_snapshot();
+        self.dirty_surface
+            .canvas()
+            .draw_image(&image, (area.min_x(), area.min_y()), None);
+        self.layer_cache
+            .insert(root_id, CachedLayer { image, area });
     }
 
     pub fn render(&mut self, node_ref: DioxusNode, layout_node: &LayoutNode) {
-        let dirty_canvas = self.dirty_surface.canvas();
+        Self::render_node(
+            node_ref,
+            layout_node,
+            self.dirty_surface.canvas(),
+            self.rdom,
+            self.layout,
+            self.font_collection,
+            self.font_manager,
+            self.fallback_fonts,
+            self.images_cache,
+            self.scale_factor,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_node(
+        node_ref: DioxusNode,
+        layout_node: &LayoutNode,
+        dirty_canvas: &Canvas,
+        rdom: &DioxusDOM,
+        layout: &Torin<NodeId>,
+        font_collection: &mut FontCollection,
+        font_manager: &FontMgr,
+        fallback_fonts: &[String],
+        images_cache: &mut ImagesCache,
+        scale_factor: f32,
+    ) {
         let node_type = &*node_ref.node_type();
         if let NodeType::Element(ElementNode { tag, .. }) = node_type {
             let Some(element_utils) = tag.utils() else {
@@ -201,37 +428,18 @@ impl RenderPipeline<'_> {
 
             // Apply viewport clipping
             for node_id in &node_viewports.viewports {
-                let node_ref = self.rdom.get(*node_id).unwrap();
+                let node_ref = rdom.get(*node_id).unwrap();
                 let node_type = node_ref.node_type();
                 let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
                     continue;
                 };
-                let layout_node = self.layout.get(*node_id).unwrap();
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
-            }
-
-            // Apply inherited scale effects
-            for (id, scale_x, scale_y) in &node_transform.scales {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let center = area.center();
-                dirty_canvas.translate((center.x, center.y));
-                dirty_canvas.scale((*scale_x, *scale_y));
-                dirty_canvas.translate((-center.x, -center.y));
+                let layout_node = layout.get(*node_id).unwrap();
+                element_utils.clip(layout_node, &node_ref, dirty_canvas, scale_factor);
             }
 
-            // Pass rotate effect to children
-            for (id, rotate_degs) in &node_transform.rotations {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let mut matrix = Matrix::new_identity();
-                matrix.set_rotate(
-                    *rotate_degs,
-                    Some(SkPoint {
-                        x: area.min_x() + area.width() / 2.0,
-                        y: area.min_y() + area.height() / 2.0,
-                    }),
-                );
+            // Apply inherited translate, scale, rotate, skew and transform effects
+            if node_transform.has_transforms() {
+                let matrix = get_transform_matrix(node_transform, layout, scale_factor);
                 dirty_canvas.concat(&matrix);
             }
 
@@ -252,18 +460,18 @@ impl RenderPipeline<'_> {
             // Clip the element itself if non-children content can overflow, like an image in case of `image`
             // or text in the case of `label` or `paragraph`
             if *tag == TagName::Paragraph || *tag == TagName::Label || *tag == TagName::Image {
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
+                element_utils.clip(layout_node, &node_ref, dirty_canvas, scale_factor);
             }
 
             element_utils.render(
                 layout_node,
                 &node_ref,
                 dirty_canvas,
-                self.font_collection,
-                self.font_manager,
-                self.fallback_fonts,
-                self.images_cache,
-                self.scale_factor,
+                font_collection,
+                font_manager,
+                fallback_fonts,
+                images_cache,
+                scale_factor,
             );
 
             dirty_canvas.restore_to_count(initial_layer);
@@ -21,8 +21,7 @@ use torin::prelude::{
 use super::{
     create_label,
     create_paragraph,
-    get_or_create_image,
-    ImageData,
+    measure_image,
 };
 use crate::{
     custom_attributes::NodeReferenceLayout,
@@ -97,9 +96,7 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
                 Some((size, Arc::new(map)))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Image => {
-                let Some(ImageData { size, .. }) =
-                    get_or_create_image(&node, area_size, self.images_cache)
-                else {
+                let Some(size) = measure_image(&node, area_size, self.images_cache) else {
                     return Some((*area_size, Arc::default()));
                 };
                 Some((size, Arc::default()))
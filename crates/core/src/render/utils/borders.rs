@@ -4,7 +4,9 @@ use torin::prelude::Area;
 use crate::values::{
     Border,
     BorderAlignment,
+    BorderStyle,
     CornerRadius,
+    Fill,
 };
 
 pub enum BorderShape {
@@ -19,23 +21,224 @@ pub fn render_border(
     border: &Border,
     corner_radius: &CornerRadius,
 ) {
-    // Create a new paint
-    let mut border_paint = Paint::default();
-    border_paint.set_style(PaintStyle::Fill);
-    border_paint.set_anti_alias(true);
+    if border.style == BorderStyle::Solid && border.fill.is_uniform() {
+        let mut border_paint = border_paint(&border.fill.top, area);
+        border_paint.set_style(PaintStyle::Fill);
 
-    border.fill.apply_to_paint(&mut border_paint, area);
+        draw_border_shape(
+            canvas,
+            border_shape(rect, corner_radius, border),
+            &border_paint,
+        );
+        return;
+    }
+
+    let (outer, inner) = border_rrects(rect, corner_radius, border);
+
+    // Draw everything at once when all the sides look the same
+    if border.fill.is_uniform() && border.width.is_uniform() {
+        let border_paint = border_paint(&border.fill.top, area);
+        draw_border_style(
+            canvas,
+            rect,
+            corner_radius,
+            border,
+            &outer,
+            &inner,
+            border.width.top,
+            border_paint,
+        );
+        return;
+    }
 
-    match border_shape(rect, corner_radius, border) {
+    // Otherwise draw each side separately, clipped from the outer corners to the inner corners
+    let (outer_rect, inner_rect) = (*outer.0.rect(), *inner.0.rect());
+    let sides = [
+        (
+            border.width.top,
+            &border.fill.top,
+            [
+                (outer_rect.left, outer_rect.top),
+                (outer_rect.right, outer_rect.top),
+                (inner_rect.right, inner_rect.top),
+                (inner_rect.left, inner_rect.top),
+            ],
+        ),
+        (
+            border.width.right,
+            &border.fill.right,
+            [
+                (outer_rect.right, outer_rect.top),
+                (outer_rect.right, outer_rect.bottom),
+                (inner_rect.right, inner_rect.bottom),
+                (inner_rect.right, inner_rect.top),
+            ],
+        ),
+        (
+            border.width.bottom,
+            &border.fill.bottom,
+            [
+                (outer_rect.right, outer_rect.bottom),
+                (outer_rect.left, outer_rect.bottom),
+                (inner_rect.left, inner_rect.bottom),
+                (inner_rect.right, inner_rect.bottom),
+            ],
+        ),
+        (
+            border.width.left,
+            &border.fill.left,
+            [
+                (outer_rect.left, outer_rect.bottom),
+                (outer_rect.left, outer_rect.top),
+                (inner_rect.left, inner_rect.top),
+                (inner_rect.left, inner_rect.bottom),
+            ],
+        ),
+    ];
+
+    for (width, fill, corners) in sides {
+        if width == 0.0 {
+            continue;
+        }
+
+        let mut clip_path = Path::new();
+        clip_path.move_to(corners[0]);
+        for corner in &corners[1..] {
+            clip_path.line_to(*corner);
+        }
+        clip_path.close();
+
+        canvas.save();
+        canvas.clip_path(&clip_path, ClipOp::Intersect, true);
+        draw_border_style(
+            canvas,
+            rect,
+            corner_radius,
+            border,
+            &outer,
+            &inner,
+            width,
+            border_paint(fill, area),
+        );
+        canvas.restore();
+    }
+}
+
+fn border_paint(fill: &Fill, area: Area) -> Paint {
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    fill.apply_to_paint(&mut paint, area);
+    paint
+}
+
+fn draw_border_shape(canvas: &Canvas, shape: BorderShape, paint: &Paint) {
+    match shape {
         BorderShape::DRRect(outer, inner) => {
-            canvas.draw_drrect(outer, inner, &border_paint);
+            canvas.draw_drrect(outer, inner, paint);
         }
         BorderShape::Path(path) => {
-            canvas.draw_path(&path, &border_paint);
+            canvas.draw_path(&path, paint);
         }
     }
 }
 
+/// Draw the [`BorderStyle`] of a side with the given width.
+#[allow(clippy::too_many_arguments)]
+fn draw_border_style(
+    canvas: &Canvas,
+    rect: Rect,
+    corner_radius: &CornerRadius,
+    border: &Border,
+    outer: &(RRect, CornerRadius),
+    inner: &(RRect, CornerRadius),
+    width: f32,
+    mut paint: Paint,
+) {
+    match border.style {
+        BorderStyle::Solid => {
+            paint.set_style(PaintStyle::Fill);
+            draw_border_shape(canvas, border_shape(rect, corner_radius, border), &paint);
+        }
+        BorderStyle::Dashed(dash) => {
+            let (length, gap) = dash
+                .map(|dash| (dash.length, dash.gap))
+                .unwrap_or((width * 3.0, width * 2.0));
+            paint.set_style(PaintStyle::Stroke);
+            paint.set_stroke_width(width);
+            paint.set_path_effect(PathEffect::dash(&[length, gap], 0.0));
+            canvas.draw_path(&stroke_path(outer, inner, 0.5), &paint);
+        }
+        BorderStyle::Dotted => {
+            // Zero-length dashes with round caps are drawn as circles
+            paint.set_style(PaintStyle::Stroke);
+            paint.set_stroke_width(width);
+            paint.set_stroke_cap(PaintCap::Round);
+            paint.set_path_effect(PathEffect::dash(&[0.0, width * 2.0], 0.0));
+            canvas.draw_path(&stroke_path(outer, inner, 0.5), &paint);
+        }
+        BorderStyle::Double => {
+            // Two lines of a third of the width each, with the remaining third between them
+            paint.set_style(PaintStyle::Stroke);
+            paint.set_stroke_width(width / 3.0);
+            canvas.draw_path(&stroke_path(outer, inner, 1.0 / 6.0), &paint);
+            canvas.draw_path(&stroke_path(outer, inner, 5.0 / 6.0), &paint);
+        }
+    }
+}
+
+/// Returns the `Path` going through the border at the given `progress`,
+/// where `0.0` is its outer edge and `1.0` its inner edge.
+fn stroke_path(
+    (outer_rrect, outer_corner_radius): &(RRect, CornerRadius),
+    (inner_rrect, inner_corner_radius): &(RRect, CornerRadius),
+    progress: f32,
+) -> Path {
+    let lerp = |outer: f32, inner: f32| outer + (inner - outer) * progress;
+    let lerp_radius = |outer: f32, inner: f32| lerp(outer.max(0.0), inner.max(0.0));
+
+    let (outer_rect, inner_rect) = (outer_rrect.rect(), inner_rrect.rect());
+    let rect = Rect::new(
+        lerp(outer_rect.left, inner_rect.left),
+        lerp(outer_rect.top, inner_rect.top),
+        lerp(outer_rect.right, inner_rect.right),
+        lerp(outer_rect.bottom, inner_rect.bottom),
+    );
+
+    let corner_radius = CornerRadius {
+        top_left: lerp_radius(outer_corner_radius.top_left, inner_corner_radius.top_left),
+        top_right: lerp_radius(outer_corner_radius.top_right, inner_corner_radius.top_right),
+        bottom_left: lerp_radius(
+            outer_corner_radius.bottom_left,
+            inner_corner_radius.bottom_left,
+        ),
+        bottom_right: lerp_radius(
+            outer_corner_radius.bottom_right,
+            inner_corner_radius.bottom_right,
+        ),
+        smoothing: outer_corner_radius.smoothing,
+    };
+
+    let rrect = RRect::new_rect_radii(
+        rect,
+        &[
+            (corner_radius.top_left, corner_radius.top_left).into(),
+            (corner_radius.top_right, corner_radius.top_right).into(),
+            (corner_radius.bottom_right, corner_radius.bottom_right).into(),
+            (corner_radius.bottom_left, corner_radius.bottom_left).into(),
+        ],
+    );
+
+    if corner_radius.smoothing > 0.0 {
+        let mut path = corner_radius.smoothed_path(rrect);
+        path.offset((rrect.rect().x(), rrect.rect().y()));
+        path
+    } else {
+        let mut path = Path::new();
+        path.add_rrect(rrect, None);
+        path
+    }
+}
+
 /// Returns a `Path` that will draw a [`Border`] around a base rectangle.
 ///
 /// We don't use Skia's stroking API here, since we might need different widths for each side.
@@ -44,6 +247,37 @@ pub fn border_shape(
     base_corner_radius: &CornerRadius,
     border: &Border,
 ) -> BorderShape {
+    let ((outer_rrect, outer_corner_radius), (inner_rrect, inner_corner_radius)) =
+        border_rrects(base_rect, base_corner_radius, border);
+
+    if base_corner_radius.smoothing > 0.0 {
+        let mut path = Path::new();
+        path.set_fill_type(PathFillType::EvenOdd);
+
+        path.add_path(
+            &outer_corner_radius.smoothed_path(outer_rrect),
+            SkPoint::new(outer_rrect.rect().x(), outer_rrect.rect().y()),
+            None,
+        );
+
+        path.add_path(
+            &inner_corner_radius.smoothed_path(inner_rrect),
+            SkPoint::new(inner_rrect.rect().x(), inner_rrect.rect().y()),
+            None,
+        );
+
+        BorderShape::Path(path)
+    } else {
+        BorderShape::DRRect(outer_rrect, inner_rrect)
+    }
+}
+
+/// Returns the outer and inner bounds of a [`Border`] around a base rectangle, along with their corner radius.
+fn border_rrects(
+    base_rect: Rect,
+    base_corner_radius: &CornerRadius,
+    border: &Border,
+) -> ((RRect, CornerRadius), (RRect, CornerRadius)) {
     let border_alignment = border.alignment;
     let border_width = border.width;
 
@@ -165,26 +399,10 @@ pub fn border_shape(
         (rrect, corner_radius)
     };
 
-    if base_corner_radius.smoothing > 0.0 {
-        let mut path = Path::new();
-        path.set_fill_type(PathFillType::EvenOdd);
-
-        path.add_path(
-            &outer_corner_radius.smoothed_path(outer_rrect),
-            SkPoint::new(outer_rrect.rect().x(), outer_rrect.rect().y()),
-            None,
-        );
-
-        path.add_path(
-            &inner_corner_radius.smoothed_path(inner_rrect),
-            SkPoint::new(inner_rrect.rect().x(), inner_rrect.rect().y()),
-            None,
-        );
-
-        BorderShape::Path(path)
-    } else {
-        BorderShape::DRRect(outer_rrect, inner_rrect)
-    }
+    (
+        (outer_rrect, outer_corner_radius),
+        (inner_rrect, inner_corner_radius),
+    )
 }
 
 fn outer_border_path_corner_radius(
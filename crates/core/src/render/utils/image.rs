@@ -6,12 +6,17 @@ use freya_native_core::prelude::NodeImmutable;
 use torin::prelude::Size2D;
 
 use crate::{
+    custom_attributes::AttributesBytes,
     dom::{
         DioxusNode,
+        ImageDecodeStatus,
         ImagesCache,
     },
     states::ImageState,
-    values::AspectRatio,
+    values::{
+        AspectRatio,
+        ImageDecoding,
+    },
 };
 
 pub struct ImageData {
@@ -19,6 +24,77 @@ pub struct ImageData {
     pub size: Size2D,
 }
 
+/// Get the size in which an image of the given original size is painted inside of the given area.
+fn get_image_size(image_state: &ImageState, image_size: Size2D, area_size: &Size2D) -> Size2D {
+    let width_ratio = area_size.width / image_size.width;
+    let height_ratio = area_size.height / image_size.height;
+
+    match image_state.aspect_ratio {
+        AspectRatio::Max => image_size * width_ratio.max(height_ratio),
+        AspectRatio::Min => image_size * width_ratio.min(height_ratio),
+        AspectRatio::Fit => image_size,
+        AspectRatio::None => *area_size,
+    }
+}
+
+/// Notify the decoding status of an `image` element through its `image_status` attribute, if it has one.
+pub fn report_image_status(node_ref: &DioxusNode, status: ImageDecodeStatus) {
+    if let Some(image_state) = node_ref.get::<ImageState>() {
+        report_status(&image_state, status);
+    }
+}
+
+fn report_status(image_state: &ImageState, status: ImageDecodeStatus) {
+    if let Some(status_ref) = &image_state.status_ref {
+        status_ref.report(status);
+    }
+}
+
+/// Get the size of the image of an `image` element without decoding it.
+pub fn measure_image(
+    node_ref: &DioxusNode,
+    area_size: &Size2D,
+    images_cache: &mut ImagesCache,
+) -> Option<Size2D> {
+    let image_state = node_ref.get::<ImageState>().unwrap();
+
+    match &image_state.image_data {
+        Some(bytes) if decodes_in_background(node_ref, &image_state, bytes, images_cache) => {
+            let Some(header) = images_cache
+                .decoder_mut()
+                .image_header(node_ref.id(), bytes)
+            else {
+                report_status(&image_state, ImageDecodeStatus::Failed);
+                return None;
+            };
+            Some(get_image_size(
+                &image_state,
+                Size2D::new(header.width as f32, header.height as f32),
+                area_size,
+            ))
+        }
+        _ => get_or_create_image(node_ref, area_size, images_cache).map(|image| image.size),
+    }
+}
+
+/// Images not using `decoding: "sync"` and animated images are decoded in background.
+fn decodes_in_background(
+    node_ref: &DioxusNode,
+    image_state: &ImageState,
+    bytes: &AttributesBytes,
+    images_cache: &mut ImagesCache,
+) -> bool {
+    image_state.decoding == ImageDecoding::Async
+        || images_cache
+            .decoder_mut()
+            .image_header(node_ref.id(), bytes)
+            .is_some_and(|header| header.is_animated())
+}
+
+/// Get the decoded image of an `image` element, decoding it if needed.
+///
+/// Images not using `decoding: "sync"` and animated images are decoded in background, so this returns `None`
+/// until their first decoding has finished. For animated images, this returns their current frame.
 pub fn get_or_create_image(
     node_ref: &DioxusNode,
     area_size: &Size2D,
@@ -26,16 +102,29 @@ pub fn get_or_create_image(
 ) -> Option<ImageData> {
     let image_state = node_ref.get::<ImageState>().unwrap();
 
+    if let Some(bytes) = &image_state.image_data {
+        if decodes_in_background(node_ref, &image_state, bytes, images_cache) {
+            return get_or_decode_image(node_ref, &image_state, bytes, area_size, images_cache);
+        }
+    }
+
     let mut get_or_create_image = |bytes: &[u8]| -> Option<Image> {
-        if let Some(image_cache_key) = &image_state.image_cache_key {
-            images_cache.get(image_cache_key).cloned().or_else(|| {
+        let image = if let Some(image_cache_key) = &image_state.image_cache_key {
+            images_cache.get(image_cache_key).or_else(|| {
                 Image::from_encoded(unsafe { Data::new_bytes(bytes) }).inspect(|image| {
                     images_cache.insert(image_cache_key.clone(), image.clone());
                 })
             })
         } else {
             Image::from_encoded(unsafe { Data::new_bytes(bytes) })
-        }
+        };
+        let status = if image.is_some() {
+            ImageDecodeStatus::Decoded
+        } else {
+            ImageDecodeStatus::Failed
+        };
+        report_status(&image_state, status);
+        image
     };
 
     let image = if let Some(image_ref) = &image_state.image_ref {
@@ -51,26 +140,74 @@ pub fn get_or_create_image(
         None
     }?;
 
-    let image_width = image.width() as f32;
-    let image_height = image.height() as f32;
+    let size = get_image_size(
+        &image_state,
+        Size2D::new(image.width() as f32, image.height() as f32),
+        area_size,
+    );
 
-    let width_ratio = area_size.width / image.width() as f32;
-    let height_ratio = area_size.height / image.height() as f32;
+    Some(ImageData { image, size })
+}
 
-    let size = match image_state.aspect_ratio {
-        AspectRatio::Max => {
-            let ratio = width_ratio.max(height_ratio);
+fn get_or_decode_image(
+    node_ref: &DioxusNode,
+    image_state: &ImageState,
+    bytes: &AttributesBytes,
+    area_size: &Size2D,
+    images_cache: &mut ImagesCache,
+) -> Option<ImageData> {
+    let Some(header) = images_cache
+        .decoder_mut()
+        .image_header(node_ref.id(), bytes)
+    else {
+        report_status(image_state, ImageDecodeStatus::Failed);
+        return None;
+    };
 
-            Size2D::new(image_width * ratio, image_height * ratio)
+    // Static images decoded with their original size can be shared with other elements
+    let shareable = !image_state.downsample && !header.is_animated();
+
+    if shareable {
+        if let Some(image) = image_state
+            .image_cache_key
+            .as_ref()
+            .and_then(|image_cache_key| images_cache.get(image_cache_key))
+        {
+            let size = get_image_size(
+                image_state,
+                Size2D::new(image.width() as f32, image.height() as f32),
+                area_size,
+            );
+            return Some(ImageData { image, size });
         }
-        AspectRatio::Min => {
-            let ratio = width_ratio.min(height_ratio);
+    }
+
+    let size = get_image_size(
+        image_state,
+        Size2D::new(header.width as f32, header.height as f32),
+        area_size,
+    );
 
-            Size2D::new(image_width * ratio, image_height * ratio)
+    // Never decode images bigger than their original size
+    let decode_size = image_state.downsample.then(|| {
+        (
+            (size.width.ceil() as i32).min(header.width).max(1),
+            (size.height.ceil() as i32).min(header.height).max(1),
+        )
+    });
+
+    let decoder = images_cache.decoder_mut();
+    let image = decoder.get_or_decode(node_ref.id(), bytes, decode_size);
+    if let Some(status) = decoder.status(node_ref.id()) {
+        report_status(image_state, status);
+    }
+    let image = image?;
+
+    if let (Some(image_cache_key), true) = (&image_state.image_cache_key, shareable) {
+        if decoder.status(node_ref.id()) == Some(ImageDecodeStatus::Decoded) {
+            images_cache.insert(image_cache_key.clone(), image.clone());
         }
-        AspectRatio::Fit => Size2D::new(image_width, image_height),
-        AspectRatio::None => *area_size,
-    };
+    }
 
     Some(ImageData { image, size })
 }
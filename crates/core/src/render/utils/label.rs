@@ -27,6 +27,7 @@ pub fn create_label(
 
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align.into());
+    paragraph_style.set_text_direction(font_style.text_direction());
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
     paragraph_style.set_text_height_behavior(font_style.text_height.into());
@@ -59,13 +60,8 @@ pub fn create_label(
     );
 
     // Relayout the paragraph so that its aligned based on its longest width
-    match font_style.text_align {
-        TextAlign::Center | TextAlign::Justify | TextAlign::Right | TextAlign::End
-            if torin_node.width.inner_sized() =>
-        {
-            paragraph.layout(paragraph.longest_line() + 1.);
-        }
-        _ => {}
+    if !font_style.is_left_aligned() && torin_node.width.inner_sized() {
+        paragraph.layout(paragraph.longest_line() + 1.);
     }
 
     ParagraphData {
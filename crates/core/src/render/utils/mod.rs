@@ -3,9 +3,11 @@ mod image;
 mod label;
 mod paragraph;
 mod shadows;
+mod transform;
 
 pub use borders::*;
 pub use image::*;
 pub use label::*;
 pub use paragraph::*;
 pub use shadows::*;
+pub use transform::*;
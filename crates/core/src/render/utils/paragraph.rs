@@ -42,6 +42,7 @@ pub fn create_paragraph(
 
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align.into());
+    paragraph_style.set_text_direction(font_style.text_direction());
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
     paragraph_style.set_text_height_behavior(font_style.text_height.into());
@@ -91,9 +92,10 @@ pub fn create_paragraph(
         },
     );
 
-    let width = match font_style.text_align {
-        TextAlign::Start | TextAlign::Left => paragraph.longest_line(),
-        _ => paragraph.max_width(),
+    let width = if font_style.is_left_aligned() {
+        paragraph.longest_line()
+    } else {
+        paragraph.max_width()
     };
 
     ParagraphData {
@@ -150,26 +152,105 @@ pub fn draw_cursor(
 ) -> Option<()> {
     let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
 
-    let cursor = node_cursor_state.position?;
-    let cursor_color = node_cursor_state.color;
-    let cursor_position = cursor as usize;
+    let rect = cursor_rect(area, paragraph, node_ref)?;
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(node_cursor_state.color);
+
+    canvas.draw_rect(rect, &paint);
+
+    Some(())
+}
+
+/// Get the rect of the cursor of a paragraph
+pub fn cursor_rect(area: &Area, paragraph: &Paragraph, node_ref: &DioxusNode) -> Option<Rect> {
+    let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+
+    let cursor_position = node_cursor_state.position? as usize;
 
     let cursor_rects = paragraph.get_rects_for_range(
         cursor_position..cursor_position + 1,
         RectHeightStyle::Tight,
         RectWidthStyle::Tight,
     );
-    let cursor_rect = cursor_rects.first()?;
+
+    if let Some(cursor_rect) = cursor_rects.first() {
+        return Some(align_highlights_and_cursor_paragraph(
+            node_ref,
+            area,
+            paragraph,
+            cursor_rect,
+            Some(1.0),
+        ));
+    }
+
+    // There is no glyph after the cursor when it's at the end of the text,
+    // so it's placed right after the previous glyph instead
+    let previous_rects = paragraph.get_rects_for_range(
+        cursor_position.checked_sub(1)?..cursor_position,
+        RectHeightStyle::Tight,
+        RectWidthStyle::Tight,
+    );
+    let previous_rect = previous_rects.first()?;
 
     let rect =
-        align_highlights_and_cursor_paragraph(node_ref, area, paragraph, cursor_rect, Some(1.0));
+        align_highlights_and_cursor_paragraph(node_ref, area, paragraph, previous_rect, Some(1.0));
+    let previous_width = previous_rect.rect.right - previous_rect.rect.left;
+
+    Some(Rect::new(
+        rect.left + previous_width,
+        rect.top,
+        rect.right + previous_width,
+        rect.bottom,
+    ))
+}
+
+/// Underline the text being composed with an IME
+pub fn draw_preedit(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_ref: &DioxusNode,
+    scale_factor: f32,
+) -> Option<()> {
+    let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
+
+    let preedit = node_cursor_state.preedit.as_ref()?;
 
     let mut paint = Paint::default();
     paint.set_anti_alias(true);
     paint.set_style(PaintStyle::Fill);
-    paint.set_color(cursor_color);
+    paint.set_color(node_cursor_state.color);
 
-    canvas.draw_rect(rect, &paint);
+    for (from, to) in preedit.iter() {
+        let preedit_rects = paragraph.get_rects_for_range(
+            *from.min(to)..*from.max(to),
+            RectHeightStyle::Tight,
+            RectWidthStyle::Tight,
+        );
+
+        for preedit_rect in preedit_rects {
+            let rect = align_highlights_and_cursor_paragraph(
+                node_ref,
+                area,
+                paragraph,
+                &preedit_rect,
+                None,
+            );
+
+            canvas.draw_rect(
+                Rect::new(
+                    rect.left,
+                    rect.bottom - scale_factor,
+                    rect.right,
+                    rect.bottom,
+                ),
+                &paint,
+            );
+        }
+    }
 
     Some(())
 }
@@ -0,0 +1,81 @@
+use freya_engine::prelude::*;
+use freya_native_core::NodeId;
+use torin::prelude::{
+    Area,
+    Torin,
+};
+
+use crate::{
+    states::TransformState,
+    values::TransformOperation,
+};
+
+/// Get the point around which the transforms of the given node are applied.
+pub fn get_transform_origin(
+    node_id: NodeId,
+    transform_state: &TransformState,
+    layout: &Torin<NodeId>,
+    scale_factor: f32,
+) -> Option<SkPoint> {
+    let area = layout.get(node_id)?.visible_area();
+    let origin = transform_state
+        .transform_origin_of(node_id)
+        .copied()
+        .unwrap_or_default();
+    let origin = origin.resolve(&area, scale_factor);
+    Some(SkPoint::new(origin.x, origin.y))
+}
+
+/// Get the matrix of all the transforms inherited by a node, composed from its outermost ancestor to the node itself.
+pub fn get_transform_matrix(
+    transform_state: &TransformState,
+    layout: &Torin<NodeId>,
+    scale_factor: f32,
+) -> Matrix {
+    let mut matrix = Matrix::new_identity();
+
+    for (id, transform) in &transform_state.transforms {
+        let Some(origin) = get_transform_origin(*id, transform_state, layout, scale_factor) else {
+            continue;
+        };
+
+        match transform {
+            TransformOperation::Translate(translate_x, translate_y) => {
+                matrix.pre_translate((translate_x * scale_factor, translate_y * scale_factor));
+            }
+            TransformOperation::Rotate(degs) => {
+                matrix.pre_rotate(*degs, origin);
+            }
+            TransformOperation::Scale(scale_x, scale_y) => {
+                matrix.pre_scale((*scale_x, *scale_y), origin);
+            }
+            TransformOperation::Skew(skew_x, skew_y) => {
+                matrix.pre_skew(
+                    (skew_x.to_radians().tan(), skew_y.to_radians().tan()),
+                    origin,
+                );
+            }
+            TransformOperation::Matrix(transform_matrix) => {
+                matrix.pre_translate(origin);
+                matrix.pre_concat(&transform_matrix.to_matrix(scale_factor));
+                matrix.pre_translate(-origin);
+            }
+        }
+    }
+
+    matrix
+}
+
+/// Get the area covered by the given area once transformed with a matrix.
+pub fn map_area(matrix: &Matrix, area: &Area) -> Area {
+    let (rect, _) = matrix.map_rect(Rect::new(
+        area.min_x(),
+        area.min_y(),
+        area.max_x(),
+        area.max_y(),
+    ));
+    Area::new(
+        (rect.left, rect.top).into(),
+        (rect.right - rect.left, rect.bottom - rect.top).into(),
+    )
+}
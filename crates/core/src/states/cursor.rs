@@ -55,6 +55,7 @@ pub struct CursorState {
     pub highlights: Option<Vec<(usize, usize)>>,
     pub highlight_color: Color,
     pub highlight_mode: HighlightMode,
+    pub preedit: Option<Vec<(usize, usize)>>,
     #[cfg_attr(feature = "serde", serde(skip_deserializing, skip_serializing))]
     pub cursor_ref: Option<CursorReference>,
 }
@@ -69,6 +70,7 @@ impl Default for CursorState {
             highlights: None,
             highlight_color: Color::from_rgb(87, 108, 188),
             highlight_mode: HighlightMode::default(),
+            preedit: None,
             cursor_ref: None,
         }
     }
@@ -110,6 +112,14 @@ impl ParseAttribute for CursorState {
                     return Err(ParseError);
                 }
             }
+            AttributeName::Preedit => {
+                if let Some(CustomAttributeValues::TextHighlights(preedit)) = attr.value.as_custom()
+                {
+                    self.preedit = Some(preedit.clone());
+                } else {
+                    return Err(ParseError);
+                }
+            }
             AttributeName::HighlightColor => {
                 self.highlight_color = Color::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
@@ -151,6 +161,7 @@ impl State<CustomAttributeValues> for CursorState {
             AttributeName::Highlights,
             AttributeName::HighlightColor,
             AttributeName::HighlightMode,
+            AttributeName::Preedit,
             AttributeName::CursorReference,
         ]))
         .with_tag();
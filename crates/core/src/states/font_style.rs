@@ -18,7 +18,10 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
-use torin::torin::Torin;
+use torin::{
+    layout_direction::LayoutDirection,
+    torin::Torin,
+};
 
 use crate::{
     custom_attributes::CustomAttributeValues,
@@ -64,6 +67,7 @@ pub struct FontStyleState {
     pub max_lines: Option<usize>,
     pub text_overflow: TextOverflow,
     pub text_height: TextHeightBehavior,
+    pub layout_direction: LayoutDirection,
 }
 
 impl FontStyleState {
@@ -110,6 +114,23 @@ impl FontStyleState {
 
         text_style
     }
+
+    /// Base direction of the text, which follows the layout direction.
+    pub fn text_direction(&self) -> TextDirection {
+        match self.layout_direction {
+            LayoutDirection::Ltr => TextDirection::LTR,
+            LayoutDirection::Rtl => TextDirection::RTL,
+        }
+    }
+
+    /// Whether the text ends up aligned to the left, as `start` depends on the text direction.
+    pub fn is_left_aligned(&self) -> bool {
+        match self.text_align {
+            TextAlign::Left => true,
+            TextAlign::Start => !self.layout_direction.is_rtl(),
+            _ => false,
+        }
+    }
 }
 
 impl Default for FontStyleState {
@@ -132,6 +153,7 @@ impl Default for FontStyleState {
             max_lines: None,
             text_overflow: TextOverflow::default(),
             text_height: TextHeightBehavior::DisableAll,
+            layout_direction: LayoutDirection::default(),
         }
     }
 }
@@ -243,6 +265,10 @@ impl ParseAttribute for FontStyleState {
                 self.text_height =
                     TextHeightBehavior::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::LayoutDirection => {
+                self.layout_direction =
+                    LayoutDirection::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
             _ => {}
         }
 
@@ -277,6 +303,7 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
             AttributeName::TextHeight,
+            AttributeName::LayoutDirection,
         ]));
 
     fn update<'a>(
@@ -29,6 +29,7 @@ use crate::{
         AttributesBytes,
         CustomAttributeValues,
         ImageReference,
+        ImageStatusReference,
     },
     dom::{
         CompositorDirtyNodes,
@@ -43,11 +44,13 @@ use crate::{
     values::{
         AspectRatio,
         ImageCover,
+        ImageDecoding,
+        ImageRepeat,
         SamplingMode,
     },
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Component)]
+#[derive(Debug, Clone, PartialEq, Component)]
 pub struct ImageState {
     pub image_sampling: SamplingMode,
     pub image_data: Option<AttributesBytes>,
@@ -55,6 +58,29 @@ pub struct ImageState {
     pub aspect_ratio: AspectRatio,
     pub image_cover: ImageCover,
     pub image_ref: Option<ImageReference>,
+    pub decoding: ImageDecoding,
+    pub downsample: bool,
+    pub playing: bool,
+    pub repeat: ImageRepeat,
+    pub status_ref: Option<ImageStatusReference>,
+}
+
+impl Default for ImageState {
+    fn default() -> Self {
+        Self {
+            image_sampling: SamplingMode::default(),
+            image_data: None,
+            image_cache_key: None,
+            aspect_ratio: AspectRatio::default(),
+            image_cover: ImageCover::default(),
+            image_ref: None,
+            decoding: ImageDecoding::default(),
+            downsample: false,
+            playing: true,
+            repeat: ImageRepeat::default(),
+            status_ref: None,
+        }
+    }
 }
 
 impl ParseAttribute for ImageState {
@@ -93,6 +119,35 @@ impl ParseAttribute for ImageState {
                     self.image_ref = Some(reference.clone());
                 }
             }
+            AttributeName::ImageStatus => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::ImageStatus(reference)) =
+                    attr.value
+                {
+                    self.status_ref = Some(reference.clone());
+                }
+            }
+            AttributeName::Decoding => {
+                self.decoding = ImageDecoding::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::Downsample => {
+                self.downsample = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse::<bool>()
+                    .map_err(|_| ParseError)?;
+            }
+            AttributeName::Playing => {
+                self.playing = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse::<bool>()
+                    .map_err(|_| ParseError)?;
+            }
+            AttributeName::Repeat => {
+                self.repeat = ImageRepeat::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
             _ => {}
         }
 
@@ -116,6 +171,11 @@ impl State<CustomAttributeValues> for ImageState {
             AttributeName::AspectRatio,
             AttributeName::ImageCover,
             AttributeName::ImageReference,
+            AttributeName::Decoding,
+            AttributeName::Downsample,
+            AttributeName::Playing,
+            AttributeName::Repeat,
+            AttributeName::ImageStatus,
         ]));
 
     fn allow_node(node_type: &NodeType<CustomAttributeValues>) -> bool {
@@ -147,13 +207,26 @@ impl State<CustomAttributeValues> for ImageState {
                 .unwrap()
                 .invalidate(node_view.node_id());
 
-            if let Some(image_cache_key) = &self.image_cache_key {
+            // Changing how an animated image is played or where its status is reported
+            // does not require decoding or measuring it again
+            let only_playback_changed = ImageState {
+                playing: self.playing,
+                repeat: self.repeat,
+                status_ref: self.status_ref.clone(),
+                ..image.clone()
+            } == *self;
+
+            if !only_playback_changed {
                 let images_cache = context.get::<Arc<Mutex<ImagesCache>>>().unwrap();
-                images_cache.lock().unwrap().remove(image_cache_key);
-            }
+                let mut images_cache = images_cache.lock().unwrap();
+                if let Some(image_cache_key) = &self.image_cache_key {
+                    images_cache.remove(image_cache_key);
+                }
+                images_cache.decoder_mut().remove(node_view.node_id());
 
-            let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
-            torin_layout.lock().unwrap().invalidate(node_view.node_id());
+                let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+                torin_layout.lock().unwrap().invalidate(node_view.node_id());
+            }
         }
 
         *self = image;
@@ -34,6 +34,10 @@ use crate::{
 pub struct LayerState {
     pub layer: i16,
     pub layer_for_children: i16,
+    pub layer_cache: bool,
+    /// Closest ancestor (or itself) whose rendered subtree is cached, see `layer_cache`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cached_by: Option<NodeId>,
 }
 
 impl ParseAttribute for LayerState {
@@ -41,7 +45,6 @@ impl ParseAttribute for LayerState {
         &mut self,
         attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
     ) -> Result<(), ParseError> {
-        #[allow(clippy::single_match)]
         match attr.attribute {
             AttributeName::Layer => {
                 let layer = LayerMode::parse(attr.value.as_text().ok_or(ParseError)?)?;
@@ -57,6 +60,14 @@ impl ParseAttribute for LayerState {
                     _ => {}
                 }
             }
+            AttributeName::LayerCache => {
+                self.layer_cache = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse::<bool>()
+                    .map_err(|_| ParseError)?;
+            }
             _ => {}
         }
 
@@ -73,7 +84,10 @@ impl State<CustomAttributeValues> for LayerState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Layer]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Layer,
+            AttributeName::LayerCache,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -91,10 +105,12 @@ impl State<CustomAttributeValues> for LayerState {
         let root_id = context.get::<NodeId>().unwrap();
         let layers = context.get::<Arc<Mutex<Layers>>>().unwrap();
         let inherited_layer = parent.map(|(p,)| p.layer_for_children).unwrap_or(0i16);
+        let inherited_cached_by = parent.and_then(|(p,)| p.cached_by);
 
         let mut layer_state = LayerState {
             layer: node_view.height() as i16 - inherited_layer,
             layer_for_children: inherited_layer,
+            ..Default::default()
         };
 
         if let Some(attributes) = node_view.attributes() {
@@ -103,6 +119,10 @@ impl State<CustomAttributeValues> for LayerState {
             }
         }
 
+        // The outermost cached element renders the whole subtree
+        layer_state.cached_by =
+            inherited_cached_by.or(layer_state.layer_cache.then_some(node_view.node_id()));
+
         let changed = &layer_state != self;
 
         let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
@@ -32,6 +32,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    values::parse_grid_tracks,
 };
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -58,6 +59,11 @@ pub struct LayoutState {
     pub node_ref: Option<NodeReference>,
     pub node_id: NodeId,
     pub spacing: Length,
+    pub wrap_spacing: Length,
+    pub grid_columns: Vec<Size>,
+    pub grid_rows: Vec<Size>,
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
 }
 
 impl ParseAttribute for LayoutState {
@@ -185,6 +191,27 @@ impl ParseAttribute for LayoutState {
                         .map_err(|_| ParseError)?,
                 );
             }
+            AttributeName::GridColumns => {
+                self.grid_columns = parse_grid_tracks(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridRows => {
+                self.grid_rows = parse_grid_tracks(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridColumn => {
+                self.grid_column = GridPlacement::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridRow => {
+                self.grid_row = GridPlacement::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::WrapSpacing => {
+                self.wrap_spacing = Length::new(
+                    attr.value
+                        .as_text()
+                        .ok_or(ParseError)?
+                        .parse::<f32>()
+                        .map_err(|_| ParseError)?,
+                );
+            }
             _ => {}
         }
         Ok(())
@@ -224,6 +251,11 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::PositionLeft,
             AttributeName::Content,
             AttributeName::Spacing,
+            AttributeName::WrapSpacing,
+            AttributeName::GridColumns,
+            AttributeName::GridRows,
+            AttributeName::GridColumn,
+            AttributeName::GridRow,
         ]));
 
     fn update<'a>(
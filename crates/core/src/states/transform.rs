@@ -27,6 +27,11 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    values::{
+        TransformMatrix,
+        TransformOperation,
+        TransformOrigin,
+    },
 };
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -34,13 +39,46 @@ use crate::{
 pub struct TransformState {
     pub node_id: NodeId,
     pub opacities: Vec<f32>,
-    pub rotations: Vec<(NodeId, f32)>,
-    pub scales: Vec<(NodeId, f32, f32)>,
+    /// Inherited transforms and the nodes that apply them, from the outermost ancestor to this node.
+    pub transforms: Vec<(NodeId, TransformOperation)>,
+    pub transform_origins: Vec<(NodeId, TransformOrigin)>,
     #[cfg_attr(feature = "serde", serde(skip_serializing, skip_deserializing))]
     pub blend_mode: Option<BlendMode>,
     pub backdrop_blur: f32,
 }
 
+impl TransformState {
+    /// Get the transform origin of the given node, if it has a custom one.
+    pub fn transform_origin_of(&self, node_id: NodeId) -> Option<&TransformOrigin> {
+        self.transform_origins
+            .iter()
+            .find_map(|(id, origin)| (*id == node_id).then_some(origin))
+    }
+
+    /// Get the rotation applied by the given node, if any.
+    pub fn rotation_of(&self, node_id: NodeId) -> Option<f32> {
+        self.transforms
+            .iter()
+            .find_map(|(id, transform)| match transform {
+                TransformOperation::Rotate(degs) if *id == node_id => Some(*degs),
+                _ => None,
+            })
+    }
+
+    /// Check if there is any transform to apply besides the opacity.
+    pub fn has_transforms(&self) -> bool {
+        !self.transforms.is_empty()
+    }
+}
+
+fn parse_degrees(value: &str) -> Result<f32, ParseError> {
+    value
+        .strip_suffix("deg")
+        .ok_or(ParseError)?
+        .parse::<f32>()
+        .map_err(|_| ParseError)
+}
+
 impl ParseAttribute for TransformState {
     fn parse_attribute(
         &mut self,
@@ -54,7 +92,8 @@ impl ParseAttribute for TransformState {
                         .trim_end_matches("deg")
                         .parse::<f32>()
                         .map_err(|_| ParseError)?;
-                    self.rotations.push((self.node_id, rotation));
+                    self.transforms
+                        .push((self.node_id, TransformOperation::Rotate(rotation)));
                 }
             }
             AttributeName::Opacity => {
@@ -73,8 +112,45 @@ impl ParseAttribute for TransformState {
                     let scale_y = y.parse::<f32>().map_err(|_| ParseError)?;
                     (scale_x, scale_y)
                 };
-                self.scales
-                    .push((self.node_id, scale_x.max(0.0), scale_y.max(0.0)));
+                self.transforms.push((
+                    self.node_id,
+                    TransformOperation::Scale(scale_x.max(0.0), scale_y.max(0.0)),
+                ));
+            }
+            AttributeName::Translate => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                let (translate_x, translate_y) = match value.trim().split_once(' ') {
+                    Some((x, y)) => (
+                        x.parse::<f32>().map_err(|_| ParseError)?,
+                        y.trim().parse::<f32>().map_err(|_| ParseError)?,
+                    ),
+                    None => (value.trim().parse::<f32>().map_err(|_| ParseError)?, 0.),
+                };
+                self.transforms.push((
+                    self.node_id,
+                    TransformOperation::Translate(translate_x, translate_y),
+                ));
+            }
+            AttributeName::Skew => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                let (skew_x, skew_y) = match value.trim().split_once(' ') {
+                    Some((x, y)) => (parse_degrees(x)?, parse_degrees(y.trim())?),
+                    None => (parse_degrees(value.trim())?, 0.),
+                };
+                self.transforms
+                    .push((self.node_id, TransformOperation::Skew(skew_x, skew_y)));
+            }
+            AttributeName::Transform => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transforms.push((
+                    self.node_id,
+                    TransformOperation::Matrix(TransformMatrix::parse(value)?),
+                ));
+            }
+            AttributeName::TransformOrigin => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transform_origins
+                    .push((self.node_id, TransformOrigin::parse(value)?));
             }
 
             AttributeName::BlendMode => {
@@ -108,6 +184,10 @@ impl State<CustomAttributeValues> for TransformState {
             AttributeName::Rotate,
             AttributeName::Opacity,
             AttributeName::Scale,
+            AttributeName::Translate,
+            AttributeName::Skew,
+            AttributeName::Transform,
+            AttributeName::TransformOrigin,
             AttributeName::AspectRatio,
             AttributeName::ImageCover,
             AttributeName::BlendMode,
@@ -133,12 +213,18 @@ impl State<CustomAttributeValues> for TransformState {
             ..inherited_transform
         };
 
+        let inherited_transforms = transform_state.transforms.len();
+
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
                 transform_state.parse_safe(attr);
             }
         }
 
+        // The transforms of this node don't depend on the order of its attributes
+        transform_state.transforms[inherited_transforms..]
+            .sort_by_key(|(_, transform)| transform.order());
+
         let changed = transform_state != *self;
 
         let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
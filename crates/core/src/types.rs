@@ -6,7 +6,10 @@ use freya_native_core::{
     events::EventName,
     NodeId,
 };
-use ragnarok::ProcessedEvents;
+use ragnarok::{
+    ExecutedEvents,
+    ProcessedEvents,
+};
 use tokio::sync::{
     mpsc::{
         UnboundedReceiver,
@@ -37,5 +40,8 @@ pub type EventEmitter =
 pub type EventReceiver =
     UnboundedReceiver<ProcessedEvents<NodeId, EventName, DomEvent, PlatformEvent>>;
 
+/// Events emitted to the VirtualDOM in a run of the executor, along with the potential events and their outcome
+pub type DomExecutedEvents = ExecutedEvents<NodeId, EventName, DomEvent, PlatformEvent>;
+
 /// Queued list of events to be processed by Freya.
 pub type EventsQueue = Vec<PlatformEvent>;
@@ -15,9 +15,10 @@ use crate::{
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Border {
-    pub fill: Fill,
+    pub fill: BorderFill,
     pub width: BorderWidth,
     pub alignment: BorderAlignment,
+    pub style: BorderStyle,
 }
 
 impl Border {
@@ -27,7 +28,57 @@ impl Border {
             && self.width.left == 0.0
             && self.width.bottom == 0.0
             && self.width.right == 0.0)
-            && self.fill != Fill::Color(Color::TRANSPARENT)
+            && !self.fill.is_transparent()
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct BorderFill {
+    pub top: Fill,
+    pub right: Fill,
+    pub bottom: Fill,
+    pub left: Fill,
+}
+
+impl BorderFill {
+    /// Use the same fill for every side.
+    pub fn all(fill: Fill) -> Self {
+        Self {
+            top: fill.clone(),
+            right: fill.clone(),
+            bottom: fill.clone(),
+            left: fill,
+        }
+    }
+
+    /// Whether every side has the same fill.
+    #[inline]
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right && self.top == self.bottom && self.top == self.left
+    }
+
+    #[inline]
+    pub fn is_transparent(&self) -> bool {
+        let transparent = Fill::Color(Color::TRANSPARENT);
+        self.top == transparent
+            && self.right == transparent
+            && self.bottom == transparent
+            && self.left == transparent
+    }
+}
+
+impl fmt::Display for BorderFill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_uniform() {
+            self.top.fmt(f)
+        } else {
+            write!(
+                f,
+                "{} {} {} {}",
+                self.top, self.right, self.bottom, self.left
+            )
+        }
     }
 }
 
@@ -40,6 +91,14 @@ pub struct BorderWidth {
     pub left: f32,
 }
 
+impl BorderWidth {
+    /// Whether every side has the same width.
+    #[inline]
+    pub fn is_uniform(&self) -> bool {
+        self.top == self.right && self.top == self.bottom && self.top == self.left
+    }
+}
+
 impl Scaled for BorderWidth {
     fn scale(&mut self, scale_factor: f32) {
         self.top *= scale_factor;
@@ -89,132 +148,196 @@ impl fmt::Display for BorderAlignment {
     }
 }
 
+/// Lengths of the dashes and the gaps between them of a [BorderStyle::Dashed] border.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BorderDash {
+    pub length: f32,
+    pub gap: f32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum BorderStyle {
+    #[default]
+    Solid,
+    /// Dashes with the given pattern, or one proportional to the border width if none is specified.
+    Dashed(Option<BorderDash>),
+    Dotted,
+    Double,
+}
+
+impl Parse for BorderStyle {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "solid" => BorderStyle::Solid,
+            "dashed" => BorderStyle::Dashed(None),
+            "dotted" => BorderStyle::Dotted,
+            "double" => BorderStyle::Double,
+            // dashed(<length> <gap>?)
+            value => {
+                let pattern = value
+                    .strip_prefix("dashed(")
+                    .and_then(|value| value.strip_suffix(')'))
+                    .ok_or(ParseError)?
+                    .split(|c: char| c == ',' || c.is_ascii_whitespace())
+                    .filter(|value| !value.is_empty())
+                    .map(|value| value.parse::<f32>().map_err(|_| ParseError))
+                    .collect::<Result<Vec<f32>, ParseError>>()?;
+
+                let (length, gap) = match pattern[..] {
+                    [length] => (length, length),
+                    [length, gap] => (length, gap),
+                    _ => return Err(ParseError),
+                };
+
+                if length <= 0.0 || gap < 0.0 {
+                    return Err(ParseError);
+                }
+
+                BorderStyle::Dashed(Some(BorderDash { length, gap }))
+            }
+        })
+    }
+}
+
+impl fmt::Display for BorderStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorderStyle::Solid => f.write_str("solid"),
+            BorderStyle::Dashed(None) => f.write_str("dashed"),
+            BorderStyle::Dashed(Some(dash)) => write!(f, "dashed({} {})", dash.length, dash.gap),
+            BorderStyle::Dotted => f.write_str("dotted"),
+            BorderStyle::Double => f.write_str("double"),
+        }
+    }
+}
+
+impl Scaled for BorderStyle {
+    fn scale(&mut self, scale_factor: f32) {
+        if let BorderStyle::Dashed(Some(dash)) = self {
+            dash.length *= scale_factor;
+            dash.gap *= scale_factor;
+        }
+    }
+}
+
 impl Parse for Border {
     fn parse(value: &str) -> Result<Self, ParseError> {
         if value == "none" {
             return Ok(Self::default());
         }
 
-        let mut border_values = value.split_ascii_whitespace_excluding_group('(', ')');
+        let border_values = value
+            .split_ascii_whitespace_excluding_group('(', ')')
+            .collect::<Vec<&str>>();
 
-        Ok(match border_values.clone().count() {
-            // <width> <style> <fill>
-            3 => {
-                let width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: width,
-                        left: width,
-                        bottom: width,
-                        right: width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
-                }
-            }
+        // <width(s)> <alignment> <style>? <fill(s)>
+        let widths_count = border_values
+            .iter()
+            .take_while(|value| value.parse::<f32>().is_ok())
+            .count();
+        let (widths, border_values) = border_values.split_at(widths_count);
 
-            // <vertical> <horizontal> <solid> <fill>
-            4 => {
-                let vertical_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let horizontal_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: vertical_width,
-                        left: horizontal_width,
-                        bottom: vertical_width,
-                        right: horizontal_width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
-                }
+        let widths = widths
+            .iter()
+            .map(|width| width.parse::<f32>().map_err(|_| ParseError))
+            .collect::<Result<Vec<f32>, ParseError>>()?;
+
+        let width = match widths[..] {
+            [width] => BorderWidth {
+                top: width,
+                right: width,
+                bottom: width,
+                left: width,
+            },
+            [vertical, horizontal] => BorderWidth {
+                top: vertical,
+                right: horizontal,
+                bottom: vertical,
+                left: horizontal,
+            },
+            [top, horizontal, bottom] => BorderWidth {
+                top,
+                right: horizontal,
+                bottom,
+                left: horizontal,
+            },
+            [top, right, bottom, left] => BorderWidth {
+                top,
+                right,
+                bottom,
+                left,
+            },
+            _ => return Err(ParseError),
+        };
+
+        let (alignment, border_values) = match border_values {
+            // The alignment can be omitted when specifying a style
+            [style, ..] if BorderStyle::parse(style).is_ok() => {
+                (BorderAlignment::default(), border_values)
             }
-            // <top> <horizontal> <bottom> <style> <fill>
-            5 => {
-                let top_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let horizontal_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let bottom_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: top_width,
-                        left: horizontal_width,
-                        bottom: bottom_width,
-                        right: horizontal_width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
-                }
+            [alignment, rest @ ..] => (BorderAlignment::parse(alignment)?, rest),
+            [] => return Err(ParseError),
+        };
+
+        let (style, border_values) = match border_values {
+            [style, rest @ ..] if BorderStyle::parse(style).is_ok() => {
+                (BorderStyle::parse(style)?, rest)
             }
-            // <top> <right> <bottom> <left> <style> <fill>
-            6 => Border {
-                width: BorderWidth {
-                    top: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    right: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    bottom: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    left: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                },
-                alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                    .map_err(|_| ParseError)?,
+            _ => (BorderStyle::default(), border_values),
+        };
+
+        let fills = border_values
+            .iter()
+            .map(|fill| Fill::parse(fill).map_err(|_| ParseError))
+            .collect::<Result<Vec<Fill>, ParseError>>()?;
+
+        let fill = match &fills[..] {
+            [fill] => BorderFill::all(fill.clone()),
+            [vertical, horizontal] => BorderFill {
+                top: vertical.clone(),
+                right: horizontal.clone(),
+                bottom: vertical.clone(),
+                left: horizontal.clone(),
+            },
+            [top, horizontal, bottom] => BorderFill {
+                top: top.clone(),
+                right: horizontal.clone(),
+                bottom: bottom.clone(),
+                left: horizontal.clone(),
+            },
+            [top, right, bottom, left] => BorderFill {
+                top: top.clone(),
+                right: right.clone(),
+                bottom: bottom.clone(),
+                left: left.clone(),
             },
             _ => return Err(ParseError),
+        };
+
+        Ok(Border {
+            fill,
+            width,
+            alignment,
+            style,
         })
     }
 }
 
 impl fmt::Display for Border {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.width, self.alignment, self.fill,)
+        write!(
+            f,
+            "{} {} {} {}",
+            self.width, self.alignment, self.style, self.fill,
+        )
     }
 }
 
 impl Scaled for Border {
     fn scale(&mut self, scale_factor: f32) {
         self.width.scale(scale_factor);
+        self.style.scale(scale_factor);
     }
 }
@@ -10,6 +10,8 @@ impl Parse for Content {
         Ok(match value {
             "fit" => Content::Fit,
             "flex" => Content::Flex,
+            "wrap" => Content::Wrap,
+            "grid" => Content::Grid,
             _ => Content::Normal,
         })
     }
@@ -0,0 +1,49 @@
+use torin::{
+    grid::GridPlacement,
+    size::Size,
+};
+
+use crate::parsing::{
+    ExtSplit,
+    Parse,
+    ParseError,
+};
+
+impl Parse for GridPlacement {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if value == "auto" {
+            return Ok(GridPlacement::default());
+        }
+
+        let mut values = value.split_ascii_whitespace();
+
+        let start = match values.clone().next() {
+            Some("span") => None,
+            Some(start) => {
+                values.next();
+                Some(start.parse::<usize>().map_err(|_| ParseError)?)
+            }
+            None => return Err(ParseError),
+        };
+
+        let span = match values.next() {
+            Some("span") => values
+                .next()
+                .ok_or(ParseError)?
+                .parse::<usize>()
+                .map_err(|_| ParseError)?,
+            Some(_) => return Err(ParseError),
+            None => 1,
+        };
+
+        Ok(GridPlacement::new(start, span))
+    }
+}
+
+/// Parse a list of grid tracks, e.g `100 flex(1) auto`
+pub fn parse_grid_tracks(value: &str) -> Result<Vec<Size>, ParseError> {
+    value
+        .split_ascii_whitespace_excluding_group('(', ')')
+        .map(Size::parse)
+        .collect()
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageDecoding {
+    /// Decode the image while painting it.
+    Sync,
+    /// Decode the image in a background worker, and paint it once ready.
+    #[default]
+    Async,
+}
+
+impl Parse for ImageDecoding {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "sync" => Ok(ImageDecoding::Sync),
+            "async" => Ok(ImageDecoding::Async),
+            _ => Err(ParseError),
+        }
+    }
+}
+
+impl fmt::Display for ImageDecoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ImageDecoding::Sync => "sync",
+            ImageDecoding::Async => "async",
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// How many times an animated image is repeated after being played once.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageRepeat {
+    /// Use the repetition count encoded in the image.
+    #[default]
+    Auto,
+    /// Repeat forever.
+    Infinite,
+    /// Repeat a specific amount of times.
+    Count(usize),
+}
+
+impl Parse for ImageRepeat {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "auto" => Ok(ImageRepeat::Auto),
+            "infinite" => Ok(ImageRepeat::Infinite),
+            "none" => Ok(ImageRepeat::Count(0)),
+            value => value
+                .parse::<usize>()
+                .map(ImageRepeat::Count)
+                .map_err(|_| ParseError),
+        }
+    }
+}
+
+impl fmt::Display for ImageRepeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageRepeat::Auto => f.write_str("auto"),
+            ImageRepeat::Infinite => f.write_str("infinite"),
+            ImageRepeat::Count(count) => count.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use torin::layout_direction::LayoutDirection;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for LayoutDirection {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        match value {
+            "ltr" => Ok(LayoutDirection::Ltr),
+            "rtl" => Ok(LayoutDirection::Rtl),
+            _ => Err(ParseError),
+        }
+    }
+}
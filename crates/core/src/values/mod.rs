@@ -15,9 +15,13 @@ mod font_weight;
 mod font_width;
 mod gaps;
 mod gradient;
+mod grid;
 mod highlight;
 mod image_cover;
+mod image_decoding;
+mod image_repeat;
 mod layer;
+mod layout_direction;
 mod overflow;
 mod paint;
 mod position;
@@ -28,6 +32,7 @@ mod text_align;
 mod text_height;
 mod text_overflow;
 mod text_shadow;
+mod transform;
 mod visible_size;
 
 pub use aspect_ratio::*;
@@ -42,8 +47,11 @@ pub use font_slant::*;
 pub use font_weight::*;
 pub use font_width::*;
 pub use gradient::*;
+pub use grid::*;
 pub use highlight::*;
 pub use image_cover::*;
+pub use image_decoding::*;
+pub use image_repeat::*;
 pub use layer::*;
 pub use overflow::*;
 pub use paint::*;
@@ -54,3 +62,4 @@ pub use text_align::*;
 pub use text_height::*;
 pub use text_overflow::*;
 pub use text_shadow::*;
+pub use transform::*;
@@ -0,0 +1,235 @@
+use freya_engine::prelude::*;
+use torin::prelude::{
+    Area,
+    Point2D,
+};
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// Offset of a [TransformOrigin] in one axis.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub enum TransformOriginValue {
+    /// Relative to the size of the element.
+    Percentage(f32),
+    /// Logical pixels from the start of the element.
+    Pixels(f32),
+}
+
+impl TransformOriginValue {
+    fn resolve(&self, start: f32, size: f32, scale_factor: f32) -> f32 {
+        match self {
+            Self::Percentage(percentage) => start + size * percentage / 100.,
+            Self::Pixels(pixels) => start + pixels * scale_factor,
+        }
+    }
+}
+
+impl Parse for TransformOriginValue {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if let Some(percentage) = value.strip_suffix('%') {
+            percentage
+                .parse::<f32>()
+                .map(Self::Percentage)
+                .map_err(|_| ParseError)
+        } else {
+            value
+                .parse::<f32>()
+                .map(Self::Pixels)
+                .map_err(|_| ParseError)
+        }
+    }
+}
+
+/// Point of an element around which its transforms are applied. Defaults to the center.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub struct TransformOrigin {
+    pub x: TransformOriginValue,
+    pub y: TransformOriginValue,
+}
+
+impl Default for TransformOrigin {
+    fn default() -> Self {
+        Self {
+            x: TransformOriginValue::Percentage(50.),
+            y: TransformOriginValue::Percentage(50.),
+        }
+    }
+}
+
+impl TransformOrigin {
+    /// Get the point of this origin in the given area.
+    pub fn resolve(&self, area: &Area, scale_factor: f32) -> Point2D {
+        Point2D::new(
+            self.x.resolve(area.min_x(), area.width(), scale_factor),
+            self.y.resolve(area.min_y(), area.height(), scale_factor),
+        )
+    }
+}
+
+enum OriginKeyword {
+    Horizontal(TransformOriginValue),
+    Vertical(TransformOriginValue),
+    Center,
+    Value(TransformOriginValue),
+}
+
+impl OriginKeyword {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "left" => Self::Horizontal(TransformOriginValue::Percentage(0.)),
+            "right" => Self::Horizontal(TransformOriginValue::Percentage(100.)),
+            "top" => Self::Vertical(TransformOriginValue::Percentage(0.)),
+            "bottom" => Self::Vertical(TransformOriginValue::Percentage(100.)),
+            "center" => Self::Center,
+            value => Self::Value(TransformOriginValue::parse(value)?),
+        })
+    }
+
+    fn horizontal(self) -> Option<TransformOriginValue> {
+        match self {
+            Self::Horizontal(value) | Self::Value(value) => Some(value),
+            Self::Center => Some(TransformOriginValue::Percentage(50.)),
+            Self::Vertical(_) => None,
+        }
+    }
+
+    fn vertical(self) -> Option<TransformOriginValue> {
+        match self {
+            Self::Vertical(value) | Self::Value(value) => Some(value),
+            Self::Center => Some(TransformOriginValue::Percentage(50.)),
+            Self::Horizontal(_) => None,
+        }
+    }
+}
+
+impl Parse for TransformOrigin {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut values = value.split_ascii_whitespace().map(OriginKeyword::parse);
+
+        let first = values.next().ok_or(ParseError)??;
+        let second = values.next().transpose()?;
+
+        if values.next().is_some() {
+            return Err(ParseError);
+        }
+
+        let (x, y) = match second {
+            // A single vertical keyword keeps the horizontal axis centered
+            None if matches!(first, OriginKeyword::Vertical(_)) => (OriginKeyword::Center, first),
+            None => (first, OriginKeyword::Center),
+            // Keywords can be specified in any order, e.g `top left`
+            Some(second)
+                if matches!(first, OriginKeyword::Vertical(_))
+                    || matches!(second, OriginKeyword::Horizontal(_)) =>
+            {
+                (second, first)
+            }
+            Some(second) => (first, second),
+        };
+
+        Ok(Self {
+            x: x.horizontal().ok_or(ParseError)?,
+            y: y.vertical().ok_or(ParseError)?,
+        })
+    }
+}
+
+/// Transform applied by a node to itself and its descendants.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub enum TransformOperation {
+    Translate(f32, f32),
+    /// Degrees.
+    Rotate(f32),
+    Scale(f32, f32),
+    /// Degrees in each axis.
+    Skew(f32, f32),
+    Matrix(TransformMatrix),
+}
+
+impl TransformOperation {
+    /// Position of the operation when a node has several, the same as CSS: translate, rotate, scale, then skew and matrix.
+    pub fn order(&self) -> u8 {
+        match self {
+            Self::Translate(..) => 0,
+            Self::Rotate(..) => 1,
+            Self::Scale(..) => 2,
+            Self::Skew(..) => 3,
+            Self::Matrix(..) => 4,
+        }
+    }
+}
+
+/// 2D affine transformation, in the same order as the CSS `matrix()` function.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug, Copy)]
+pub struct TransformMatrix {
+    pub scale_x: f32,
+    pub skew_y: f32,
+    pub skew_x: f32,
+    pub scale_y: f32,
+    pub translate_x: f32,
+    pub translate_y: f32,
+}
+
+impl Default for TransformMatrix {
+    fn default() -> Self {
+        Self {
+            scale_x: 1.,
+            skew_y: 0.,
+            skew_x: 0.,
+            scale_y: 1.,
+            translate_x: 0.,
+            translate_y: 0.,
+        }
+    }
+}
+
+impl TransformMatrix {
+    /// Convert into a Skia [Matrix], with the translation scaled by the given factor.
+    pub fn to_matrix(&self, scale_factor: f32) -> Matrix {
+        Matrix::new_all(
+            self.scale_x,
+            self.skew_x,
+            self.translate_x * scale_factor,
+            self.skew_y,
+            self.scale_y,
+            self.translate_y * scale_factor,
+            0.,
+            0.,
+            1.,
+        )
+    }
+}
+
+impl Parse for TransformMatrix {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let values = value
+            .trim()
+            .strip_prefix("matrix(")
+            .and_then(|value| value.strip_suffix(')'))
+            .ok_or(ParseError)?
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<f32>().map_err(|_| ParseError))
+            .collect::<Result<Vec<f32>, ParseError>>()?;
+
+        let [scale_x, skew_y, skew_x, scale_y, translate_x, translate_y] = values[..] else {
+            return Err(ParseError);
+        };
+
+        Ok(Self {
+            scale_x,
+            skew_y,
+            skew_x,
+            scale_y,
+            translate_x,
+            translate_y,
+        })
+    }
+}
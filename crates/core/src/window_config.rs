@@ -16,6 +16,7 @@ use winit::window::{
 };
 
 use crate::{
+    dom::ImagesCacheBudget,
     event_loop_messages::{
         EventLoopMessage,
         EventLoopMessageAction,
@@ -24,8 +25,8 @@ use crate::{
     values::Color,
 };
 
-pub type WindowCallback = Box<dyn FnOnce(&mut Window) + Send + Sync>;
-pub type OnCloseCallback = Box<dyn FnMut(&mut Window) -> OnCloseResponse + Send + Sync>;
+pub type WindowCallback = Box<dyn FnOnce(&Window) + Send + Sync>;
+pub type OnCloseCallback = Box<dyn FnMut(&Window) -> OnCloseResponse + Send + Sync>;
 pub type WindowBuilderHook = Box<dyn FnOnce(WindowAttributes) -> WindowAttributes + Send + Sync>;
 
 impl From<accesskit_winit::Event> for EventLoopMessage {
@@ -37,6 +38,16 @@ impl From<accesskit_winit::Event> for EventLoopMessage {
     }
 }
 
+/// Graphics backend used to render a Window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsBackend {
+    /// Render with the GPU through OpenGL, falling back to [GraphicsBackend::Software] if it's not supported.
+    #[default]
+    OpenGl,
+    /// Render with the CPU. Useful for virtual machines or remote desktops without GPU access.
+    Software,
+}
+
 #[derive(PartialEq)]
 pub enum OnCloseResponse {
     Close,
@@ -72,6 +83,10 @@ pub struct WindowConfig {
     pub window_attributes_hook: Option<WindowBuilderHook>,
     /// Max resource in bytes to be used by the GPU. Defaults to automatic.
     pub max_gpu_resources_bytes: Option<usize>,
+    /// Graphics backend used to render the Window. Defaults to OpenGL.
+    pub graphics_backend: GraphicsBackend,
+    /// Limits of the decoded images cached with `cache_key`. Defaults to 256 MiB.
+    pub images_cache_budget: ImagesCacheBudget,
 }
 
 impl WindowConfig {
@@ -108,6 +123,8 @@ impl WindowConfig {
             on_close: None,
             window_attributes_hook: None,
             max_gpu_resources_bytes: None,
+            graphics_backend: GraphicsBackend::default(),
+            images_cache_budget: ImagesCacheBudget::default(),
         }
     }
 
@@ -151,6 +168,16 @@ impl WindowConfig {
         self.max_gpu_resources_bytes = Some(max_gpu_resources_bytes);
         self
     }
+    /// Specify the graphics backend used to render the Window.
+    pub fn with_graphics_backend(mut self, graphics_backend: GraphicsBackend) -> Self {
+        self.graphics_backend = graphics_backend;
+        self
+    }
+    /// Specify the limits of the images cache of the Window.
+    pub fn with_images_cache_budget(mut self, images_cache_budget: ImagesCacheBudget) -> Self {
+        self.images_cache_budget = images_cache_budget;
+        self
+    }
     /// Specify the Window background color.
     pub fn with_background(mut self, background: &str) -> Self {
         self.background = Color::parse(background).unwrap_or(Color::WHITE);
@@ -169,7 +196,7 @@ impl WindowConfig {
     }
 
     /// Register a callback that will be executed when the window is created.
-    pub fn on_setup(mut self, callback: impl FnOnce(&mut Window) + 'static + Send + Sync) -> Self {
+    pub fn on_setup(mut self, callback: impl FnOnce(&Window) + 'static + Send + Sync) -> Self {
         self.on_setup = Some(Box::new(callback));
         self
     }
@@ -177,7 +204,7 @@ impl WindowConfig {
     /// Register a callback that will be executed when the window is closed.
     pub fn on_close(
         mut self,
-        callback: impl FnMut(&mut Window) -> OnCloseResponse + 'static + Send + Sync,
+        callback: impl FnMut(&Window) -> OnCloseResponse + 'static + Send + Sync,
     ) -> Self {
         self.on_close = Some(Box::new(callback));
         self
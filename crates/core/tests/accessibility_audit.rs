@@ -0,0 +1,201 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn issue_name(issue: &AccessibilityIssue) -> &'static str {
+    match issue {
+        AccessibilityIssue::FocusableWithoutName => "focusable-without-name",
+        AccessibilityIssue::DuplicateId { .. } => "duplicate-id",
+        AccessibilityIssue::ImageWithoutAlt => "image-without-alt",
+        AccessibilityIssue::InteractiveWithoutRole => "interactive-without-role",
+        AccessibilityIssue::InteractiveWithoutActions => "interactive-without-actions",
+        AccessibilityIssue::LowContrast { .. } => "low-contrast",
+    }
+}
+
+#[tokio::test]
+pub async fn audit_finds_issues() {
+    fn audit_app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            rect {
+                background: "white",
+                rect {
+                    a11y_focusable: "true",
+                    width: "50",
+                    height: "50",
+                }
+                rect {
+                    onclick: |_| {},
+                    label {
+                        "Click me"
+                    }
+                }
+                rect {
+                    a11y_role: "button",
+                    label {
+                        "Can't press me"
+                    }
+                }
+                image {
+                    width: "50",
+                    height: "50",
+                }
+                rect {
+                    a11y_id: focus.attribute(),
+                    a11y_name: "First",
+                }
+                rect {
+                    a11y_id: focus.attribute(),
+                    a11y_name: "Second",
+                }
+                label {
+                    color: "rgb(200, 200, 200)",
+                    "Hard to read"
+                }
+                rect {
+                    background: "rgb(40, 40, 40)",
+                    label {
+                        color: "rgb(200, 200, 200)",
+                        "Easy to read"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(audit_app);
+    utils.wait_for_update().await;
+
+    let findings = utils.audit_accessibility();
+    assert_eq!(
+        findings
+            .iter()
+            .map(|finding| issue_name(&finding.issue))
+            .collect::<Vec<_>>(),
+        vec![
+            "focusable-without-name",
+            "interactive-without-role",
+            "interactive-without-actions",
+            "image-without-alt",
+            "duplicate-id",
+            "low-contrast",
+        ]
+    );
+
+    let duplicated = utils.locator().a11y_name("Second").only();
+    assert_eq!(findings[4].node_id, duplicated.id());
+    let first = utils.locator().a11y_name("First").only();
+    assert_eq!(
+        findings[4].issue,
+        AccessibilityIssue::DuplicateId {
+            first_node_id: first.id()
+        }
+    );
+
+    let AccessibilityIssue::LowContrast {
+        ratio, min_ratio, ..
+    } = findings[5].issue
+    else {
+        unreachable!()
+    };
+    assert!(ratio < 2.0);
+    assert_eq!(min_ratio, MIN_TEXT_CONTRAST);
+}
+
+#[test]
+pub fn contrast_ratios() {
+    assert!((contrast_ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 0.01);
+    assert!((contrast_ratio(Color::WHITE, Color::BLACK) - 21.0).abs() < 0.01);
+    assert_eq!(contrast_ratio(Color::RED, Color::RED), 1.0);
+}
+
+#[tokio::test]
+pub async fn accessible_app() {
+    fn accessible_app() -> Element {
+        rsx!(
+            rect {
+                background: "white",
+                rect {
+                    a11y_role: "button",
+                    a11y_focusable: "true",
+                    onclick: |_| {},
+                    label {
+                        "Save"
+                    }
+                }
+                image {
+                    a11y_name: "Logo",
+                    width: "50",
+                    height: "50",
+                }
+                label {
+                    color: "rgb(90, 90, 90)",
+                    "Some text"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(accessible_app);
+    utils.wait_for_update().await;
+
+    utils.assert_accessible();
+}
+
+#[tokio::test]
+#[should_panic(expected = "Image without an alternative text")]
+pub async fn assert_accessible_panics() {
+    fn app() -> Element {
+        rsx!(image {
+            width: "50",
+            height: "50",
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    utils.assert_accessible();
+}
+
+#[tokio::test]
+pub async fn contrast_over_window_background() {
+    fn app() -> Element {
+        rsx!(
+            label {
+                color: "rgb(200, 200, 200)",
+                "Light text"
+            }
+            rect {
+                background: "rgb(255, 255, 255, 0.5)",
+                label {
+                    color: "rgb(90, 90, 90)",
+                    "Dark text"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let light = utils.locator().text("Light text").only();
+    let findings = utils.audit_accessibility();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].node_id, light.id());
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig::<()> {
+            background: Color::BLACK,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let dark = utils.locator().text("Dark text").only();
+    let findings = utils.audit_accessibility();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].node_id, dark.id());
+}
@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_core::dom::{
+    ImageCacheKey,
+    ImageDecodeStatus,
+    ImageHeader,
+    ImagesCacheBudget,
+};
+use freya_engine::prelude::{
+    Data,
+    Image,
+};
+use freya_native_core::NodeId;
+use freya_testing::prelude::*;
+
+/// 4x4 GIF with 3 frames of 50ms each, repeating forever.
+static ANIMATED_GIF: &[u8] = include_bytes!("./animated.gif");
+
+static FREYA_LOGO: &[u8] = include_bytes!("./freya.png");
+
+const FRAME_DURATION: Duration = Duration::from_millis(50);
+
+fn launch_animated_test(app: fn() -> Element) -> TestingHandler<()> {
+    launch_test_with_config(
+        app,
+        TestingConfig::<()> {
+            virtual_time: true,
+            ..TestingConfig::default()
+        },
+    )
+}
+
+/// Render until the image is decoded and its animation has started.
+///
+/// Images are decoded in background threads, so this waits in real time without advancing the virtual clock.
+async fn wait_for_decoding(utils: &mut TestingHandler<()>, node_id: NodeId) {
+    for _ in 0..100 {
+        // Rendering picks up the decoded images and starts playing them
+        let _ = utils.create_snapshot();
+
+        let sdom = utils.sdom();
+        let fdom = sdom.get();
+        if fdom.images_cache().decoder().status(node_id) == Some(ImageDecodeStatus::Decoded) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    panic!("The image was not decoded in time.");
+}
+
+/// Advance the virtual clock and render.
+async fn advance_frame(utils: &mut TestingHandler<()>, duration: Duration) {
+    utils.advance_time(duration).await;
+    let _ = utils.create_snapshot();
+}
+
+fn current_frame(utils: &TestingHandler<()>, node_id: NodeId) -> Option<usize> {
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let frame = fdom.images_cache().decoder().frame(node_id);
+    frame
+}
+
+#[test]
+pub fn read_animated_image_header() {
+    let header = ImageHeader::read(ANIMATED_GIF).unwrap();
+    assert_eq!((header.width, header.height), (4, 4));
+    assert_eq!(header.frames, 3);
+    assert!(header.is_animated());
+}
+
+#[tokio::test]
+pub async fn animated_image_plays() {
+    fn animated_image_plays() -> Element {
+        rsx!(image {
+            image_data: static_bytes(ANIMATED_GIF),
+            width: "4",
+            height: "4",
+        })
+    }
+
+    let mut utils = launch_animated_test(animated_image_plays);
+    utils.wait_for_update().await;
+    let image_id = utils.root().children_ids()[0];
+
+    wait_for_decoding(&mut utils, image_id).await;
+    let _ = utils.create_snapshot();
+    assert_eq!(current_frame(&utils, image_id), Some(0));
+
+    // Not enough to move to the next frame
+    advance_frame(&mut utils, FRAME_DURATION / 2).await;
+    assert_eq!(current_frame(&utils, image_id), Some(0));
+
+    // Frames are played in order and start over once finished
+    for frame in [1, 2, 0, 1] {
+        advance_frame(&mut utils, FRAME_DURATION).await;
+        assert_eq!(current_frame(&utils, image_id), Some(frame));
+    }
+
+    assert!(utils.sdom().get().images_cache().decoder().is_animating());
+}
+
+#[tokio::test]
+pub async fn paused_animated_image() {
+    fn paused_animated_image() -> Element {
+        rsx!(image {
+            image_data: static_bytes(ANIMATED_GIF),
+            width: "4",
+            height: "4",
+            playing: "false",
+        })
+    }
+
+    let mut utils = launch_animated_test(paused_animated_image);
+    utils.wait_for_update().await;
+    let image_id = utils.root().children_ids()[0];
+
+    wait_for_decoding(&mut utils, image_id).await;
+
+    for _ in 0..5 {
+        advance_frame(&mut utils, FRAME_DURATION).await;
+        assert_eq!(current_frame(&utils, image_id), Some(0));
+    }
+
+    assert!(!utils.sdom().get().images_cache().decoder().is_animating());
+}
+
+#[tokio::test]
+pub async fn animated_image_repeat() {
+    fn animated_image_repeat() -> Element {
+        rsx!(image {
+            image_data: static_bytes(ANIMATED_GIF),
+            width: "4",
+            height: "4",
+            repeat: "none",
+        })
+    }
+
+    let mut utils = launch_animated_test(animated_image_repeat);
+    utils.wait_for_update().await;
+    let image_id = utils.root().children_ids()[0];
+
+    wait_for_decoding(&mut utils, image_id).await;
+    let _ = utils.create_snapshot();
+
+    for frame in [1, 2, 2, 2] {
+        advance_frame(&mut utils, FRAME_DURATION).await;
+        assert_eq!(current_frame(&utils, image_id), Some(frame));
+    }
+
+    // Played once and stopped in the last frame
+    assert!(!utils.sdom().get().images_cache().decoder().is_animating());
+}
+
+#[tokio::test]
+pub async fn animated_image_frames_budget() {
+    fn animated_image_frames_budget() -> Element {
+        rsx!(image {
+            image_data: static_bytes(ANIMATED_GIF),
+            width: "4",
+            height: "4",
+        })
+    }
+
+    let mut utils = launch_animated_test(animated_image_frames_budget);
+    let logo = Image::from_encoded(Data::new_copy(FREYA_LOGO)).unwrap();
+    let logo_bytes = 32 * 32 * 4;
+    let frames_bytes = 3 * 4 * 4 * 4;
+    {
+        let sdom = utils.sdom();
+        let fdom = sdom.get();
+        let mut images_cache = fdom.images_cache();
+        images_cache.set_budget(ImagesCacheBudget::unlimited().with_max_bytes(logo_bytes + 1));
+        images_cache.insert(ImageCacheKey("logo".to_string()), logo);
+        assert_eq!(images_cache.bytes(), logo_bytes);
+    }
+    utils.wait_for_update().await;
+    let image_id = utils.root().children_ids()[0];
+
+    wait_for_decoding(&mut utils, image_id).await;
+
+    // The decoded frames do not fit next to the cached image, so it gets evicted
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let images_cache = fdom.images_cache();
+    assert!(images_cache.is_empty());
+    assert_eq!(images_cache.stats().evictions, 1);
+    assert_eq!(images_cache.bytes(), frames_bytes);
+}
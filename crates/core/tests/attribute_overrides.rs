@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_native_core::attributes::AttributeName;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn attribute_overrides() {
+    fn attribute_overrides_app() -> Element {
+        let mut width = use_signal(|| 100);
+        rsx!(rect {
+            width: "{width}",
+            height: "100",
+            onclick: move |_| width += 50,
+        })
+    }
+
+    let mut utils = launch_test(attribute_overrides_app);
+    utils.wait_for_update().await;
+    let rect_id = utils.root().children_ids()[0];
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.);
+
+    // Override the width
+    let (must_repaint, must_relayout) =
+        utils
+            .sdom()
+            .get_mut()
+            .override_attribute(rect_id, AttributeName::Width, "50".to_string());
+    assert!(must_repaint);
+    assert!(must_relayout);
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 50.);
+    assert_eq!(
+        utils.sdom().get().attribute_overrides(rect_id),
+        vec![AttributeName::Width]
+    );
+
+    // Restore the original width
+    utils.sdom().get_mut().reset_attribute_overrides(rect_id);
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.);
+    assert!(utils.sdom().get().attribute_overrides(rect_id).is_empty());
+
+    // The component setting the attribute again replaces the override
+    utils
+        .sdom()
+        .get_mut()
+        .override_attribute(rect_id, AttributeName::Width, "50".to_string());
+    utils.wait_for_update().await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 150.);
+    assert!(utils.sdom().get().attribute_overrides(rect_id).is_empty());
+}
@@ -1,5 +1,14 @@
 use freya::prelude::*;
-use freya_core::dom::ImageCacheKey;
+use freya_core::dom::{
+    ImageCacheKey,
+    ImageDecodeStatus,
+    ImagesCache,
+    ImagesCacheBudget,
+};
+use freya_engine::prelude::{
+    Data,
+    Image,
+};
 use freya_testing::prelude::*;
 
 static FREYA_LOGO: &[u8] = include_bytes!("./freya.png");
@@ -136,3 +145,180 @@ pub async fn changed_image_cache_key() {
         assert!(images_cache.contains_key(&ImageCacheKey(3.to_string())));
     }
 }
+
+#[test]
+pub fn images_cache_lru_eviction() {
+    let image = Image::from_encoded(Data::new_copy(FREYA_LOGO)).unwrap();
+    let mut images_cache = ImagesCache::new(ImagesCacheBudget::unlimited().with_max_entries(2));
+
+    images_cache.insert(ImageCacheKey("a".to_string()), image.clone());
+    images_cache.insert(ImageCacheKey("b".to_string()), image.clone());
+
+    // Use `a` so `b` becomes the least recently used
+    assert!(images_cache.get(&ImageCacheKey("a".to_string())).is_some());
+
+    images_cache.insert(ImageCacheKey("c".to_string()), image.clone());
+
+    assert_eq!(images_cache.len(), 2);
+    assert!(images_cache.contains_key(&ImageCacheKey("a".to_string())));
+    assert!(!images_cache.contains_key(&ImageCacheKey("b".to_string())));
+    assert!(images_cache.contains_key(&ImageCacheKey("c".to_string())));
+
+    assert!(images_cache.get(&ImageCacheKey("b".to_string())).is_none());
+
+    let stats = images_cache.stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.evictions, 1);
+    assert_eq!(stats.entries, 2);
+    assert_eq!(stats.bytes, images_cache.bytes());
+
+    // Budget in bytes
+    let image_bytes = (image.width() * image.height() * 4) as usize;
+    images_cache.set_budget(ImagesCacheBudget::unlimited().with_max_bytes(image_bytes));
+    assert_eq!(images_cache.len(), 1);
+    assert_eq!(images_cache.bytes(), image_bytes);
+}
+
+#[test]
+pub fn images_cache_purge() {
+    let image = Image::from_encoded(Data::new_copy(FREYA_LOGO)).unwrap();
+    let mut images_cache = ImagesCache::default();
+
+    for key in ["gallery-1", "gallery-2", "avatar-1"] {
+        images_cache.insert(ImageCacheKey(key.to_string()), image.clone());
+    }
+
+    assert_eq!(images_cache.purge_prefix("gallery-"), 2);
+    assert_eq!(images_cache.len(), 1);
+    assert_eq!(
+        images_cache.keys().collect::<Vec<_>>(),
+        vec![&ImageCacheKey("avatar-1".to_string())]
+    );
+    assert!(images_cache
+        .peek(&ImageCacheKey("avatar-1".to_string()))
+        .is_some());
+    assert_eq!(images_cache.stats().hits, 0);
+
+    assert!(images_cache
+        .remove(&ImageCacheKey("avatar-1".to_string()))
+        .is_some());
+    assert!(images_cache.is_empty());
+    assert_eq!(images_cache.bytes(), 0);
+}
+
+#[tokio::test]
+pub async fn window_images_cache_budget() {
+    fn window_images_cache_budget() -> Element {
+        rsx!(for item in 0..5 {
+            image {
+                key: "{item}",
+                width: "10",
+                height: "10",
+                image_data: static_bytes(FREYA_LOGO),
+                cache_key: "{item}",
+            }
+        })
+    }
+
+    let mut utils = launch_test(window_images_cache_budget);
+    utils
+        .sdom()
+        .get()
+        .images_cache()
+        .set_budget(ImagesCacheBudget::unlimited().with_max_entries(3));
+    utils.wait_for_update().await;
+
+    // Simulate a render
+    let _ = utils.create_snapshot();
+
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let images_cache = fdom.images_cache();
+    assert_eq!(images_cache.len(), 3);
+    assert!(images_cache.stats().evictions >= 2);
+}
+
+#[tokio::test]
+pub async fn async_image_decoding() {
+    fn async_image_decoding() -> Element {
+        rsx!(image {
+            image_data: static_bytes(FREYA_LOGO),
+            width: "16",
+            height: "16",
+            downsample: "true",
+        })
+    }
+
+    let mut utils = launch_test(async_image_decoding);
+    utils.wait_for_update().await;
+    let root = utils.root();
+    let image_id = root.children_ids()[0];
+
+    // The layout does not wait for the image to be decoded
+    let area = root.get(0).layout().unwrap().area;
+    assert_eq!((area.width(), area.height()), (16., 16.));
+
+    // Snapshots wait for the images decoded in background
+    let _ = utils.create_snapshot();
+
+    let status = utils.sdom().get().images_cache().decoder().status(image_id);
+    assert_eq!(status, Some(ImageDecodeStatus::Decoded));
+
+    // Decoded with the laid out size instead of the original 32x32
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let image = fdom.images_cache().decoder().image(image_id).unwrap();
+    assert_eq!((image.width(), image.height()), (16, 16));
+}
+
+#[tokio::test]
+pub async fn image_status() {
+    fn image_status() -> Element {
+        let (valid_status, valid) = use_image_status();
+        let (broken_status, broken) = use_image_status();
+        let (async_status, decoded_async) = use_image_status();
+        let valid = format!("valid {:?}", valid());
+        let broken = format!("broken {:?}", broken());
+        let decoded_async = format!("async {:?}", decoded_async());
+        rsx!(
+            image {
+                image_data: static_bytes(FREYA_LOGO),
+                decoding: "sync",
+                image_status: valid_status,
+            }
+            image {
+                image_data: static_bytes(&[0, 1, 2, 3]),
+                image_status: broken_status,
+            }
+            image {
+                image_data: static_bytes(FREYA_LOGO),
+                width: "16",
+                height: "16",
+                image_status: async_status,
+            }
+            label {
+                "{valid}"
+            }
+            label {
+                "{broken}"
+            }
+            label {
+                "{decoded_async}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(image_status);
+    utils.wait_for_update().await;
+
+    // Nothing is reported until the images are rendered
+    assert!(utils.locator().text("valid None").exists());
+
+    let _ = utils.create_snapshot();
+    utils.wait_for_update().await;
+
+    assert!(utils.locator().text("valid Some(Decoded)").exists());
+    assert!(utils.locator().text("broken Some(Failed)").exists());
+    assert!(utils.locator().text("async Some(Decoded)").exists());
+}
@@ -0,0 +1,69 @@
+use freya::prelude::*;
+use freya_core::render::LayerCacheStats;
+use freya_testing::prelude::*;
+
+fn layer_cache_stats(utils: &TestingHandler<()>) -> LayerCacheStats {
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let stats = fdom.layer_cache().stats();
+    stats
+}
+
+#[tokio::test]
+pub async fn layer_cache() {
+    fn layer_cache_app() -> Element {
+        let mut outside = use_signal(|| 0);
+        let mut inside = use_signal(|| 0);
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                background: if outside() % 2 == 0 { "red" } else { "blue" },
+                onclick: move |_| outside += 1,
+            }
+            rect {
+                width: "100",
+                height: "100",
+                layer_cache: "true",
+                onclick: move |_| inside += 1,
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    background: "green",
+                    label {
+                        "{inside}"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(layer_cache_app);
+    utils.wait_for_update().await;
+    let cached_id = utils.root().children_ids()[1];
+
+    // Rendered for the first time
+    let _ = utils.create_snapshot();
+    assert_eq!(
+        layer_cache_stats(&utils),
+        LayerCacheStats { hits: 0, misses: 1 }
+    );
+    assert!(utils.sdom().get().layer_cache().contains(&cached_id));
+
+    // Changes outside the subtree reuse the cache
+    utils.click_cursor((5., 5.)).await;
+    let _ = utils.create_snapshot();
+    assert_eq!(
+        layer_cache_stats(&utils),
+        LayerCacheStats { hits: 1, misses: 1 }
+    );
+
+    // Changes inside the subtree render it again
+    utils.click_cursor((5., 105.)).await;
+    let _ = utils.create_snapshot();
+    assert_eq!(
+        layer_cache_stats(&utils),
+        LayerCacheStats { hits: 1, misses: 2 }
+    );
+    assert_eq!(utils.root().get(1).get(0).get(0).get(0).text(), Some("1"));
+}
@@ -3,6 +3,9 @@ use freya_core::{
     values::{
         Border,
         BorderAlignment,
+        BorderDash,
+        BorderFill,
+        BorderStyle,
         BorderWidth,
         Color,
         Fill,
@@ -24,8 +27,9 @@ fn parse_basic_border() {
                 bottom: 1.0,
                 left: 1.0,
             },
-            fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -46,8 +50,9 @@ fn parse_border_widths() {
                 bottom: 2.0,
                 left: 2.0,
             },
-            fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -60,8 +65,9 @@ fn parse_border_widths() {
                 bottom: 1.0,
                 left: 2.0,
             },
-            fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -74,8 +80,9 @@ fn parse_border_widths() {
                 bottom: 3.0,
                 left: 2.0,
             },
-            fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -88,8 +95,9 @@ fn parse_border_widths() {
                 bottom: 3.0,
                 left: 4.0,
             },
-            fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -106,7 +114,7 @@ fn parse_gradient_border() {
                 bottom: 1.0,
                 left: 1.0,
             },
-            fill: Fill::LinearGradient(Box::new(LinearGradient {
+            fill: BorderFill::all(Fill::LinearGradient(Box::new(LinearGradient {
                 angle: 0.0,
                 stops: vec![
                     GradientStop {
@@ -118,8 +126,9 @@ fn parse_gradient_border() {
                         offset: 1.0,
                     }
                 ]
-            })),
-            alignment: BorderAlignment::Inner
+            }))),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -136,3 +145,112 @@ fn parse_border_alignments() {
     assert_eq!(center, Ok(BorderAlignment::Center));
     assert_eq!(invalid, Ok(BorderAlignment::Inner));
 }
+
+#[test]
+fn parse_border_styles() {
+    let solid = BorderStyle::parse("solid");
+    let dashed = BorderStyle::parse("dashed");
+    let dotted = BorderStyle::parse("dotted");
+    let double = BorderStyle::parse("double");
+    let dash_pattern = BorderStyle::parse("dashed(6 2)");
+    let single_dash_pattern = BorderStyle::parse("dashed(4)");
+
+    assert_eq!(solid, Ok(BorderStyle::Solid));
+    assert_eq!(dashed, Ok(BorderStyle::Dashed(None)));
+    assert_eq!(dotted, Ok(BorderStyle::Dotted));
+    assert_eq!(double, Ok(BorderStyle::Double));
+    assert_eq!(
+        dash_pattern,
+        Ok(BorderStyle::Dashed(Some(BorderDash {
+            length: 6.0,
+            gap: 2.0
+        })))
+    );
+    assert_eq!(
+        single_dash_pattern,
+        Ok(BorderStyle::Dashed(Some(BorderDash {
+            length: 4.0,
+            gap: 4.0
+        })))
+    );
+    assert!(BorderStyle::parse("wavy").is_err());
+    assert!(BorderStyle::parse("dashed(0 2)").is_err());
+}
+
+#[test]
+fn parse_styled_border() {
+    let border = Border::parse("2 outer dashed(6 2) red");
+    let without_alignment = Border::parse("2 dotted red");
+
+    assert_eq!(
+        border,
+        Ok(Border {
+            width: BorderWidth {
+                top: 2.0,
+                right: 2.0,
+                bottom: 2.0,
+                left: 2.0,
+            },
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Outer,
+            style: BorderStyle::Dashed(Some(BorderDash {
+                length: 6.0,
+                gap: 2.0
+            })),
+        })
+    );
+
+    assert_eq!(
+        without_alignment,
+        Ok(Border {
+            width: BorderWidth {
+                top: 2.0,
+                right: 2.0,
+                bottom: 2.0,
+                left: 2.0,
+            },
+            fill: BorderFill::all(Fill::Color(Color::RED)),
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Dotted,
+        })
+    );
+}
+
+#[test]
+fn parse_border_fills() {
+    let two_fills = Border::parse("1 inner red blue");
+    let four_fills =
+        Border::parse("0 0 2 0 inner solid transparent transparent rgb(0, 0, 255) transparent");
+
+    assert_eq!(
+        two_fills.map(|border| border.fill),
+        Ok(BorderFill {
+            top: Fill::Color(Color::RED),
+            right: Fill::Color(Color::BLUE),
+            bottom: Fill::Color(Color::RED),
+            left: Fill::Color(Color::BLUE),
+        })
+    );
+
+    assert_eq!(
+        four_fills,
+        Ok(Border {
+            width: BorderWidth {
+                top: 0.0,
+                right: 0.0,
+                bottom: 2.0,
+                left: 0.0,
+            },
+            fill: BorderFill {
+                top: Fill::Color(Color::TRANSPARENT),
+                right: Fill::Color(Color::TRANSPARENT),
+                bottom: Fill::Color(Color::BLUE),
+                left: Fill::Color(Color::TRANSPARENT),
+            },
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
+        })
+    );
+
+    assert!(Border::parse("1 inner red blue red blue red").is_err());
+}
@@ -0,0 +1,53 @@
+use freya_core::{
+    parsing::Parse,
+    values::parse_grid_tracks,
+};
+use torin::{
+    geometry::Length,
+    grid::GridPlacement,
+    size::Size,
+};
+
+#[test]
+fn parse_auto_grid_placement() {
+    let placement = GridPlacement::parse("auto");
+    assert_eq!(placement, Ok(GridPlacement::new(None, 1)));
+}
+
+#[test]
+fn parse_start_grid_placement() {
+    let placement = GridPlacement::parse("2");
+    assert_eq!(placement, Ok(GridPlacement::new(Some(2), 1)));
+}
+
+#[test]
+fn parse_span_grid_placement() {
+    let placement = GridPlacement::parse("span 3");
+    assert_eq!(placement, Ok(GridPlacement::new(None, 3)));
+}
+
+#[test]
+fn parse_start_and_span_grid_placement() {
+    let placement = GridPlacement::parse("1 span 2");
+    assert_eq!(placement, Ok(GridPlacement::new(Some(1), 2)));
+}
+
+#[test]
+fn parse_invalid_grid_placement() {
+    assert!(GridPlacement::parse("1 2").is_err());
+    assert!(GridPlacement::parse("span").is_err());
+}
+
+#[test]
+fn parse_grid_tracks_list() {
+    let tracks = parse_grid_tracks("100 flex(2) auto 50%");
+    assert_eq!(
+        tracks,
+        Ok(vec![
+            Size::Pixels(Length::new(100.0)),
+            Size::Flex(Length::new(2.0)),
+            Size::Inner,
+            Size::Percentage(Length::new(50.0)),
+        ])
+    );
+}
@@ -0,0 +1,20 @@
+use freya_core::parsing::Parse;
+use torin::layout_direction::LayoutDirection;
+
+#[test]
+fn parse_ltr_layout_direction() {
+    let layout_direction = LayoutDirection::parse("ltr");
+    assert_eq!(layout_direction, Ok(LayoutDirection::Ltr));
+}
+
+#[test]
+fn parse_rtl_layout_direction() {
+    let layout_direction = LayoutDirection::parse("rtl");
+    assert_eq!(layout_direction, Ok(LayoutDirection::Rtl));
+}
+
+#[test]
+fn parse_invalid_layout_direction() {
+    let layout_direction = LayoutDirection::parse("up");
+    assert!(layout_direction.is_err());
+}
@@ -0,0 +1,78 @@
+use freya_core::{
+    parsing::Parse,
+    values::{
+        TransformMatrix,
+        TransformOrigin,
+        TransformOriginValue,
+    },
+};
+
+#[test]
+fn parse_center_transform_origin() {
+    let transform_origin = TransformOrigin::parse("center");
+    assert_eq!(transform_origin, Ok(TransformOrigin::default()));
+}
+
+#[test]
+fn parse_keywords_transform_origin() {
+    let transform_origin = TransformOrigin::parse("top left");
+    assert_eq!(
+        transform_origin,
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(0.),
+            y: TransformOriginValue::Percentage(0.),
+        })
+    );
+
+    let transform_origin = TransformOrigin::parse("bottom");
+    assert_eq!(
+        transform_origin,
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(50.),
+            y: TransformOriginValue::Percentage(100.),
+        })
+    );
+}
+
+#[test]
+fn parse_values_transform_origin() {
+    let transform_origin = TransformOrigin::parse("25% 10");
+    assert_eq!(
+        transform_origin,
+        Ok(TransformOrigin {
+            x: TransformOriginValue::Percentage(25.),
+            y: TransformOriginValue::Pixels(10.),
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_transform_origin() {
+    assert!(TransformOrigin::parse("left right").is_err());
+    assert!(TransformOrigin::parse("top bottom").is_err());
+    assert!(TransformOrigin::parse("10 10 10").is_err());
+    assert!(TransformOrigin::parse("").is_err());
+}
+
+#[test]
+fn parse_transform_matrix() {
+    let transform_matrix = TransformMatrix::parse("matrix(1, 0.5, 0, 2, 10, -20)");
+    assert_eq!(
+        transform_matrix,
+        Ok(TransformMatrix {
+            scale_x: 1.,
+            skew_y: 0.5,
+            skew_x: 0.,
+            scale_y: 2.,
+            translate_x: 10.,
+            translate_y: -20.,
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_transform_matrix() {
+    assert!(TransformMatrix::parse("matrix(1, 0, 0, 1)").is_err());
+    assert!(TransformMatrix::parse("1, 0, 0, 1, 0, 0").is_err());
+    assert!(TransformMatrix::parse("matrix(a, 0, 0, 1, 0, 0)").is_err());
+}
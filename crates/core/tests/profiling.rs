@@ -0,0 +1,145 @@
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+
+use freya::prelude::*;
+use freya_core::profiling::{
+    chrome_trace,
+    FrameProfile,
+    FrameStats,
+    ProfileSpan,
+};
+use freya_testing::prelude::*;
+use serde_json::{
+    json,
+    Value,
+};
+
+#[tokio::test]
+pub async fn frame_stats() {
+    fn frame_stats_app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                background: "red",
+            }
+            label {
+                "Hello"
+            }
+        )
+    }
+
+    let mut utils = launch_test(frame_stats_app);
+    utils.wait_for_update().await;
+    let _ = utils.create_snapshot();
+
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let frame_stats = fdom.frame_stats();
+    assert!(frame_stats.dirty_layout_nodes > 0);
+    assert!(frame_stats.painted_nodes >= 2);
+}
+
+#[tokio::test]
+pub async fn component_renders() {
+    #[allow(non_snake_case)]
+    fn Counter() -> Element {
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| count += 1,
+                label {
+                    "{count}"
+                }
+            }
+        )
+    }
+
+    fn component_renders_app() -> Element {
+        rsx!(Counter {})
+    }
+
+    let mut utils = launch_test(component_renders_app);
+    utils.wait_for_update().await;
+
+    // Nothing is counted unless profiling
+    utils.click_cursor((5., 5.)).await;
+    assert!(utils
+        .sdom()
+        .get()
+        .frame_stats()
+        .component_renders
+        .is_empty());
+
+    utils.sdom().get_mut().set_profiling(true);
+    utils.click_cursor((5., 5.)).await;
+    utils.click_cursor((5., 5.)).await;
+
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    assert_eq!(
+        fdom.frame_stats().component_renders,
+        BTreeMap::from([("Counter".to_string(), 2)])
+    );
+}
+
+#[test]
+pub fn chrome_trace_export() {
+    let frame = FrameProfile {
+        index: 3,
+        window: 1,
+        spans: vec![
+            ProfileSpan {
+                name: "Layout",
+                start: Duration::from_micros(1000),
+                duration: Duration::from_micros(500),
+            },
+            ProfileSpan {
+                name: "Render",
+                start: Duration::from_micros(1500),
+                duration: Duration::from_micros(250),
+            },
+        ],
+        stats: FrameStats {
+            dirty_layout_nodes: 4,
+            painted_nodes: 2,
+            dirty_area: None,
+            component_renders: BTreeMap::from([("App \"main\"".to_string(), 1)]),
+        },
+        images_cache: ImagesCacheStats {
+            entries: 2,
+            bytes: 4096,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(frame.duration(), Duration::from_micros(750));
+    let trace: Value = serde_json::from_str(&chrome_trace(&[frame])).unwrap();
+    assert_eq!(
+        trace,
+        json!({
+            "traceEvents": [
+                { "name": "Frame 3", "cat": "frame", "ph": "X", "ts": 1000.0, "dur": 750.0, "pid": 1, "tid": 1 },
+                { "name": "Layout", "cat": "step", "ph": "X", "ts": 1000.0, "dur": 500.0, "pid": 1, "tid": 1 },
+                { "name": "Render", "cat": "step", "ph": "X", "ts": 1500.0, "dur": 250.0, "pid": 1, "tid": 1 },
+                {
+                    "name": "Frame stats", "ph": "C", "ts": 1000.0, "pid": 1, "tid": 1,
+                    "args": { "dirty_layout_nodes": 4, "painted_nodes": 2, "dirty_area": 0.0 }
+                },
+                {
+                    "name": "Images cache", "ph": "C", "ts": 1000.0, "pid": 1, "tid": 1,
+                    "args": { "entries": 2, "bytes": 4096 }
+                },
+                {
+                    "name": "Component renders", "ph": "C", "ts": 1000.0, "pid": 1, "tid": 1,
+                    "args": { "App \"main\"": 1 }
+                }
+            ]
+        })
+    );
+}
@@ -0,0 +1,55 @@
+use freya::prelude::*;
+use freya_engine::prelude::{
+    raster_n32_premul,
+    Image,
+    SkColor,
+};
+use freya_testing::prelude::*;
+
+/// Read the color of a pixel from a snapshot of the app.
+fn painted_color(utils: &mut TestingHandler<()>, x: i32, y: i32) -> SkColor {
+    let image = Image::from_encoded(utils.create_snapshot()).unwrap();
+    let mut surface = raster_n32_premul((image.width(), image.height())).unwrap();
+    surface.canvas().draw_image(&image, (0., 0.), None);
+    surface.peek_pixels().unwrap().get_color((x, y))
+}
+
+#[tokio::test]
+pub async fn nested_transforms() {
+    fn nested_transforms_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "200",
+                height: "200",
+                rotate: "90deg",
+                rect {
+                    width: "50",
+                    height: "50",
+                    translate: "100 0",
+                    background: "red",
+                    onclick: move |_| clicks += 1,
+                }
+            }
+            label {
+                "{clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(nested_transforms_app);
+    utils.wait_for_update().await;
+
+    // The child is translated inside its parent and then rotated around the center of its parent,
+    // so its center goes from (25, 25) to (125, 25) and then to (175, 125).
+    // Applying the translation after the rotation would move it to (275, 25) instead.
+    assert_eq!(painted_color(&mut utils, 175, 125), SkColor::RED);
+    assert_eq!(painted_color(&mut utils, 275, 25), SkColor::WHITE);
+
+    utils.click_cursor((275., 25.)).await;
+    assert_eq!(utils.root().get(1).get(0).text(), Some("0"));
+
+    utils.click_cursor((175., 125.)).await;
+    assert_eq!(utils.root().get(1).get(0).text(), Some("1"));
+}
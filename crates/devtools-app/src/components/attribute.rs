@@ -1,16 +1,38 @@
 use freya::prelude::*;
-use freya_devtools::AttributeType;
+use freya_devtools::{
+    AttributeType,
+    NodeInfo,
+};
 
-use crate::property::{
-    BorderProperty,
-    ColorProperty,
-    GradientProperty,
-    Property,
-    ShadowProperty,
-    TextShadowProperty,
+use crate::{
+    components::editable_attribute::EditableAttribute,
+    property::{
+        BorderProperty,
+        ColorProperty,
+        GradientProperty,
+        Property,
+        ShadowProperty,
+        TextShadowProperty,
+    },
 };
 
-pub fn attribute_element(i: usize, name: &str, attribute: AttributeType<'_>) -> Option<Element> {
+/// Name of the element attribute that sets the given devtools attribute.
+fn attribute_name(name: &str) -> &str {
+    match name {
+        "main_alignment" => "main_align",
+        "cross_alignment" => "cross_align",
+        "svg_fill" => "fill",
+        "svg_stroke" => "stroke",
+        name => name,
+    }
+}
+
+pub fn attribute_element(
+    i: usize,
+    name: &str,
+    attribute: AttributeType<'_>,
+    node: &NodeInfo,
+) -> Option<Element> {
     let element = match attribute {
         AttributeType::Measure(measure) => {
             rsx! {
@@ -189,5 +211,22 @@ pub fn attribute_element(i: usize, name: &str, attribute: AttributeType<'_>) ->
         }
     };
 
-    Some(element)
+    let value = node.attribute(name).unwrap_or_default();
+    let attribute = attribute_name(name);
+    let overridden = node
+        .overridden_attributes
+        .iter()
+        .any(|overridden| overridden == attribute);
+
+    Some(rsx!(
+        EditableAttribute {
+            key: "{i}",
+            window_id: node.window_id,
+            node_id: node.node_id,
+            name: attribute,
+            value,
+            overridden,
+            {element}
+        }
+    ))
 }
@@ -0,0 +1,82 @@
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+use freya_devtools::IncomingMessageAction;
+use freya_native_core::NodeId;
+
+use crate::state::DevtoolsChannel;
+
+/// Wraps an attribute of the inspected node so it can be overridden in the running app by clicking on it.
+/// The editor starts with the current `value` of the attribute.
+#[component]
+pub fn EditableAttribute(
+    window_id: u64,
+    node_id: NodeId,
+    name: String,
+    value: String,
+    overridden: bool,
+    children: Element,
+) -> Element {
+    let radio = use_radio(DevtoolsChannel::Global);
+    let mut editing = use_signal(|| false);
+    let current_value = value;
+    let mut value = use_signal(|| current_value.clone());
+
+    let apply = move |_| {
+        radio.read().send(IncomingMessageAction::SetAttribute {
+            window_id,
+            node_id,
+            attribute: name.clone(),
+            value: value(),
+        });
+        editing.set(false);
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            spacing: "6",
+            rect {
+                width: "100%",
+                onclick: move |_| {
+                    if !editing() {
+                        value.set(current_value.clone());
+                    }
+                    editing.toggle();
+                },
+                {children}
+            }
+            if overridden {
+                label {
+                    font_size: "12",
+                    color: "rgb(255, 200, 100)",
+                    "Overridden"
+                }
+            }
+            if editing() {
+                rect {
+                    direction: "horizontal",
+                    cross_align: "center",
+                    spacing: "6",
+                    Input {
+                        value: value(),
+                        placeholder: "New value",
+                        width: "150",
+                        onchange: move |new_value| value.set(new_value),
+                    }
+                    Button {
+                        onpress: apply,
+                        label {
+                            "Apply"
+                        }
+                    }
+                    Button {
+                        onpress: move |_| editing.set(false),
+                        label {
+                            "Cancel"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
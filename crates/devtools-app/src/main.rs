@@ -2,6 +2,7 @@ use std::{
     collections::{
         HashMap,
         HashSet,
+        VecDeque,
     },
     sync::Arc,
     time::Duration,
@@ -22,6 +23,7 @@ use futures_util::{
     SinkExt,
     StreamExt,
 };
+use hooks::use_node_info;
 use state::{
     DevtoolsChannel,
     DevtoolsState,
@@ -35,7 +37,9 @@ mod state;
 mod tabs;
 
 use tabs::{
+    accessibility::*,
     computed_layout::*,
+    events::*,
     font_style::*,
     layout::*,
     misc::*,
@@ -59,9 +63,11 @@ pub fn app() -> Element {
     use_init_theme(|| DARK_THEME);
     use_init_radio_station::<DevtoolsState, DevtoolsChannel>(|| DevtoolsState {
         nodes: HashMap::new(),
+        accessibility_nodes: HashMap::new(),
         expanded_nodes: HashSet::default(),
         client: Arc::default(),
         animation_speed: AnimationClock::DEFAULT_SPEED / AnimationClock::MAX_SPEED * 100.,
+        event_log: VecDeque::new(),
     });
     let radio = use_radio(DevtoolsChannel::Global);
 
@@ -86,6 +92,17 @@ pub fn app() -> Element {
                                         .nodes
                                         .insert(window_id, nodes);
                                 }
+                                OutgoingMessageAction::AccessibilityTree { window_id, nodes } => {
+                                    radio
+                                        .write_channel(DevtoolsChannel::UpdatedAccessibility)
+                                        .accessibility_nodes
+                                        .insert(window_id, nodes);
+                                }
+                                OutgoingMessageAction::EventLog { entries } => {
+                                    radio
+                                        .write_channel(DevtoolsChannel::EventLog)
+                                        .log_events(entries);
+                                }
                             }
                         }
                     }
@@ -128,6 +145,28 @@ pub fn NavBar() -> Element {
                     }
                 }
             }
+            Link {
+                to: Route::Accessibility { },
+                ActivableRoute {
+                    route: Route::Accessibility { },
+                    Tab {
+                        label {
+                            "Accessibility"
+                        }
+                    }
+                }
+            }
+            Link {
+                to: Route::Events { },
+                ActivableRoute {
+                    route: Route::Events { },
+                    Tab {
+                        label {
+                            "Events"
+                        }
+                    }
+                }
+            }
             Link {
                 to: Route::Misc { },
                 ActivableRoute {
@@ -150,6 +189,10 @@ pub fn NavBar() -> Element {
 #[rustfmt::skip]
 pub enum Route {
     #[layout(NavBar)]
+        #[route("/accessibility")]
+        Accessibility {},
+        #[route("/events")]
+        Events {},
         #[route("/misc")]
         Misc {},
         #[layout(LayoutForDOMInspector)]
@@ -196,6 +239,10 @@ impl Route {
 
 #[component]
 fn LayoutForNodeInspector(node_id: NodeId, window_id: u64) -> Element {
+    let radio = use_radio(DevtoolsChannel::Global);
+    let has_overrides = use_node_info(node_id, window_id)
+        .is_some_and(|node| !node.overridden_attributes.is_empty());
+
     rsx!(
         rect {
             overflow: "clip",
@@ -270,6 +317,16 @@ fn LayoutForNodeInspector(node_id: NodeId, window_id: u64) -> Element {
                     }
                 }
             }
+            if has_overrides {
+                Button {
+                    onpress: move |_| {
+                        radio.read().send(IncomingMessageAction::ResetAttributes { window_id, node_id });
+                    },
+                    label {
+                        "Reset overridden attributes"
+                    }
+                }
+            }
             Outlet::<Route> {}
         }
     )
@@ -182,7 +182,7 @@ pub fn BorderProperty(name: String, border: Border) -> Element {
                 text {
                     font_size: "15",
                     color: "rgb(252,181,172)",
-                    "{border.width} {border.alignment:?}"
+                    "{border.width} {border.alignment:?} {border.style}"
                 }
             }
             rect {
@@ -198,7 +198,7 @@ pub fn BorderProperty(name: String, border: Border) -> Element {
                     corner_radius: "3",
                     width: "100%",
                     height: "100%",
-                    background: "{border.fill}",
+                    background: "{border.fill.top}",
                 }
             }
             rect {
@@ -2,14 +2,26 @@ use std::{
     collections::{
         HashMap,
         HashSet,
+        VecDeque,
     },
     sync::Arc,
 };
 
 use dioxus_radio::prelude::*;
-use freya_devtools::NodeInfo;
+use freya::prelude::spawn;
+use freya_devtools::{
+    AccessibilityNodeInfo,
+    EventLogEntry,
+    IncomingMessage,
+    IncomingMessageAction,
+    MAX_EVENT_LOG_ENTRIES,
+    NodeInfo,
+};
 use freya_native_core::prelude::NodeId;
-use futures_util::stream::SplitSink;
+use futures_util::{
+    SinkExt,
+    stream::SplitSink,
+};
 use tokio::{
     net::TcpStream,
     sync::Mutex,
@@ -24,15 +36,54 @@ pub type WebSocket = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Messa
 
 pub struct DevtoolsState {
     pub(crate) nodes: HashMap<u64, Vec<NodeInfo>>,
+    pub(crate) accessibility_nodes: HashMap<u64, Vec<AccessibilityNodeInfo>>,
     pub(crate) expanded_nodes: HashSet<(u64, NodeId)>,
     pub(crate) client: Arc<Mutex<Option<WebSocket>>>,
     pub(crate) animation_speed: f32,
+    pub(crate) event_log: VecDeque<EventLogEntry>,
+}
+
+impl DevtoolsState {
+    /// Append the given entries to the events log, discarding the oldest ones if needed.
+    pub(crate) fn log_events(&mut self, entries: Vec<EventLogEntry>) {
+        for entry in entries {
+            // Entries sent again after reconnecting are already known
+            if self
+                .event_log
+                .back()
+                .is_some_and(|last| last.index >= entry.index)
+            {
+                continue;
+            }
+            self.event_log.push_back(entry);
+        }
+        while self.event_log.len() > MAX_EVENT_LOG_ENTRIES {
+            self.event_log.pop_front();
+        }
+    }
+
+    /// Send a message to the devtools server of the app, if connected.
+    pub(crate) fn send(&self, action: IncomingMessageAction) {
+        let message = Message::Text(
+            serde_json::to_string(&IncomingMessage { action })
+                .unwrap()
+                .into(),
+        );
+        let client = self.client.clone();
+        spawn(async move {
+            if let Some(client) = client.lock().await.as_mut() {
+                client.send(message).await.ok();
+            }
+        });
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 pub enum DevtoolsChannel {
     Global,
     UpdatedDOM,
+    UpdatedAccessibility,
+    EventLog,
     Misc,
 }
 
@@ -0,0 +1,238 @@
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+use freya_devtools::{
+    AccessibilityNodeInfo,
+    IncomingMessageAction,
+};
+use freya_router::prelude::use_navigator;
+
+use crate::{
+    Route,
+    property::Property,
+    state::DevtoolsChannel,
+};
+
+#[component]
+pub fn Accessibility() -> Element {
+    let radio = use_radio(DevtoolsChannel::UpdatedAccessibility);
+    let mut only_warnings = use_signal(|| false);
+    let mut selected = use_signal::<Option<(u64, u64)>>(|| None);
+
+    let nodes = radio
+        .read()
+        .accessibility_nodes
+        .iter()
+        .flat_map(|(window_id, nodes)| {
+            nodes
+                .iter()
+                .filter(|node| !only_warnings() || !node.issues.is_empty())
+                .map(|node| (*window_id, node.clone()))
+        })
+        .collect::<Vec<_>>();
+    let warnings_count = nodes
+        .iter()
+        .map(|(_, node)| node.issues.len())
+        .sum::<usize>();
+    let selected_node = selected().and_then(|(window_id, accessibility_id)| {
+        nodes
+            .iter()
+            .find(|(node_window_id, node)| {
+                *node_window_id == window_id && node.accessibility_id == accessibility_id
+            })
+            .cloned()
+    });
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "6",
+                Switch {
+                    enabled: only_warnings(),
+                    ontoggled: move |_| only_warnings.toggle(),
+                }
+                label {
+                    "Only show nodes with warnings ({warnings_count} warnings)"
+                }
+            }
+            if nodes.is_empty() {
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        "No accessibility nodes"
+                    }
+                }
+            } else {
+                ScrollView {
+                    show_scrollbar: true,
+                    height: "flex(1)",
+                    for (window_id, node) in nodes {
+                        AccessibilityNodeElement {
+                            key: "{window_id}-{node.accessibility_id}",
+                            is_selected: selected() == Some((window_id, node.accessibility_id)),
+                            onselected: move |accessibility_id| selected.set(Some((window_id, accessibility_id))),
+                            window_id,
+                            node,
+                        }
+                    }
+                }
+            }
+            if let Some((window_id, node)) = selected_node {
+                AccessibilityNodeDetails {
+                    window_id,
+                    node
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn AccessibilityNodeElement(
+    window_id: u64,
+    node: AccessibilityNodeInfo,
+    is_selected: bool,
+    onselected: EventHandler<u64>,
+) -> Element {
+    let radio = use_radio(DevtoolsChannel::Global);
+    let node_id = node.node_id;
+    let accessibility_id = node.accessibility_id;
+    let margin_left = node.depth * 10;
+    let name = node.name.clone().unwrap_or_default();
+    let background = if is_selected {
+        "rgb(40, 40, 40)"
+    } else {
+        "none"
+    };
+
+    let onmouseenter = move |_| {
+        radio
+            .read()
+            .send(IncomingMessageAction::HighlightNode { window_id, node_id });
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "27",
+            direction: "horizontal",
+            cross_align: "center",
+            corner_radius: "99",
+            padding: "0 0 0 {margin_left}",
+            background,
+            onmouseenter,
+            onclick: move |_| onselected.call(accessibility_id),
+            paragraph {
+                max_lines: "1",
+                text_overflow: "ellipsis",
+                text {
+                    font_size: "14",
+                    color: "white",
+                    "{node.role}"
+                }
+                text {
+                    font_size: "14",
+                    color: "rgb(252, 181, 172)",
+                    " {name}"
+                }
+                if node.is_focused {
+                    text {
+                        font_size: "14",
+                        color: "rgb(102, 163, 217)",
+                        " (focused)"
+                    }
+                }
+                if !node.issues.is_empty() {
+                    text {
+                        font_size: "14",
+                        color: "rgb(255, 200, 100)",
+                        " ⚠ {node.issues.len()}"
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn AccessibilityNodeDetails(window_id: u64, node: AccessibilityNodeInfo) -> Element {
+    let navigator = use_navigator();
+    let node_id = node.node_id;
+    let actions = node.actions.join(", ");
+    let bounds = node
+        .bounds
+        .map(|bounds| {
+            format!(
+                "{}x{} at ({}, {})",
+                bounds.width().round(),
+                bounds.height().round(),
+                bounds.min_x().round(),
+                bounds.min_y().round()
+            )
+        })
+        .unwrap_or_default();
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "4",
+            corner_radius: "8",
+            background: "rgb(30, 30, 30)",
+            for issue in node.issues.iter() {
+                label {
+                    color: "rgb(255, 200, 100)",
+                    "⚠ {issue}"
+                }
+            }
+            Property {
+                name: "role",
+                value: node.role.clone()
+            }
+            Property {
+                name: "name",
+                value: node.name.clone().unwrap_or_default()
+            }
+            Property {
+                name: "description",
+                value: node.description.clone().unwrap_or_default()
+            }
+            Property {
+                name: "value",
+                value: node.value.clone().unwrap_or_default()
+            }
+            Property {
+                name: "actions",
+                value: actions
+            }
+            Property {
+                name: "bounds",
+                value: bounds
+            }
+            Property {
+                name: "focusable",
+                value: node.is_focusable.to_string()
+            }
+            Property {
+                name: "focused",
+                value: node.is_focused.to_string()
+            }
+            Button {
+                onpress: move |_| {
+                    navigator.push(Route::NodeInspectorLayout { node_id, window_id });
+                },
+                label {
+                    "Show in the tree inspector"
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,266 @@
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+use freya_devtools::{
+    EmittedEventInfo,
+    EventLogEntry,
+    IncomingMessageAction,
+};
+use freya_native_core::NodeId;
+
+use crate::{
+    hooks::use_node_info,
+    state::DevtoolsChannel,
+};
+
+#[component]
+pub fn Events() -> Element {
+    let mut radio = use_radio(DevtoolsChannel::EventLog);
+    let nodes_radio = use_radio(DevtoolsChannel::UpdatedDOM);
+    let mut filter = use_signal(String::new);
+
+    let node_index = filter.read().trim().parse::<usize>().ok();
+    let entries = {
+        let radio = radio.read();
+        let nodes_radio = nodes_radio.read();
+        radio
+            .event_log
+            .iter()
+            .rev()
+            .filter(|entry| {
+                let Some(node_index) = node_index else {
+                    return true;
+                };
+                nodes_radio
+                    .nodes
+                    .get(&entry.window_id)
+                    .and_then(|nodes| nodes.iter().find(|node| node.node_id.index() == node_index))
+                    .is_some_and(|node| entry.involves(node.node_id))
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "6",
+                Input {
+                    value: filter(),
+                    placeholder: "Filter by node id",
+                    width: "150",
+                    onchange: move |value| filter.set(value),
+                }
+                Button {
+                    onpress: move |_| radio.write().event_log.clear(),
+                    label {
+                        "Clear"
+                    }
+                }
+            }
+            if entries.is_empty() {
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        "No events yet"
+                    }
+                }
+            } else {
+                ScrollView {
+                    show_scrollbar: true,
+                    spacing: "6",
+                    for entry in entries {
+                        EventLogItem {
+                            key: "{entry.index}",
+                            entry
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn EventLogItem(entry: EventLogEntry) -> Element {
+    let window_id = entry.window_id;
+    let sources = entry
+        .source_events
+        .iter()
+        .map(|source| match source.cursor {
+            Some((x, y)) => format!("{} ({x:.0}, {y:.0})", source.name),
+            None => source.name.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8",
+            spacing: "4",
+            corner_radius: "8",
+            background: "rgb(30, 30, 30)",
+            label {
+                font_size: "14",
+                color: "rgb(200, 200, 200)",
+                "#{entry.index} {sources}"
+            }
+            if !entry.potential_events.is_empty() {
+                rect {
+                    direction: "horizontal",
+                    cross_align: "center",
+                    spacing: "4",
+                    label {
+                        font_size: "14",
+                        color: "rgb(102, 163, 217)",
+                        "Hit"
+                    }
+                    for (i, potential) in entry.potential_events.iter().enumerate() {
+                        EventNode {
+                            key: "{i}",
+                            window_id,
+                            node_id: potential.node_id,
+                            details: "layer {potential.layer}"
+                        }
+                    }
+                }
+            }
+            if entry.emitted_events.is_empty() {
+                label {
+                    font_size: "14",
+                    color: "rgb(150, 150, 150)",
+                    "No handler was listening"
+                }
+            }
+            for (i, emitted) in entry.emitted_events.iter().cloned().enumerate() {
+                EmittedEvent {
+                    key: "{i}",
+                    window_id,
+                    emitted
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn EmittedEvent(window_id: u64, emitted: EmittedEventInfo) -> Element {
+    let mut outcome = Vec::new();
+    if !emitted.default_action_enabled {
+        outcome.push("default prevented".to_string());
+    }
+    if emitted.propagation_stopped {
+        outcome.push("propagation stopped".to_string());
+    } else if emitted.bubbles {
+        outcome.push("bubbles".to_string());
+    }
+    let outcome = outcome.join(", ");
+
+    rsx!(
+        rect {
+            spacing: "4",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "4",
+                label {
+                    font_size: "14",
+                    color: "rgb(252, 181, 172)",
+                    "on{emitted.name}"
+                }
+                label {
+                    font_size: "14",
+                    color: "rgb(150, 150, 150)",
+                    "from {emitted.source} to"
+                }
+                EventNode {
+                    window_id,
+                    node_id: emitted.node_id,
+                    details: outcome
+                }
+            }
+            if emitted.propagation_stopped {
+                label {
+                    font_size: "13",
+                    color: "rgb(150, 150, 150)",
+                    margin: "0 0 0 12",
+                    "Stopped by a handler of this node or one of its ancestors, the handlers do not report which one"
+                }
+            }
+            if !emitted.cancelled_events.is_empty() {
+                rect {
+                    direction: "horizontal",
+                    cross_align: "center",
+                    spacing: "4",
+                    margin: "0 0 0 12",
+                    label {
+                        font_size: "14",
+                        color: "rgb(255, 200, 100)",
+                        "Cancelled"
+                    }
+                    for (i, cancelled) in emitted.cancelled_events.iter().enumerate() {
+                        EventNode {
+                            key: "{i}",
+                            window_id,
+                            node_id: cancelled.node_id,
+                            details: "on{cancelled.name}"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Node targeted by an event, highlighted in the app while hovered.
+#[component]
+fn EventNode(window_id: u64, node_id: NodeId, details: String) -> Element {
+    let radio = use_radio(DevtoolsChannel::Global);
+    let tag = use_node_info(node_id, window_id)
+        .map(|node| node.tag.to_string())
+        .unwrap_or_else(|| "removed".to_string());
+    let id = node_id.index();
+
+    let onmouseenter = move |_| {
+        radio
+            .read()
+            .send(IncomingMessageAction::HighlightNode { window_id, node_id });
+    };
+
+    rsx!(
+        rect {
+            padding: "2 6",
+            corner_radius: "6",
+            background: "rgb(45, 45, 45)",
+            onmouseenter,
+            paragraph {
+                text {
+                    font_size: "14",
+                    color: "white",
+                    "{tag}"
+                }
+                text {
+                    font_size: "14",
+                    color: "rgb(200, 200, 200)",
+                    ", id: {id}"
+                }
+                if !details.is_empty() {
+                    text {
+                        font_size: "14",
+                        color: "rgb(150, 150, 150)",
+                        " ({details})"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -1,4 +1,6 @@
+pub mod accessibility;
 pub mod computed_layout;
+pub mod events;
 pub mod font_style;
 pub mod layout;
 pub mod misc;
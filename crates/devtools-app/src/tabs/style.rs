@@ -25,7 +25,7 @@ pub fn NodeInspectorStyle(node_id: NodeId, window_id: u64) -> Element {
                     "transparent"
                 };
 
-                let element = attribute_element(i, name, attribute)?;
+                let element = attribute_element(i, name, attribute, &node)?;
 
                 Some(rsx!(
                     rect {
@@ -0,0 +1,267 @@
+use freya_core::accessibility::AccessibilityIssue;
+use freya_native_core::NodeId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use torin::prelude::Area;
+
+/// Accessibility node of a window, as exposed to assistive technologies.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct AccessibilityNodeInfo {
+    pub node_id: NodeId,
+    pub accessibility_id: u64,
+    pub parent_id: Option<u64>,
+    pub children: Vec<u64>,
+    /// Depth in the accessibility tree, the root being `0`.
+    pub depth: usize,
+    pub role: String,
+    /// Name announced by screen readers, either its label, `a11y_name` or text.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub value: Option<String>,
+    pub actions: Vec<String>,
+    pub bounds: Option<Area>,
+    pub is_focusable: bool,
+    pub is_focused: bool,
+    /// Issues found in this node by [`freya_core::accessibility::audit_accessibility`].
+    pub issues: Vec<AccessibilityIssue>,
+}
+
+#[cfg(feature = "server")]
+mod conversion {
+    use std::collections::HashMap;
+
+    use accesskit::Action;
+    use freya_core::{
+        accessibility::{
+            ACCESSIBILITY_ACTIONS,
+            AccessibilityFinding,
+            AccessibilityTree,
+            NodeAccessibility,
+        },
+        dom::DioxusDOM,
+        states::AccessibilityState,
+        types::AccessibilityId,
+    };
+    use freya_native_core::{
+        prelude::NodeImmutable,
+        tags::TagName,
+    };
+    use torin::torin::Torin;
+
+    use super::*;
+
+    impl AccessibilityNodeInfo {
+        /// Collect the accessibility nodes of the given DOM, in the same order as the accessibility tree is built.
+        /// Each node gets the issues of the given audit findings that belong to it.
+        pub fn collect(
+            rdom: &DioxusDOM,
+            layout: &Torin<NodeId>,
+            focused_id: AccessibilityId,
+            findings: &[AccessibilityFinding],
+        ) -> Vec<Self> {
+            let mut nodes = Vec::<Self>::new();
+            let mut parents = HashMap::<u64, (u64, usize)>::new();
+
+            rdom.traverse_depth_first_advanced(|node_ref| {
+                if !node_ref.node_type().is_element() {
+                    return false;
+                }
+
+                let accessibility_id = node_ref.get_accessibility_id();
+                let layout_node = layout.get(node_ref.id());
+
+                if let Some((accessibility_id, layout_node)) = accessibility_id.zip(layout_node) {
+                    let accessibility_state = node_ref.get::<AccessibilityState>().unwrap();
+                    let node = AccessibilityTree::create_node(
+                        &node_ref,
+                        layout_node,
+                        &accessibility_state,
+                    );
+
+                    let role = node.role();
+                    let name = node
+                        .label()
+                        .or(node.class_name())
+                        .or(node.value())
+                        .map(str::to_string);
+                    let actions = ACCESSIBILITY_ACTIONS
+                        .iter()
+                        .filter(|action| node.supports_action(**action))
+                        .map(|action| format!("{action:?}"))
+                        .collect::<Vec<_>>();
+                    let is_focusable = node.supports_action(Action::Focus);
+                    let issues = findings
+                        .iter()
+                        .filter(|finding| finding.node_id == node_ref.id())
+                        .map(|finding| finding.issue.clone())
+                        .collect::<Vec<_>>();
+
+                    let children = node
+                        .children()
+                        .iter()
+                        .map(|child| child.0)
+                        .collect::<Vec<_>>();
+                    let (parent_id, depth) = parents
+                        .get(&accessibility_id.0)
+                        .map(|(parent_id, parent_depth)| (Some(*parent_id), parent_depth + 1))
+                        .unwrap_or((None, 0));
+                    for child in &children {
+                        parents.insert(*child, (accessibility_id.0, depth));
+                    }
+
+                    nodes.push(Self {
+                        node_id: node_ref.id(),
+                        accessibility_id: accessibility_id.0,
+                        parent_id,
+                        children,
+                        depth,
+                        role: format!("{role:?}"),
+                        name,
+                        description: node.description().map(str::to_string),
+                        value: node.value().map(str::to_string),
+                        actions,
+                        bounds: node.bounds().map(|bounds| {
+                            Area::new(
+                                (bounds.x0 as f32, bounds.y0 as f32).into(),
+                                (bounds.width() as f32, bounds.height() as f32).into(),
+                            )
+                        }),
+                        is_focusable,
+                        is_focused: accessibility_id == focused_id,
+                        issues,
+                    });
+                }
+
+                if let Some(tag) = node_ref.node_type().tag() {
+                    if *tag == TagName::Paragraph || *tag == TagName::Label {
+                        return false;
+                    }
+                }
+
+                true
+            });
+
+            nodes
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use freya::prelude::*;
+        use freya_core::{
+            accessibility::{
+                AccessibilityIssue,
+                audit_accessibility,
+            },
+            values::Color,
+        };
+        use freya_testing::prelude::*;
+
+        use super::AccessibilityNodeInfo;
+
+        fn collect(utils: &TestingHandler<()>) -> Vec<AccessibilityNodeInfo> {
+            let fdom = utils.sdom().get();
+            let rdom = fdom.rdom();
+            let findings = audit_accessibility(rdom, Color::WHITE);
+            AccessibilityNodeInfo::collect(rdom, &fdom.layout(), utils.focus_id(), &findings)
+        }
+
+        fn info_of(nodes: &[AccessibilityNodeInfo], node: TestNode) -> AccessibilityNodeInfo {
+            nodes
+                .iter()
+                .find(|info| info.node_id == node.id())
+                .cloned()
+                .expect("Node is not in the accessibility tree")
+        }
+
+        #[tokio::test]
+        pub async fn accessibility_tree() {
+            fn app() -> Element {
+                rsx!(rect {
+                    a11y_role: "button",
+                    a11y_name: "Save",
+                    a11y_description: "Saves the file",
+                    a11y_focusable: "true",
+                    width: "100",
+                    height: "50",
+                    label {
+                        "Save"
+                    }
+                })
+            }
+
+            let mut utils = launch_test(app);
+            utils.wait_for_update().await;
+
+            let nodes = collect(&utils);
+
+            let root = &nodes[0];
+            assert_eq!(root.role, "Window");
+            assert_eq!(root.parent_id, None);
+            assert_eq!(root.depth, 0);
+            assert!(root.is_focused);
+
+            let button = info_of(&nodes, utils.locator().a11y_name("Save").only());
+            assert_eq!(button.role, "Button");
+            assert_eq!(button.name.as_deref(), Some("Save"));
+            assert_eq!(button.description.as_deref(), Some("Saves the file"));
+            assert!(button.actions.contains(&"Focus".to_string()));
+            assert!(button.is_focusable);
+            assert!(!button.is_focused);
+            assert_eq!(
+                button.bounds.map(|bounds| bounds.size.to_tuple()),
+                Some((100., 50.))
+            );
+            assert!(button.issues.is_empty());
+
+            let label = info_of(&nodes, utils.locator().tag("label").only());
+            assert_eq!(label.role, "Label");
+            assert_eq!(label.value.as_deref(), Some("Save"));
+            assert_eq!(label.parent_id, Some(button.accessibility_id));
+            assert_eq!(label.depth, button.depth + 1);
+            assert_eq!(button.children, vec![label.accessibility_id]);
+        }
+
+        #[tokio::test]
+        pub async fn accessibility_issues() {
+            fn app() -> Element {
+                rsx!(
+                    rect {
+                        a11y_role: "button",
+                        a11y_focusable: "true",
+                        width: "50",
+                        height: "50",
+                    }
+                    rect {
+                        a11y_role: "switch",
+                        a11y_name: "Dark mode",
+                        width: "50",
+                        height: "50",
+                    }
+                )
+            }
+
+            let mut utils = launch_test(app);
+            utils.wait_for_update().await;
+
+            let nodes = collect(&utils);
+
+            let button = info_of(&nodes, utils.locator().role("button").only());
+            assert_eq!(
+                button.issues,
+                vec![AccessibilityIssue::FocusableWithoutName]
+            );
+
+            let switch = info_of(&nodes, utils.locator().role("switch").only());
+            assert_eq!(
+                switch.issues,
+                vec![AccessibilityIssue::InteractiveWithoutActions]
+            );
+
+            let issues_count = nodes.iter().map(|node| node.issues.len()).sum::<usize>();
+            assert_eq!(issues_count, 2);
+        }
+    }
+}
@@ -0,0 +1,618 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use freya_native_core::{
+    NodeId,
+    tags::TagName,
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+    stream::SplitSink,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tokio::{
+    net::TcpStream,
+    sync::broadcast,
+    task::JoinHandle,
+};
+use tokio_tungstenite::{
+    MaybeTlsStream,
+    WebSocketStream,
+    connect_async,
+    tungstenite::{
+        self,
+        Message,
+    },
+};
+use torin::prelude::Area;
+
+use crate::{
+    AccessibilityNodeInfo,
+    EventLogEntry,
+    IncomingMessage,
+    IncomingMessageAction,
+    MAX_EVENT_LOG_ENTRIES,
+    NodeInfo,
+    OutgoingMessage,
+    OutgoingMessageAction,
+};
+
+/// Address where the devtools server of an app listens.
+pub const DEVTOOLS_SERVER_URL: &str = "ws://[::1]:7354";
+
+type WebSocketSink = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
+
+#[derive(Debug)]
+pub enum DevtoolsClientError {
+    /// The connection with the devtools server failed.
+    Connection(tungstenite::Error),
+    /// A message couldn't be serialized.
+    Serialization(serde_json::Error),
+    Io(std::io::Error),
+    /// The devtools server closed the connection.
+    Disconnected,
+}
+
+impl Display for DevtoolsClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connection(err) => write!(f, "Devtools connection failed: {err}"),
+            Self::Serialization(err) => write!(f, "Failed to serialize a devtools message: {err}"),
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Disconnected => f.write_str("The devtools server closed the connection"),
+        }
+    }
+}
+
+impl std::error::Error for DevtoolsClientError {}
+
+impl From<tungstenite::Error> for DevtoolsClientError {
+    fn from(err: tungstenite::Error) -> Self {
+        Self::Connection(err)
+    }
+}
+
+impl From<serde_json::Error> for DevtoolsClientError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Serialization(err)
+    }
+}
+
+impl From<std::io::Error> for DevtoolsClientError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Latest state of the app received by a [DevtoolsClient].
+#[derive(Default, Clone, Debug)]
+pub struct DevtoolsSnapshot {
+    /// Nodes of every window, in depth-first order.
+    pub nodes: HashMap<u64, Vec<NodeInfo>>,
+    pub accessibility_nodes: HashMap<u64, Vec<AccessibilityNodeInfo>>,
+    pub event_log: Vec<EventLogEntry>,
+}
+
+impl DevtoolsSnapshot {
+    fn apply(&mut self, action: &OutgoingMessageAction) {
+        match action {
+            OutgoingMessageAction::Update { window_id, nodes } => {
+                if nodes.is_empty() {
+                    self.nodes.remove(window_id);
+                } else {
+                    self.nodes.insert(*window_id, nodes.clone());
+                }
+            }
+            OutgoingMessageAction::AccessibilityTree { window_id, nodes } => {
+                if nodes.is_empty() {
+                    self.accessibility_nodes.remove(window_id);
+                } else {
+                    self.accessibility_nodes.insert(*window_id, nodes.clone());
+                }
+            }
+            OutgoingMessageAction::EventLog { entries } => {
+                self.event_log.extend(entries.iter().cloned());
+                let overflow = self.event_log.len().saturating_sub(MAX_EVENT_LOG_ENTRIES);
+                self.event_log.drain(..overflow);
+            }
+        }
+    }
+}
+
+/// Criteria to find nodes with [DevtoolsClient::find_nodes]. All the criteria must match.
+///
+/// ```rust, no_run
+/// # use freya_devtools::NodeQuery;
+/// # use freya_native_core::tags::TagName;
+/// let query = NodeQuery::new()
+///     .tag(TagName::Rect)
+///     .attribute("width", "100");
+/// ```
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct NodeQuery {
+    window_id: Option<u64>,
+    tag: Option<TagName>,
+    attributes: Vec<(String, String)>,
+    text: Option<String>,
+}
+
+impl NodeQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match nodes of the given window.
+    pub fn window(mut self, window_id: u64) -> Self {
+        self.window_id = Some(window_id);
+        self
+    }
+
+    /// Only match elements with the given tag.
+    pub fn tag(mut self, tag: TagName) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Only match nodes whose attribute is formatted as the given value, see [NodeInfo::attribute].
+    pub fn attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Only match nodes whose text contains the given text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn matches(&self, node: &NodeInfo) -> bool {
+        if self
+            .window_id
+            .is_some_and(|window_id| window_id != node.window_id)
+        {
+            return false;
+        }
+        if self.tag.is_some_and(|tag| tag != node.tag) {
+            return false;
+        }
+        if let Some(text) = &self.text {
+            if !node
+                .text
+                .as_ref()
+                .is_some_and(|node_text| node_text.contains(text))
+            {
+                return false;
+            }
+        }
+        self.attributes
+            .iter()
+            .all(|(name, value)| node.attribute(name).as_ref() == Some(value))
+    }
+}
+
+/// Node of the layout tree exported by [DevtoolsClient::layout_tree].
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct LayoutTreeNode {
+    pub node_id: NodeId,
+    pub tag: TagName,
+    pub text: Option<String>,
+    pub area: Area,
+    pub inner_area: Area,
+    pub children: Vec<LayoutTreeNode>,
+}
+
+impl LayoutTreeNode {
+    /// Build the layout tree of the nodes of a window, starting from the window node.
+    pub fn from_nodes(nodes: &[NodeInfo]) -> Option<Self> {
+        let mut children = HashMap::<NodeId, Vec<&NodeInfo>>::new();
+        for node in nodes {
+            if let Some(parent_id) = node.parent_id {
+                children.entry(parent_id).or_default().push(node);
+            }
+        }
+
+        fn build(node: &NodeInfo, children: &HashMap<NodeId, Vec<&NodeInfo>>) -> LayoutTreeNode {
+            LayoutTreeNode {
+                node_id: node.node_id,
+                tag: node.tag,
+                text: node.text.clone(),
+                area: node.layout_node.area,
+                inner_area: node.layout_node.inner_area,
+                children: children
+                    .get(&node.node_id)
+                    .map(|node_children| {
+                        node_children
+                            .iter()
+                            .map(|child| build(child, children))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        }
+
+        nodes
+            .iter()
+            .find(|node| node.is_window)
+            .map(|window| build(window, &children))
+    }
+}
+
+/// Client of the devtools server of a running app, for scripting the inspection of apps.
+///
+/// ```rust, no_run
+/// # use freya_devtools::{DevtoolsClient, NodeQuery, DEVTOOLS_SERVER_URL};
+/// # async fn inspect() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut client = DevtoolsClient::connect(DEVTOOLS_SERVER_URL).await?;
+/// client.wait_for_nodes().await?;
+///
+/// if let Some(node) = client.find_node(&NodeQuery::new().text("Submit")) {
+///     client.highlight(&node).await?;
+/// }
+///
+/// for window_id in client.windows() {
+///     client.save_layout_tree(window_id, format!("layout-{window_id}.json"))?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct DevtoolsClient {
+    sink: WebSocketSink,
+    snapshot: Arc<Mutex<DevtoolsSnapshot>>,
+    updates: broadcast::Receiver<OutgoingMessageAction>,
+    reader: JoinHandle<()>,
+}
+
+impl Drop for DevtoolsClient {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+impl DevtoolsClient {
+    /// Connect to the devtools server listening in the given address, e.g [DEVTOOLS_SERVER_URL].
+    pub async fn connect(url: &str) -> Result<Self, DevtoolsClientError> {
+        let (websocket, _) = connect_async(url).await?;
+        let (sink, mut stream) = websocket.split();
+
+        let snapshot = Arc::new(Mutex::new(DevtoolsSnapshot::default()));
+        let (updates_sender, updates) = broadcast::channel(256);
+
+        let reader = tokio::spawn({
+            let snapshot = snapshot.clone();
+            async move {
+                while let Some(Ok(message)) = stream.next().await {
+                    let Ok(text) = message.into_text() else {
+                        continue;
+                    };
+                    let Ok(outgoing) = serde_json::from_str::<OutgoingMessage>(&text) else {
+                        continue;
+                    };
+                    snapshot.lock().unwrap().apply(&outgoing.action);
+                    updates_sender.send(outgoing.action).ok();
+                }
+            }
+        });
+
+        Ok(Self {
+            sink,
+            snapshot,
+            updates,
+            reader,
+        })
+    }
+
+    /// Receive every message sent by the server from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<OutgoingMessageAction> {
+        self.updates.resubscribe()
+    }
+
+    /// Wait until the server sends the nodes of a window, unless some were received already.
+    pub async fn wait_for_nodes(&self) -> Result<(), DevtoolsClientError> {
+        let mut updates = self.subscribe();
+        if !self.snapshot.lock().unwrap().nodes.is_empty() {
+            return Ok(());
+        }
+        loop {
+            match updates.recv().await {
+                Ok(OutgoingMessageAction::Update { nodes, .. }) if !nodes.is_empty() => {
+                    return Ok(());
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(DevtoolsClientError::Disconnected);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Wait for the next update of the nodes of any window.
+    pub async fn wait_for_update(&self) -> Result<(), DevtoolsClientError> {
+        let mut updates = self.subscribe();
+        loop {
+            match updates.recv().await {
+                Ok(OutgoingMessageAction::Update { .. }) => return Ok(()),
+                Err(broadcast::error::RecvError::Closed) => {
+                    return Err(DevtoolsClientError::Disconnected);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Copy of the latest state received from the server.
+    pub fn snapshot(&self) -> DevtoolsSnapshot {
+        self.snapshot.lock().unwrap().clone()
+    }
+
+    /// Identifiers of the open windows, sorted.
+    pub fn windows(&self) -> Vec<u64> {
+        let mut windows = self
+            .snapshot
+            .lock()
+            .unwrap()
+            .nodes
+            .keys()
+            .copied()
+            .collect::<Vec<_>>();
+        windows.sort_unstable();
+        windows
+    }
+
+    /// Nodes of the given window, in depth-first order.
+    pub fn nodes(&self, window_id: u64) -> Vec<NodeInfo> {
+        self.snapshot
+            .lock()
+            .unwrap()
+            .nodes
+            .get(&window_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Find all the nodes matching the given query, in depth-first order.
+    pub fn find_nodes(&self, query: &NodeQuery) -> Vec<NodeInfo> {
+        let snapshot = self.snapshot.lock().unwrap();
+        let mut windows = snapshot.nodes.iter().collect::<Vec<_>>();
+        windows.sort_unstable_by_key(|(window_id, _)| **window_id);
+        windows
+            .into_iter()
+            .flat_map(|(_, nodes)| nodes.iter())
+            .filter(|node| query.matches(node))
+            .cloned()
+            .collect()
+    }
+
+    /// Find the first node matching the given query.
+    pub fn find_node(&self, query: &NodeQuery) -> Option<NodeInfo> {
+        self.find_nodes(query).into_iter().next()
+    }
+
+    /// Send a message to the server, just like the devtools app does.
+    pub async fn send(&mut self, action: IncomingMessageAction) -> Result<(), DevtoolsClientError> {
+        let message = Message::Text(serde_json::to_string(&IncomingMessage { action })?.into());
+        self.sink.send(message).await?;
+        Ok(())
+    }
+
+    /// Highlight the given node in its window.
+    pub async fn highlight(&mut self, node: &NodeInfo) -> Result<(), DevtoolsClientError> {
+        self.send(IncomingMessageAction::HighlightNode {
+            window_id: node.window_id,
+            node_id: node.node_id,
+        })
+        .await
+    }
+
+    /// Override an attribute of the given node, see [IncomingMessageAction::SetAttribute].
+    pub async fn set_attribute(
+        &mut self,
+        node: &NodeInfo,
+        attribute: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<(), DevtoolsClientError> {
+        self.send(IncomingMessageAction::SetAttribute {
+            window_id: node.window_id,
+            node_id: node.node_id,
+            attribute: attribute.into(),
+            value: value.into(),
+        })
+        .await
+    }
+
+    /// Restore the overridden attributes of the given node.
+    pub async fn reset_attributes(&mut self, node: &NodeInfo) -> Result<(), DevtoolsClientError> {
+        self.send(IncomingMessageAction::ResetAttributes {
+            window_id: node.window_id,
+            node_id: node.node_id,
+        })
+        .await
+    }
+
+    /// Change the speed of the animations of the app.
+    pub async fn set_animation_speed(&mut self, speed: f32) -> Result<(), DevtoolsClientError> {
+        self.send(IncomingMessageAction::SetSpeedTo { speed }).await
+    }
+
+    /// Build the layout tree of the given window, starting from the window node.
+    pub fn layout_tree(&self, window_id: u64) -> Option<LayoutTreeNode> {
+        LayoutTreeNode::from_nodes(&self.nodes(window_id))
+    }
+
+    /// Serialize the layout tree of the given window as pretty JSON.
+    pub fn layout_tree_json(&self, window_id: u64) -> Result<String, DevtoolsClientError> {
+        Ok(serde_json::to_string_pretty(&self.layout_tree(window_id))?)
+    }
+
+    /// Save the layout tree of the given window as JSON in the given file.
+    pub fn save_layout_tree(
+        &self,
+        window_id: u64,
+        path: impl AsRef<Path>,
+    ) -> Result<(), DevtoolsClientError> {
+        std::fs::write(path, self.layout_tree_json(window_id)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya_core::node_state_snapshot::NodeState;
+    use torin::{
+        prelude::{
+            LayoutNode,
+            Length,
+        },
+        size::Size,
+    };
+
+    use super::*;
+
+    fn node_id(index: u64) -> NodeId {
+        format!("{index}-0").parse().unwrap()
+    }
+
+    fn node(window_id: u64, index: u64, parent: Option<u64>, tag: TagName) -> NodeInfo {
+        NodeInfo {
+            window_id,
+            is_window: parent.is_none(),
+            node_id: node_id(index),
+            parent_id: parent.map(node_id),
+            children_len: 0,
+            tag,
+            height: 0,
+            state: NodeState::default(),
+            layout_node: LayoutNode {
+                area: Area::new((0., index as f32 * 10.).into(), (100., 10.).into()),
+                ..LayoutNode::default()
+            },
+            overridden_attributes: Vec::new(),
+            text: None,
+        }
+    }
+
+    fn event_log_entry(index: u64) -> EventLogEntry {
+        EventLogEntry {
+            index,
+            window_id: 1,
+            source_events: Vec::new(),
+            potential_events: Vec::new(),
+            emitted_events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn query_matches() {
+        let mut button = node(1, 2, Some(1), TagName::Rect);
+        button.state.layout.width = Size::Pixels(Length::new(100.));
+        button.text = Some("Submit form".to_string());
+
+        assert!(NodeQuery::new().matches(&button));
+        assert!(NodeQuery::new().window(1).matches(&button));
+        assert!(!NodeQuery::new().window(2).matches(&button));
+        assert!(NodeQuery::new().tag(TagName::Rect).matches(&button));
+        assert!(!NodeQuery::new().tag(TagName::Label).matches(&button));
+        assert!(NodeQuery::new().text("Submit").matches(&button));
+        assert!(!NodeQuery::new().text("Cancel").matches(&button));
+        assert!(NodeQuery::new().attribute("width", "100").matches(&button));
+        assert!(!NodeQuery::new().attribute("width", "50").matches(&button));
+        assert!(
+            !NodeQuery::new()
+                .attribute("unknown", "100")
+                .matches(&button)
+        );
+
+        // All the criteria must match
+        assert!(
+            !NodeQuery::new()
+                .tag(TagName::Rect)
+                .text("Cancel")
+                .matches(&button)
+        );
+
+        // Nodes without text never match a text
+        let container = node(1, 3, Some(1), TagName::Rect);
+        assert!(!NodeQuery::new().text("").matches(&container));
+    }
+
+    #[test]
+    fn snapshot_apply() {
+        let mut snapshot = DevtoolsSnapshot::default();
+        let nodes = vec![node(1, 1, None, TagName::Rect)];
+
+        snapshot.apply(&OutgoingMessageAction::Update {
+            window_id: 1,
+            nodes: nodes.clone(),
+        });
+        assert_eq!(snapshot.nodes.get(&1), Some(&nodes));
+
+        // Windows without nodes are removed
+        snapshot.apply(&OutgoingMessageAction::Update {
+            window_id: 1,
+            nodes: Vec::new(),
+        });
+        assert!(snapshot.nodes.is_empty());
+
+        snapshot.apply(&OutgoingMessageAction::AccessibilityTree {
+            window_id: 1,
+            nodes: Vec::new(),
+        });
+        assert!(snapshot.accessibility_nodes.is_empty());
+
+        // Only the latest entries of the events log are kept
+        snapshot.apply(&OutgoingMessageAction::EventLog {
+            entries: (0..MAX_EVENT_LOG_ENTRIES as u64)
+                .map(event_log_entry)
+                .collect(),
+        });
+        snapshot.apply(&OutgoingMessageAction::EventLog {
+            entries: vec![event_log_entry(MAX_EVENT_LOG_ENTRIES as u64)],
+        });
+        assert_eq!(snapshot.event_log.len(), MAX_EVENT_LOG_ENTRIES);
+        assert_eq!(snapshot.event_log[0].index, 1);
+        assert_eq!(
+            snapshot.event_log.last().map(|entry| entry.index),
+            Some(MAX_EVENT_LOG_ENTRIES as u64)
+        );
+    }
+
+    #[test]
+    fn layout_tree() {
+        let mut label = node(1, 3, Some(2), TagName::Label);
+        label.text = Some("Hello".to_string());
+        let nodes = vec![
+            node(1, 1, None, TagName::Rect),
+            node(1, 2, Some(1), TagName::Rect),
+            label,
+            node(1, 4, Some(1), TagName::Image),
+        ];
+
+        let tree = LayoutTreeNode::from_nodes(&nodes).unwrap();
+        assert_eq!(tree.node_id, node_id(1));
+        assert_eq!(
+            tree.children
+                .iter()
+                .map(|child| (child.node_id, child.tag))
+                .collect::<Vec<_>>(),
+            vec![(node_id(2), TagName::Rect), (node_id(4), TagName::Image)]
+        );
+
+        let label = &tree.children[0].children[0];
+        assert_eq!(label.text.as_deref(), Some("Hello"));
+        assert_eq!(label.area, nodes[2].layout_node.area);
+        assert!(label.children.is_empty());
+
+        // There is no tree without the window node
+        assert_eq!(LayoutTreeNode::from_nodes(&nodes[1..]), None);
+    }
+}
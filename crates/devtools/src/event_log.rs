@@ -0,0 +1,149 @@
+use freya_native_core::NodeId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// How many entries of the events log are kept, both by the app and by the devtools.
+pub const MAX_EVENT_LOG_ENTRIES: usize = 500;
+
+/// Events processed in a run of the events executor of a window.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct EventLogEntry {
+    /// Position of this entry since the app started.
+    pub index: u64,
+    pub window_id: u64,
+    /// Platform events that were processed.
+    pub source_events: Vec<SourceEventInfo>,
+    /// Nodes that were hit by the source events, from the top layer to the bottom one.
+    pub potential_events: Vec<PotentialEventInfo>,
+    /// Events sent to the handlers of the nodes, in the order they were emitted.
+    pub emitted_events: Vec<EmittedEventInfo>,
+}
+
+impl EventLogEntry {
+    /// Check if nothing was hit or emitted, e.g. the cursor moving over an empty area.
+    pub fn is_empty(&self) -> bool {
+        self.potential_events.is_empty() && self.emitted_events.is_empty()
+    }
+
+    /// Check if any event of this entry involves the given node.
+    pub fn involves(&self, node_id: NodeId) -> bool {
+        self.potential_events
+            .iter()
+            .any(|event| event.node_id == node_id)
+            || self.emitted_events.iter().any(|event| {
+                event.node_id == node_id
+                    || event
+                        .cancelled_events
+                        .iter()
+                        .any(|event| event.node_id == node_id)
+            })
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct SourceEventInfo {
+    pub name: String,
+    pub cursor: Option<(f64, f64)>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct PotentialEventInfo {
+    pub node_id: NodeId,
+    pub name: String,
+    pub layer: i16,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct EmittedEventInfo {
+    pub node_id: NodeId,
+    pub name: String,
+    /// Name of the platform event this event was derived from.
+    pub source: String,
+    pub bubbles: bool,
+    /// Whether the handler kept the default action enabled, otherwise `cancelled_events` were discarded.
+    pub default_action_enabled: bool,
+    /// Whether a handler of this node or one of its ancestors stopped the bubbling.
+    ///
+    /// Dioxus calls all the handlers of the bubbling path at once and only exposes whether the event
+    /// still propagates in the end, so which of the handlers stopped it is unknown.
+    pub propagation_stopped: bool,
+    pub cancelled_events: Vec<CancelledEventInfo>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct CancelledEventInfo {
+    pub node_id: NodeId,
+    pub name: String,
+}
+
+#[cfg(feature = "server")]
+mod conversion {
+    use freya_core::{
+        events::DomEvent,
+        types::DomExecutedEvents,
+    };
+    use ragnarok::SourceEvent;
+
+    use super::*;
+
+    impl EventLogEntry {
+        pub fn new(index: u64, window_id: u64, events: &DomExecutedEvents) -> Self {
+            Self {
+                index,
+                window_id,
+                source_events: events
+                    .source_events
+                    .iter()
+                    .map(|event| SourceEventInfo {
+                        name: event_name(event.as_event_name()),
+                        cursor: event.try_cursor().map(|cursor| (cursor.x, cursor.y)),
+                    })
+                    .collect(),
+                potential_events: events
+                    .potential_events
+                    .iter()
+                    .map(|event| PotentialEventInfo {
+                        node_id: event.node_key,
+                        name: event_name(event.name),
+                        layer: event.layer,
+                    })
+                    .collect(),
+                emitted_events: events
+                    .emitted_events
+                    .iter()
+                    .map(|emitted| {
+                        let DomEvent {
+                            node_id,
+                            name,
+                            source_event,
+                            bubbles,
+                            ..
+                        } = &emitted.event;
+                        EmittedEventInfo {
+                            node_id: *node_id,
+                            name: event_name(*name),
+                            source: event_name(*source_event),
+                            bubbles: *bubbles,
+                            default_action_enabled: emitted.outcome.default_action_enabled,
+                            propagation_stopped: emitted.outcome.propagation_stopped,
+                            cancelled_events: emitted
+                                .cancelled_events
+                                .iter()
+                                .map(|event| CancelledEventInfo {
+                                    node_id: event.node_id,
+                                    name: event_name(event.name),
+                                })
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    fn event_name(name: freya_core::events::EventName) -> String {
+        <&str>::from(name).to_string()
+    }
+}
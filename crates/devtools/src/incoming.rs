@@ -11,6 +11,23 @@ pub struct IncomingMessage {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub enum IncomingMessageAction {
-    HighlightNode { window_id: u64, node_id: NodeId },
-    SetSpeedTo { speed: f32 },
+    HighlightNode {
+        window_id: u64,
+        node_id: NodeId,
+    },
+    SetSpeedTo {
+        speed: f32,
+    },
+    /// Override an attribute of a node until its component sets it again.
+    SetAttribute {
+        window_id: u64,
+        node_id: NodeId,
+        attribute: String,
+        value: String,
+    },
+    /// Restore the attributes overridden with [IncomingMessageAction::SetAttribute].
+    ResetAttributes {
+        window_id: u64,
+        node_id: NodeId,
+    },
 }
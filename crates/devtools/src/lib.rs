@@ -1,12 +1,20 @@
+mod accessibility_info;
+mod event_log;
 mod incoming;
 mod node_info;
 mod outgoing;
 
+#[cfg(feature = "client")]
+mod client;
 #[cfg(feature = "server")]
 mod plugin;
 #[cfg(feature = "server")]
 mod server;
 
+pub use accessibility_info::*;
+#[cfg(feature = "client")]
+pub use client::*;
+pub use event_log::*;
 pub use incoming::*;
 pub use node_info::*;
 pub use outgoing::*;
@@ -5,6 +5,7 @@ use freya_core::{
         CornerRadius,
         Fill,
         Shadow,
+        ShadowPosition,
         SvgPaint,
         TextAlign,
         TextOverflow,
@@ -43,6 +44,26 @@ pub struct NodeInfo {
     pub height: u16,
     pub state: NodeState,
     pub layout_node: LayoutNode,
+    /// Attributes overridden from the devtools.
+    pub overridden_attributes: Vec<String>,
+    /// Text of the text nodes inside this element.
+    pub text: Option<String>,
+}
+
+impl NodeInfo {
+    /// Get the value of an attribute, e.g `width` or `background`, formatted the same way the devtools show it.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        [
+            self.state.layout_attributes(),
+            self.state.style_attributes(),
+            self.state.font_style_attributes(),
+            self.state.svg_attributes(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|(attribute_name, _)| *attribute_name == name)
+        .and_then(|(_, attribute)| attribute.pretty())
+    }
 }
 
 pub trait NodeStateAttributes {
@@ -209,3 +230,45 @@ pub enum AttributeType<'a> {
     TextAlignment(&'a TextAlign),
     TextOverflow(&'a TextOverflow),
 }
+
+impl AttributeType<'_> {
+    /// Format the value of this attribute, if there is any.
+    pub fn pretty(&self) -> Option<String> {
+        Some(match self {
+            Self::Color(fill) | Self::Gradient(fill) => fill.to_string(),
+            Self::OptionalColor(fill) => fill.as_ref()?.to_string(),
+            Self::Size(size) => size.pretty(),
+            Self::VisibleSize(visible_size) => visible_size.pretty(),
+            Self::Measure(measure) => measure.to_string(),
+            Self::OptionalMeasure(measure) => measure?.to_string(),
+            Self::Measures(measures) => measures.pretty(),
+            Self::CornerRadius(radius) => radius.pretty(),
+            Self::Direction(direction) => direction.pretty(),
+            Self::Position(position) => position.pretty(),
+            Self::Content(content) => content.pretty(),
+            Self::Alignment(alignment) => alignment.pretty(),
+            Self::Shadow(shadow) => {
+                let inset = if shadow.position == ShadowPosition::Inset {
+                    "inset "
+                } else {
+                    ""
+                };
+                format!(
+                    "{inset}{} {} {} {} {}",
+                    shadow.x, shadow.y, shadow.blur, shadow.spread, shadow.fill
+                )
+            }
+            Self::TextShadow(text_shadow) => format!(
+                "{} {} {} {}",
+                text_shadow.offset.0,
+                text_shadow.offset.1,
+                text_shadow.blur_sigma,
+                Fill::Color(text_shadow.color)
+            ),
+            Self::Text(text) => text.clone(),
+            Self::Border(border) => border.to_string(),
+            Self::TextAlignment(text_align) => text_align.pretty(),
+            Self::TextOverflow(text_overflow) => text_overflow.pretty(),
+        })
+    }
+}
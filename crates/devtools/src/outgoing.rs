@@ -3,17 +3,28 @@ use serde::{
     Serialize,
 };
 
-use crate::node_info::NodeInfo;
+use crate::{
+    accessibility_info::AccessibilityNodeInfo,
+    event_log::EventLogEntry,
+    node_info::NodeInfo,
+};
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct OutgoingMessage {
     pub action: OutgoingMessageAction,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 pub enum OutgoingMessageAction {
     Update {
         window_id: u64,
         nodes: Vec<NodeInfo>,
     },
+    /// Accessibility tree of a window, sent every time it changes.
+    AccessibilityTree {
+        window_id: u64,
+        nodes: Vec<AccessibilityNodeInfo>,
+    },
+    /// New entries of the events log, from the oldest to the latest.
+    EventLog { entries: Vec<EventLogEntry> },
 }
@@ -1,5 +1,8 @@
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     sync::{
         Arc,
         Mutex,
@@ -7,14 +10,25 @@ use std::{
 };
 
 use freya_core::{
+    accessibility::{
+        ACCESSIBILITY_ROOT_ID,
+        audit_accessibility,
+    },
     animation_clock::AnimationClock,
-    dom::DioxusDOM,
+    dom::{
+        DioxusNode,
+        FreyaDOM,
+    },
     node_state_snapshot::NodeStateSnapshot,
     plugins::{
         FreyaPlugin,
         PluginEvent,
         PluginHandle,
     },
+    types::{
+        AccessibilityId,
+        DomExecutedEvents,
+    },
     values::Color,
 };
 use freya_engine::prelude::{
@@ -23,6 +37,7 @@ use freya_engine::prelude::{
 };
 use freya_native_core::{
     NodeId,
+    node::NodeType,
     prelude::NodeImmutable,
 };
 use futures::{
@@ -39,17 +54,24 @@ use winit::window::WindowId;
 use crate::{
     OutgoingMessage,
     OutgoingMessageAction,
+    accessibility_info::AccessibilityNodeInfo,
+    event_log::{
+        EventLogEntry,
+        MAX_EVENT_LOG_ENTRIES,
+    },
     node_info::NodeInfo,
     server::run_server,
 };
 
 pub(crate) type Websockets = HashMap<u32, SplitSink<WebSocketStream<TokioIo<Upgraded>>, Message>>;
 pub(crate) type SharedWebsockets = Arc<tokio::sync::Mutex<Websockets>>;
+pub(crate) type SharedEventLog = Arc<Mutex<VecDeque<EventLogEntry>>>;
 
 #[derive(Clone)]
 pub struct WindowState {
     pub animation_clock: AnimationClock,
     pub nodes: Vec<NodeInfo>,
+    pub accessibility_nodes: Vec<AccessibilityNodeInfo>,
 }
 
 #[derive(Default)]
@@ -58,17 +80,35 @@ pub struct DevtoolsPlugin {
     websockets: SharedWebsockets,
     init: Option<()>,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
+    event_log: SharedEventLog,
+    event_log_index: u64,
+    focused_accessibility_ids: HashMap<u64, AccessibilityId>,
+    window_backgrounds: HashMap<u64, Color>,
+}
+
+/// Join the text nodes that are direct children of the given node.
+fn inner_text(node: &DioxusNode) -> Option<String> {
+    let texts = node
+        .children()
+        .iter()
+        .filter_map(|child| match &*child.node_type() {
+            NodeType::Text(text) => Some(text.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    (!texts.is_empty()).then(|| texts.concat())
 }
 
 impl DevtoolsPlugin {
     pub fn sync(
         &mut self,
         window_id: WindowId,
-        rdom: &DioxusDOM,
+        fdom: &FreyaDOM,
         layout: &Torin<NodeId>,
         animation_clock: AnimationClock,
     ) {
         let window_id: u64 = window_id.into();
+        let rdom = fdom.rdom();
         let mut new_nodes = Vec::new();
 
         rdom.traverse_depth_first(|node| {
@@ -91,20 +131,58 @@ impl DevtoolsPlugin {
                         height: node.height(),
                         state: node.state_snapshot(),
                         layout_node,
+                        overridden_attributes: fdom
+                            .attribute_overrides(node.id())
+                            .iter()
+                            .map(|name| name.as_str().to_string())
+                            .collect(),
+                        text: inner_text(&node),
                     });
                 }
             }
         });
 
+        let focused_id = self
+            .focused_accessibility_ids
+            .get(&window_id)
+            .copied()
+            .unwrap_or(ACCESSIBILITY_ROOT_ID);
+        let background = self
+            .window_backgrounds
+            .get(&window_id)
+            .copied()
+            .unwrap_or(Color::WHITE);
+        let findings = audit_accessibility(rdom, background);
+        let accessibility_nodes =
+            AccessibilityNodeInfo::collect(rdom, layout, focused_id, &findings);
+
         // Update nodes snapshot
-        self.windows.lock().unwrap().insert(
+        let previous_state = self.windows.lock().unwrap().insert(
             window_id,
             WindowState {
                 nodes: new_nodes,
+                accessibility_nodes: accessibility_nodes.clone(),
                 animation_clock,
             },
         );
 
+        // Only notify about the accessibility tree when it changes
+        let accessibility_changed = previous_state
+            .is_none_or(|previous_state| previous_state.accessibility_nodes != accessibility_nodes);
+        if accessibility_changed {
+            let outgoing_message = Message::Text(
+                serde_json::to_string(&OutgoingMessage {
+                    action: OutgoingMessageAction::AccessibilityTree {
+                        window_id,
+                        nodes: accessibility_nodes,
+                    },
+                })
+                .unwrap()
+                .into(),
+            );
+            self.broadcast(outgoing_message);
+        }
+
         // Notify the existing subscribers of this change
         let outgoing_message = Message::Text(
             serde_json::to_string(&OutgoingMessage {
@@ -123,10 +201,44 @@ impl DevtoolsPlugin {
             .unwrap()
             .into(),
         );
+        self.broadcast(outgoing_message);
+    }
+
+    /// Log the events processed by a window and notify the subscribers.
+    fn log_events(&mut self, window_id: WindowId, events: &DomExecutedEvents) {
+        let entry = EventLogEntry::new(self.event_log_index, window_id.into(), events);
+
+        // Ignore the events that didn't reach any node, e.g. moving the cursor over nothing
+        if entry.is_empty() {
+            return;
+        }
+        self.event_log_index += 1;
+
+        {
+            let mut event_log = self.event_log.lock().unwrap();
+            event_log.push_back(entry.clone());
+            while event_log.len() > MAX_EVENT_LOG_ENTRIES {
+                event_log.pop_front();
+            }
+        }
+
+        let outgoing_message = Message::Text(
+            serde_json::to_string(&OutgoingMessage {
+                action: OutgoingMessageAction::EventLog {
+                    entries: vec![entry],
+                },
+            })
+            .unwrap()
+            .into(),
+        );
+        self.broadcast(outgoing_message);
+    }
+
+    fn broadcast(&self, message: Message) {
         let websockets = self.websockets.clone();
         tokio::spawn(async move {
             for websocket in websockets.lock().await.values_mut() {
-                websocket.send(outgoing_message.clone()).await.unwrap();
+                websocket.send(message.clone()).await.unwrap();
             }
         });
     }
@@ -140,24 +252,41 @@ impl FreyaPlugin for DevtoolsPlugin {
 
                 // Update nodes snapshot
                 self.windows.lock().unwrap().remove(&window_id);
+                self.focused_accessibility_ids.remove(&window_id);
+                self.window_backgrounds.remove(&window_id);
 
                 // Notify the existing subscribers of this change
-                let outgoing_message = Message::Text(
-                    serde_json::to_string(&OutgoingMessage {
-                        action: OutgoingMessageAction::Update {
-                            window_id,
-                            nodes: vec![],
-                        },
-                    })
-                    .unwrap()
-                    .into(),
-                );
-                let websockets = self.websockets.clone();
-                tokio::spawn(async move {
-                    for websocket in websockets.lock().await.values_mut() {
-                        websocket.send(outgoing_message.clone()).await.unwrap();
-                    }
-                });
+                for action in [
+                    OutgoingMessageAction::Update {
+                        window_id,
+                        nodes: vec![],
+                    },
+                    OutgoingMessageAction::AccessibilityTree {
+                        window_id,
+                        nodes: vec![],
+                    },
+                ] {
+                    let outgoing_message = Message::Text(
+                        serde_json::to_string(&OutgoingMessage { action })
+                            .unwrap()
+                            .into(),
+                    );
+                    self.broadcast(outgoing_message);
+                }
+            }
+            PluginEvent::EmittedEvents { window, events, .. } => {
+                self.log_events(window.id(), events);
+            }
+            PluginEvent::FinishedUpdatingAccessibility {
+                window,
+                focused_id,
+                background,
+                ..
+            } => {
+                self.focused_accessibility_ids
+                    .insert(window.id().into(), *focused_id);
+                self.window_backgrounds
+                    .insert(window.id().into(), *background);
             }
             PluginEvent::AfterRender {
                 fdom,
@@ -165,7 +294,6 @@ impl FreyaPlugin for DevtoolsPlugin {
                 canvas,
                 ..
             } => {
-                let rdom = fdom.rdom();
                 let layout = fdom.layout();
                 let animation_clock = fdom.animation_clock();
 
@@ -196,18 +324,25 @@ impl FreyaPlugin for DevtoolsPlugin {
                     }
                 }
 
-                self.sync(window.id(), rdom, &layout, animation_clock.clone());
+                self.sync(window.id(), fdom, &layout, animation_clock.clone());
             }
             PluginEvent::WindowCreated { .. } => {
                 if self.init.is_none() {
                     let nodes = self.windows.clone();
                     let websockets = self.websockets.clone();
                     let highlighted_node = self.highlighted_node.clone();
+                    let event_log = self.event_log.clone();
                     let plugin_handle = plugin_handle.clone();
                     tokio::spawn(async move {
-                        run_server(nodes, websockets, highlighted_node, plugin_handle)
-                            .await
-                            .unwrap();
+                        run_server(
+                            nodes,
+                            websockets,
+                            highlighted_node,
+                            event_log,
+                            plugin_handle,
+                        )
+                        .await
+                        .unwrap();
                     });
                     self.init.replace(());
                 }
@@ -215,4 +350,8 @@ impl FreyaPlugin for DevtoolsPlugin {
             _ => {}
         }
     }
+
+    fn wants_emitted_events(&self) -> bool {
+        true
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    str::FromStr,
     sync::{
         Arc,
         Mutex,
@@ -17,7 +18,10 @@ use freya_core::{
     },
     plugins::PluginHandle,
 };
-use freya_native_core::NodeId;
+use freya_native_core::{
+    NodeId,
+    attributes::AttributeName,
+};
 use futures::{
     sink::SinkExt,
     stream::StreamExt,
@@ -42,6 +46,7 @@ use crate::{
     IncomingMessage,
     OutgoingMessage,
     OutgoingMessageAction,
+    SharedEventLog,
     SharedWebsockets,
     WindowState,
     incoming::IncomingMessageAction,
@@ -54,6 +59,7 @@ async fn handle_request(
     websockets: SharedWebsockets,
     mut request: Request<Incoming>,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
+    event_log: SharedEventLog,
     plugin_handle: PluginHandle,
 ) -> Result<Response<Full<Bytes>>, Error> {
     if hyper_tungstenite::is_upgrade_request(&request) {
@@ -67,6 +73,7 @@ async fn handle_request(
                 id,
                 websocket,
                 highlighted_node,
+                event_log,
                 plugin_handle,
             )
             .await
@@ -91,13 +98,22 @@ async fn serve_websocket(
     id: u32,
     websocket: HyperWebsocket,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
+    event_log: SharedEventLog,
     plugin_handle: PluginHandle,
 ) -> Result<(), Error> {
     let websocket = websocket.await?;
     let (mut write, mut read) = websocket.split();
 
     let windows_snapshot = windows.lock().unwrap().clone();
-    for (window_id, WindowState { nodes, .. }) in windows_snapshot {
+    for (
+        window_id,
+        WindowState {
+            nodes,
+            accessibility_nodes,
+            ..
+        },
+    ) in windows_snapshot
+    {
         let message = Message::Text(
             serde_json::to_string(&OutgoingMessage {
                 action: OutgoingMessageAction::Update { window_id, nodes },
@@ -107,8 +123,31 @@ async fn serve_websocket(
 
         // Send nodes snapshot
         write.send(message).await?;
+
+        let message = Message::Text(
+            serde_json::to_string(&OutgoingMessage {
+                action: OutgoingMessageAction::AccessibilityTree {
+                    window_id,
+                    nodes: accessibility_nodes,
+                },
+            })?
+            .into(),
+        );
+
+        // Send accessibility tree snapshot
+        write.send(message).await?;
     }
 
+    // Send the events logged so far
+    let entries = event_log.lock().unwrap().iter().cloned().collect();
+    let message = Message::Text(
+        serde_json::to_string(&OutgoingMessage {
+            action: OutgoingMessageAction::EventLog { entries },
+        })?
+        .into(),
+    );
+    write.send(message).await?;
+
     // Store websocket
     websockets.lock().await.insert(id, write);
 
@@ -126,6 +165,30 @@ async fn serve_websocket(
                                 action: EventLoopMessageAction::RequestRerender,
                             });
                         }
+                        IncomingMessageAction::SetAttribute {
+                            window_id,
+                            node_id,
+                            attribute,
+                            value,
+                        } => match AttributeName::from_str(&attribute) {
+                            Ok(name) => {
+                                plugin_handle.send_event_loop_event(EventLoopMessage {
+                                    window_id: Some(window_id.into()),
+                                    action: EventLoopMessageAction::OverrideAttribute {
+                                        node_id,
+                                        name,
+                                        value,
+                                    },
+                                });
+                            }
+                            Err(err) => println!("{err}"),
+                        },
+                        IncomingMessageAction::ResetAttributes { window_id, node_id } => {
+                            plugin_handle.send_event_loop_event(EventLoopMessage {
+                                window_id: Some(window_id.into()),
+                                action: EventLoopMessageAction::ResetAttributeOverrides(node_id),
+                            });
+                        }
                         IncomingMessageAction::SetSpeedTo { speed } => {
                             for WindowState {
                                 animation_clock, ..
@@ -154,6 +217,7 @@ pub async fn run_server(
     windows: Arc<Mutex<HashMap<u64, WindowState>>>,
     websockets: SharedWebsockets,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
+    event_log: SharedEventLog,
     plugin_handle: PluginHandle,
 ) -> Result<(), Error> {
     let addr: std::net::SocketAddr = "[::1]:7354".parse()?;
@@ -168,6 +232,7 @@ pub async fn run_server(
         let windows = windows.clone();
         let websockets = websockets.clone();
         let highlighted_node = highlighted_node.clone();
+        let event_log = event_log.clone();
         let plugin_handle = plugin_handle.clone();
         let connection = http
             .serve_connection(
@@ -178,6 +243,7 @@ pub async fn run_server(
                         websockets.clone(),
                         req,
                         highlighted_node.clone(),
+                        event_log.clone(),
                         plugin_handle.clone(),
                     )
                 }),
@@ -156,4 +156,144 @@ def_attribute!(
     /// }
     /// ```
     sampling,
+
+    /// `decoding` controls when the `image` element data gets decoded.
+    ///
+    /// Accepted values:
+    /// - `async` (default): The image is decoded in a pool of background threads. Nothing is painted until it is ready,
+    ///   and a new frame is requested once it is. The layout does not wait for it, as only the image header is read to know its size.
+    /// - `sync`: The image is decoded while being painted, so it is painted in the same frame, but this might stall it with big images.
+    ///
+    /// `async` decoding is only supported for `image_data`, `image_reference` is always decoded while painting.
+    /// Animated images (GIF, APNG or WebP) are always decoded in background.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static RUST_LOGO: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     let image_data = static_bytes(RUST_LOGO);
+    ///     rsx!(
+    ///         image {
+    ///             image_data,
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             decoding: "sync"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    decoding,
+
+    /// `downsample` makes an `image` element decoded in background decode its data with the size it is
+    /// painted with (taking the scale factor into account) instead of its original size, which saves memory for big images shown small.
+    /// The image is decoded again if its size changes. Images are never upscaled.
+    ///
+    /// Accepted values: `true` or `false` (default).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static RUST_LOGO: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     let image_data = static_bytes(RUST_LOGO);
+    ///     rsx!(
+    ///         image {
+    ///             image_data,
+    ///             width: "64",
+    ///             height: "64",
+    ///             downsample: "true"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    downsample,
+
+    /// `playing` controls whether an animated `image` element (GIF, APNG or WebP) is playing or paused.
+    /// Animations are also paused while the element is not visible, e.g scrolled out of view.
+    ///
+    /// Accepted values: `true` (default) or `false`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static SPINNER: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     let mut playing = use_signal(|| true);
+    ///     rsx!(
+    ///         image {
+    ///             image_data: static_bytes(SPINNER),
+    ///             width: "64",
+    ///             height: "64",
+    ///             playing: "{playing}",
+    ///             onclick: move |_| playing.toggle(),
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    playing,
+
+    /// `repeat` controls how many times an animated `image` element is repeated after being played once.
+    ///
+    /// Accepted values:
+    /// - `auto` (default): Use the repetition count encoded in the image.
+    /// - `infinite`: Repeat forever.
+    /// - `none`: Play it only once.
+    /// - A number of repetitions, e.g `2`.
+    ///
+    /// Once finished, the last frame is kept.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static SPINNER: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         image {
+    ///             image_data: static_bytes(SPINNER),
+    ///             width: "64",
+    ///             height: "64",
+    ///             repeat: "infinite",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    repeat,
+
+    /// `image_status` reports whether the image of an `image` element is being decoded, was decoded or failed to decode.
+    ///
+    /// This attribute is used in conjunction with the `use_image_status` hook.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static RUST_LOGO: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     let (image_status, status) = use_image_status();
+    ///     rsx!(
+    ///         image {
+    ///             image_data: static_bytes(RUST_LOGO),
+    ///             width: "64",
+    ///             height: "64",
+    ///             image_status,
+    ///         }
+    ///         if status() == Some(ImageDecodeStatus::Failed) {
+    ///             label {
+    ///                 "Could not load the image"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    image_status,
 );
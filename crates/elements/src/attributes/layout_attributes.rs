@@ -390,6 +390,8 @@ def_attribute!(
     /// - `normal` (default): Uses parent bounds.
     /// - `fit`: Uses parent bounds but later shrunks to the size of the biggest element inside.
     /// - `flex`: Marks the container as flex container, children of this element will be able to use `size`/`size(n)` in their `width` and `height` attributes.
+    /// - `wrap`: Inner elements that don't fit in the current line along the `direction` are moved into a new line. The space between lines can be set with `wrap_spacing`.
+    /// - `grid`: Inner elements are placed in the cells of a grid defined by `grid_columns` and `grid_rows`.
     ///
     ///
     /// ### `fit`
@@ -429,6 +431,35 @@ def_attribute!(
     ///     )
     /// }
     /// ```
+    ///
+    /// ### `wrap`
+    ///
+    /// The `wrap` mode breaks the inner elements into multiple lines. `main_align` and `cross_align` are applied to every line individually.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "wrap",
+    ///             direction: "horizontal",
+    ///             width: "250",
+    ///             spacing: "10",
+    ///             wrap_spacing: "10",
+    ///             for i in 0..5 {
+    ///                 rect {
+    ///                     key: "{i}",
+    ///                     width: "100", // Only two elements fit in every line
+    ///                     height: "50",
+    ///                     background: "red",
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
     content,
 
     /// ### main_align
@@ -541,6 +572,180 @@ def_attribute!(
     /// ```
     spacing,
 
+    /// Specify a space between the lines of an element with `content: "wrap"`.
+    /// It only applies to the cross axis of the direction.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "wrap",
+    ///             direction: "horizontal",
+    ///             width: "200",
+    ///             spacing: "10",
+    ///             // There will be a space of 20 pixels between the lines
+    ///             wrap_spacing: "20",
+    ///             rect {
+    ///                 width: "150",
+    ///                 height: "100",
+    ///                 background: "red",
+    ///             }
+    ///             // Doesn't fit next to the previous element so it goes in a new line
+    ///             rect {
+    ///                 width: "150",
+    ///                 height: "100",
+    ///                 background: "blue",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    wrap_spacing,
+
+    /// Specify the sizes of the columns of an element with `content: "grid"`, separated by spaces.
+    ///
+    /// Every column accepts the [`Size Units`](crate::_docs::size_unit):
+    ///
+    /// - Fixed sizes like `100`, `50%` or `calc(50% - 10)`.
+    /// - `flex(n)`: Shares the remaining space with the other flex columns.
+    /// - `auto`: Takes the size of its biggest element.
+    ///
+    /// The `spacing` attribute is applied between both the columns and the rows,
+    /// and `main_align`/`cross_align` align the inner elements inside their cells.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             width: "fill",
+    ///             grid_columns: "150 flex(1) flex(2)",
+    ///             grid_rows: "50 auto",
+    ///             spacing: "8",
+    ///             label { "Name" }
+    ///             rect { width: "fill", height: "fill", background: "red" }
+    ///             rect { width: "fill", height: "fill", background: "blue" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_columns,
+
+    /// Specify the sizes of the rows of an element with `content: "grid"`, separated by spaces.
+    /// It accepts the same values as `grid_columns`.
+    ///
+    /// Elements that don't fit in the defined rows are placed in new `auto` rows.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             height: "fill",
+    ///             grid_columns: "flex(1) flex(1)",
+    ///             grid_rows: "flex(1) 100",
+    ///             rect { width: "fill", height: "fill", background: "red" }
+    ///             rect { width: "fill", height: "fill", background: "blue" }
+    ///             rect { width: "fill", height: "fill", background: "green" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_rows,
+
+    /// Specify in what column of the parent grid the element is placed and how many columns it spans.
+    ///
+    /// Accepted values:
+    ///
+    /// - `auto` (default): Placed in the next free cell.
+    /// - `n`: Placed in the column with index `n`, starting from `0`.
+    /// - `span n`: Placed in the next free cell, spanning `n` columns.
+    /// - `n span m`: Placed in the column with index `n`, spanning `m` columns.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             grid_columns: "100 100 100",
+    ///             rect {
+    ///                 grid_column: "1 span 2",
+    ///                 width: "fill",
+    ///                 height: "50",
+    ///                 background: "red",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_column,
+
+    /// Specify in what row of the parent grid the element is placed and how many rows it spans.
+    /// It accepts the same values as `grid_column`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             grid_columns: "100 100",
+    ///             grid_rows: "50 50",
+    ///             rect {
+    ///                 grid_row: "0 span 2",
+    ///                 width: "fill",
+    ///                 height: "fill",
+    ///                 background: "red",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_row,
+
+    /// Specify the horizontal direction in which the element and its inner elements are laid out.
+    /// It's inherited by the inner elements, so it's usually set once at the root of the app.
+    ///
+    /// In `rtl`, horizontally stacked elements start from the right, `start` and `end` alignments
+    /// in the horizontal axis are mirrored, as well as the left and right sides of `padding`, `margin`
+    /// and `position`. Texts also use it as their base direction.
+    ///
+    /// Accepted values:
+    ///
+    /// - `ltr` (default)
+    /// - `rtl`
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             layout_direction: "rtl",
+    ///             direction: "horizontal",
+    ///             label { "First" }
+    ///             label { "Second" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    layout_direction,
+
     /// Specify how overflow should be handled.
     ///
     /// Accepted values:
@@ -661,4 +866,31 @@ def_attribute!(
     /// }
     /// ```
     layer,
+
+    /// Caches the rendered content of the element and its descendants, so it can be painted again
+    /// without rendering each node. The cache is discarded once any node inside changes or when the scale factor changes.
+    ///
+    /// Useful for big and mostly static subtrees that get painted often because of changes in other elements.
+    /// Accepts `true` or `false` (default).
+    ///
+    /// Descendants using the `layer` attribute are painted in the same layer as the cached element.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             layer_cache: "true",
+    ///             for i in 0..100 {
+    ///                 label {
+    ///                     "Row {i}"
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    layer_cache,
 );
@@ -39,7 +39,7 @@ def_attribute!(
     /// Specify borders for an element.
     ///
     /// The `border` attribute follows this syntax:
-    /// border: `<width(s)> <alignment> <fill>`
+    /// border: `<width(s)> <alignment> <style>? <fill(s)>`
     ///
     /// Width specification follows CSS-like patterns:
     /// - Single value: Applied to all sides
@@ -54,6 +54,14 @@ def_attribute!(
     ///
     /// *Border alignment* determines how the border is positioned relative to the element's edge. Alignment can be `inner`, `outer`, or `center`.
     ///
+    /// Style is optional and can be one of:
+    /// - `solid` (default)
+    /// - `dashed`: Dashes proportional to the border width, or a custom pattern like `dashed(6 2)` (dash length and gap)
+    /// - `dotted`
+    /// - `double`
+    ///
+    /// Fills follow the same patterns as the widths, so each side can have a different fill.
+    ///
     /// Note: Borders exist outside the layout system, which means they will be drawn underneath child elements and may overlap with adjacent elements.
     /// Add appropriate padding or margin to prevent border overlap with other content.
     ///
@@ -85,6 +93,33 @@ def_attribute!(
     /// }
     /// ```
     ///
+    /// A dashed, blue border, for example for a drop zone.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             border: "2 inner dashed(8 4) blue",
+    ///             corner_radius: "8",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ///
+    /// A border only on the bottom side, with a different fill.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             border: "0 0 2 0 inner transparent transparent green transparent",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ///
     /// Borders can take any valid fill type, including gradients.
     ///
     /// ```rust, no_run
@@ -15,4 +15,6 @@ def_attribute!(
     highlight_color,
     /// Defines a highlight_mode attribute. For more information, see `use_editable`.
     highlight_mode,
+    /// Defines a preedit attribute. For more information, see `use_editable`.
+    preedit,
 );
@@ -5,7 +5,7 @@ def_attribute!(
     ///
     /// Syntax is `<0-360>deg`.
     ///
-    /// Note: Rotations don't affect neither layout or mouse events, they are merely a rendering effect.
+    /// Note: Rotations don't affect the layout, they are merely a rendering effect. Mouse events do take them into account.
     ///
     /// ### Example
     ///
@@ -52,7 +52,7 @@ def_attribute!(
     /// - `<f32>`: Same value for both scale x and y.
     /// - `<f32>, <f32>`: Specify the scale x and y separately.
     ///
-    /// Note: Scaling doesn't affect neither layout or mouse events, it is only a rendering effect.
+    /// Note: Scaling doesn't affect the layout, it is only a rendering effect. Mouse events do take it into account.
     ///
     /// ### Example
     ///
@@ -72,4 +72,102 @@ def_attribute!(
     /// ```
     scale,
 
+    /// Specify the translation for this element.
+    ///
+    /// Accepted syntax:
+    /// - `<f32>`: Translate only in the x axis.
+    /// - `<f32> <f32>`: Specify the translation x and y separately.
+    ///
+    /// Note: Translations don't affect the layout, they are only a rendering effect. Mouse events do take them into account.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             translate: "50 20",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    translate,
+
+    /// Specify the skew for this element.
+    ///
+    /// Accepted syntax:
+    /// - `<f32>deg`: Skew only in the x axis.
+    /// - `<f32>deg <f32>deg`: Specify the skew x and y separately.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             skew: "20deg",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    skew,
+
+    /// Specify a 2D transformation matrix for this element.
+    ///
+    /// Syntax is `matrix(<a>, <b>, <c>, <d>, <tx>, <ty>)`, same as in CSS.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             transform: "matrix(1, 0.2, 0, 1, 10, 0)",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    transform,
+
+    /// Specify the point around which the `rotate`, `scale`, `skew` and `transform` effects are applied.
+    ///
+    /// Accepted syntax:
+    /// - `center` (default)
+    /// - `<x> <y>`: Where each one is a keyword (`left`, `right`, `top`, `bottom`, `center`),
+    ///   a percentage of the element size like `25%` or a number of pixels like `10`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             rotate: "45deg",
+    ///             transform_origin: "top left",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    transform_origin,
+
 );
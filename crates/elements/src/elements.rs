@@ -36,7 +36,11 @@ def_element!(
         position_right,
         position_bottom,
         position_left,
+        grid_column,
+        grid_row,
+        layout_direction,
         layer,
+        layer_cache,
 
         // Children layout
         direction,
@@ -44,6 +48,9 @@ def_element!(
         main_align,
         cross_align,
         spacing,
+        wrap_spacing,
+        grid_columns,
+        grid_rows,
         overflow,
         offset_x,
         offset_y,
@@ -81,6 +88,10 @@ def_element!(
         rotate,
         opacity,
         scale,
+        translate,
+        skew,
+        transform,
+        transform_origin,
 
         // Reference
         canvas_reference,
@@ -185,6 +196,9 @@ def_element!(
         position_right,
         position_bottom,
         position_left,
+        grid_column,
+        grid_row,
+        layout_direction,
         layer,
 
         // Children layout
@@ -326,6 +340,9 @@ def_element!(
         position_right,
         position_bottom,
         position_left,
+        grid_column,
+        grid_row,
+        layout_direction,
         layer,
 
         // Children layout
@@ -362,6 +379,7 @@ def_element!(
         highlights,
         highlight_color,
         highlight_mode,
+        preedit,
         cursor_reference,
 
         // Accessibility
@@ -486,6 +504,9 @@ def_element!(
         position_right,
         position_bottom,
         position_left,
+        grid_column,
+        grid_row,
+        layout_direction,
         layer,
 
         // Children layout
@@ -494,6 +515,9 @@ def_element!(
         main_align,
         cross_align,
         spacing,
+        wrap_spacing,
+        grid_columns,
+        grid_rows,
         overflow,
         offset_x,
         offset_y,
@@ -508,10 +532,15 @@ def_element!(
         cover,
         cache_key,
         sampling,
+        decoding,
+        downsample,
+        playing,
+        repeat,
 
         // Reference
         reference,
         image_reference,
+        image_status,
 
         // Accessibility
         a11y_id,
@@ -618,6 +647,9 @@ def_element!(
         position_right,
         position_bottom,
         position_left,
+        grid_column,
+        grid_row,
+        layout_direction,
         layer,
 
         // Transform
@@ -1,6 +1,8 @@
 pub mod file;
+pub mod ime;
 pub mod keyboard;
 pub mod mouse;
+pub mod pinch;
 pub mod pointer;
 pub mod touch;
 pub mod wheel;
@@ -9,17 +11,21 @@ use std::any::Any;
 
 use dioxus_core::Event;
 pub use file::*;
+pub use ime::*;
 pub use keyboard::*;
 pub use mouse::*;
+pub use pinch::*;
 pub use pointer::*;
 pub use touch::*;
 pub use wheel::*;
 
 pub type KeyboardEvent = Event<KeyboardData>;
+pub type ImeEvent = Event<ImeData>;
 pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type PinchEvent = Event<PinchData>;
 
 /// A platform specific event.
 #[doc(hidden)]
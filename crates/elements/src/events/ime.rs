@@ -0,0 +1,55 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    ImeData;
+
+    /// The `ime` event fires when the Input Method Editor (IME) of the platform changes its state
+    /// while the element is focused, for instance when the user is composing CJK text.
+    ///
+    /// Committed texts are also emitted as `keydown` events, so elements that don't handle
+    /// compositions will still receive the final text.
+    ///
+    /// Event Data: [`ImeData`](crate::events::ImeData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             onime: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onime
+];
+
+/// Data of an IME event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImeData {
+    /// The IME was enabled.
+    Enabled,
+    /// A text is being composed.
+    ///
+    /// `cursor` is the byte range of the cursor inside `text`, a `None` cursor should be hidden.
+    /// An empty text means that the composition was cancelled.
+    Preedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+    /// The composed text was committed.
+    Commit(String),
+    /// The IME was disabled.
+    Disabled,
+}
+
+impl From<&ErasedEventData> for ImeData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<ImeData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,85 @@
+use torin::geometry::CursorPoint;
+
+use crate::{
+    events::{
+        touch::TouchPhase,
+        ErasedEventData,
+    },
+    impl_event,
+};
+
+impl_event! [
+    PinchData;
+
+    /// The `pinch` event fires when the user pinches a touchpad while hovering over the element.
+    ///
+    /// Event Data: [`PinchData`](crate::events::PinchData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onpinch: |e| println!("Pinching by {}", e.get_delta())
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onpinch
+];
+
+/// Data of a Pinch event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinchData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub delta: f64,
+    pub phase: TouchPhase,
+}
+
+impl PinchData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            delta,
+            phase,
+        }
+    }
+
+    /// Get the cursor coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the scale delta of this event, positive values mean zooming in.
+    pub fn get_delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Get the phase of the pinch gesture.
+    pub fn get_pinch_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&ErasedEventData> for PinchData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<PinchData>().cloned().unwrap()
+    }
+}
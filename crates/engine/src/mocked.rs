@@ -217,6 +217,57 @@ impl Matrix {
     pub fn rotate_deg_pivot(_degrees: f32, _pivot: impl Into<SkPoint>) -> Self {
         unimplemented!("This is mocked")
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_all(
+        _scale_x: f32,
+        _skew_x: f32,
+        _trans_x: f32,
+        _skew_y: f32,
+        _scale_y: f32,
+        _trans_y: f32,
+        _pers_0: f32,
+        _pers_1: f32,
+        _pers_2: f32,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_translate(&mut self, _delta: impl Into<SkPoint>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_scale(
+        &mut self,
+        _scale: (f32, f32),
+        _pivot: impl Into<Option<SkPoint>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<SkPoint>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_skew(&mut self, _skew: (f32, f32), _pivot: impl Into<Option<SkPoint>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_concat(&mut self, _other: &Matrix) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn invert(&self) -> Option<Matrix> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_point(&self, _point: impl Into<SkPoint>) -> SkPoint {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_rect(&self, _rect: impl AsRef<Rect>) -> (Rect, bool) {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -692,6 +743,14 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_path_effect(&mut self, _path_effect: impl Into<Option<PathEffect>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -700,6 +759,20 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+pub struct PathEffect;
+
+impl PathEffect {
+    pub fn dash(_intervals: &[f32], _phase: f32) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
@@ -1075,6 +1148,15 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_image(
+        &self,
+        image: impl AsRef<Image>,
+        left_top: impl Into<SkPoint>,
+        paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_image_rect(
         &self,
         image: impl AsRef<Image>,
@@ -1409,6 +1491,64 @@ impl Image {
     }
 }
 
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new_n32_premul(
+        _dimensions: impl Into<ISize>,
+        _color_space: impl Into<Option<SkColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub fn raster_from_data(
+    _info: &ImageInfo,
+    _pixels: impl Into<Data>,
+    _row_bytes: usize,
+) -> Option<Image> {
+    unimplemented!("This is mocked")
+}
+
+pub struct Codec;
+
+impl Codec {
+    pub fn from_data(_data: impl Into<Data>) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn get_frame_count(&mut self) -> usize {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn get_frame_info(&mut self, _index: usize) -> Option<FrameInfo> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn get_repetition_count(&mut self) -> Option<usize> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn get_image<'a>(
+        &mut self,
+        _info: impl Into<Option<ImageInfo>>,
+        _options: impl Into<Option<&'a CodecOptions>>,
+    ) -> Result<Image, ()> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Default)]
+pub struct CodecOptions {
+    pub frame_index: usize,
+    pub prior_frame: Option<usize>,
+}
+
+pub struct FrameInfo {
+    pub required_frame: i32,
+    pub duration: i32,
+}
+
 pub struct Data;
 
 impl Data {
@@ -1826,6 +1966,34 @@ impl Surface {
     pub fn new_surface_with_dimensions(&mut self, dim: impl Into<ISize>) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn peek_pixels(&mut self) -> Option<Pixmap> {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct Pixmap;
+
+impl Pixmap {
+    pub fn bytes(&self) -> Option<&[u8]> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn row_bytes(&self) -> usize {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn width(&self) -> i32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn color_type(&self) -> ColorType {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn get_color(&self, p: (i32, i32)) -> SkColor {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct ISize;
@@ -1842,6 +2010,7 @@ pub struct SkColorSpace;
 #[repr(i32)]
 pub enum ColorType {
     RGBA8888 = 4,
+    BGRA8888 = 6,
 }
 
 pub struct SurfaceProps;
@@ -1,5 +1,9 @@
 pub use skia_safe::{
     canvas::SaveLayerRec,
+    codec::{
+        FrameInfo,
+        Options as CodecOptions,
+    },
     font_style::{
         Slant,
         Weight,
@@ -65,6 +69,7 @@ pub use skia_safe::{
     BlurStyle,
     Canvas,
     ClipOp,
+    Codec,
     Color as SkColor,
     Color4f,
     ColorSpace,
@@ -85,9 +90,11 @@ pub use skia_safe::{
     Matrix,
     MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathDirection,
+    PathEffect,
     PathFillType,
     Pixmap,
     Point as SkPoint,
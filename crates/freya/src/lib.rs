@@ -125,10 +125,16 @@ pub mod prelude {
             CanvasRunnerContext,
             CustomAttributeValues,
         },
+        dom::{
+            ImageDecodeStatus,
+            ImagesCacheBudget,
+            ImagesCacheStats,
+        },
         platform::*,
         platform_state::*,
         types::AccessibilityId,
         window_config::{
+            GraphicsBackend,
             OnCloseResponse,
             WindowConfig,
         },
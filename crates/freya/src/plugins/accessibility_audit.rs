@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use freya_core::{
+    accessibility::{
+        audit_accessibility,
+        AccessibilityFinding,
+    },
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+};
+use freya_winit::reexports::winit::window::WindowId;
+
+/// Log the accessibility issues found in the app every time its accessibility tree changes, see [audit_accessibility].
+///
+/// Issues are only logged once while they persist, and nothing is audited in release builds.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # fn app() -> Element { None }
+/// fn main() {
+///     launch_cfg(
+///         LaunchConfig::new()
+///             .with_window(WindowConfig::new(app))
+///             .with_plugin(AccessibilityAuditPlugin::default()),
+///     )
+/// }
+/// ```
+#[derive(Default)]
+pub struct AccessibilityAuditPlugin {
+    findings: HashMap<WindowId, Vec<AccessibilityFinding>>,
+}
+
+impl FreyaPlugin for AccessibilityAuditPlugin {
+    fn on_event(&mut self, event: &PluginEvent, _handle: PluginHandle) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        match event {
+            PluginEvent::FinishedUpdatingAccessibility {
+                window,
+                fdom,
+                background,
+                ..
+            } => {
+                let findings = audit_accessibility(fdom.rdom(), *background);
+                let previous_findings = self.findings.entry(window.id()).or_default();
+
+                for finding in &findings {
+                    if !previous_findings.contains(finding) {
+                        tracing::warn!("Accessibility: {finding}");
+                    }
+                }
+
+                *previous_findings = findings;
+            }
+            PluginEvent::WindowClosed { window, .. } => {
+                self.findings.remove(&window.id());
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
+};
+
+use freya_core::{
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+    profiling::{
+        chrome_trace,
+        FrameProfile,
+        ProfileSpan,
+    },
+};
+use freya_winit::reexports::winit::window::WindowId;
+
+struct ProfilerState {
+    started: Instant,
+    max_frames: usize,
+    frames: VecDeque<FrameProfile>,
+}
+
+/// Frames recorded by a [FrameProfilerPlugin]. Can be cloned and kept around to export them at any time.
+#[derive(Clone)]
+pub struct FrameProfiler {
+    state: Arc<Mutex<ProfilerState>>,
+}
+
+impl FrameProfiler {
+    fn new(max_frames: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ProfilerState {
+                started: Instant::now(),
+                max_frames,
+                frames: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// The recorded frames, from the oldest to the latest.
+    pub fn frames(&self) -> Vec<FrameProfile> {
+        self.state.lock().unwrap().frames.iter().cloned().collect()
+    }
+
+    /// Discard the recorded frames.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().frames.clear();
+    }
+
+    /// Serialize the recorded frames as a Chrome trace, see [chrome_trace].
+    pub fn to_chrome_trace(&self) -> String {
+        chrome_trace(&self.state.lock().unwrap().frames)
+    }
+
+    /// Save the recorded frames as a Chrome trace in the given file.
+    pub fn save_chrome_trace(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_chrome_trace())
+    }
+
+    fn push_frame(&self, frame: FrameProfile) {
+        let mut state = self.state.lock().unwrap();
+        state.frames.push_back(frame);
+        while state.frames.len() > state.max_frames {
+            state.frames.pop_front();
+        }
+    }
+}
+
+#[derive(Default)]
+struct WindowProfile {
+    frame: FrameProfile,
+    started_steps: HashMap<&'static str, Instant>,
+}
+
+/// Record the steps, stats and component renders of every frame, which can be exported as a Chrome trace.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # fn app() -> Element { None }
+/// fn main() {
+///     launch_cfg(
+///         LaunchConfig::new()
+///             .with_window(WindowConfig::new(app))
+///             .with_plugin(FrameProfilerPlugin::default().with_trace_file("trace.json")),
+///     )
+/// }
+/// ```
+pub struct FrameProfilerPlugin {
+    profiler: FrameProfiler,
+    trace_file: Option<PathBuf>,
+    windows: HashMap<WindowId, WindowProfile>,
+}
+
+impl Default for FrameProfilerPlugin {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl FrameProfilerPlugin {
+    /// Create a profiler that keeps up to `max_frames` frames.
+    pub fn new(max_frames: usize) -> Self {
+        Self {
+            profiler: FrameProfiler::new(max_frames),
+            trace_file: None,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Save the Chrome trace in the given file once a window is closed.
+    pub fn with_trace_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.trace_file = Some(path.into());
+        self
+    }
+
+    /// Get a handle to the recorded frames.
+    pub fn profiler(&self) -> FrameProfiler {
+        self.profiler.clone()
+    }
+
+    fn start_step(&mut self, window_id: WindowId, step: &'static str) {
+        self.windows
+            .entry(window_id)
+            .or_default()
+            .started_steps
+            .insert(step, Instant::now());
+    }
+
+    fn finish_step(&mut self, window_id: WindowId, step: &'static str) {
+        let started = self.profiler.state.lock().unwrap().started;
+        let window = self.windows.entry(window_id).or_default();
+        if let Some(step_started) = window.started_steps.remove(step) {
+            window.frame.spans.push(ProfileSpan {
+                name: step,
+                start: step_started.duration_since(started),
+                duration: step_started.elapsed(),
+            });
+        }
+    }
+}
+
+impl FreyaPlugin for FrameProfilerPlugin {
+    fn wants_frame_stats(&self) -> bool {
+        true
+    }
+
+    fn on_event(&mut self, event: &PluginEvent, _handle: PluginHandle) {
+        match event {
+            PluginEvent::WindowClosed { window, .. } => {
+                self.windows.remove(&window.id());
+                if let Some(trace_file) = &self.trace_file {
+                    if let Err(err) = self.profiler.save_chrome_trace(trace_file) {
+                        tracing::error!("Failed to save the Chrome trace in {trace_file:?}: {err}");
+                    }
+                }
+            }
+            PluginEvent::StartedUpdatingDOM { window, .. } => {
+                self.start_step(window.id(), "DOM Updates")
+            }
+            PluginEvent::FinishedUpdatingDOM { window, .. } => {
+                self.finish_step(window.id(), "DOM Updates")
+            }
+            PluginEvent::StartedMeasuringEvents { window, .. } => {
+                self.start_step(window.id(), "Events")
+            }
+            PluginEvent::FinishedMeasuringEvents { window, .. } => {
+                self.finish_step(window.id(), "Events")
+            }
+            PluginEvent::StartedMeasuringLayout { window, .. } => {
+                self.start_step(window.id(), "Layout")
+            }
+            PluginEvent::FinishedMeasuringLayout { window, .. } => {
+                self.finish_step(window.id(), "Layout")
+            }
+            PluginEvent::BeforeRender { window, .. } => self.start_step(window.id(), "Render"),
+            PluginEvent::AfterRender { window, fdom, .. } => {
+                self.finish_step(window.id(), "Render");
+
+                let window_profile = self.windows.entry(window.id()).or_default();
+                let mut frame = std::mem::take(&mut window_profile.frame);
+                window_profile.frame.index = frame.index + 1;
+
+                frame.window = window.id().into();
+                frame.stats = std::mem::take(&mut *fdom.frame_stats());
+                frame.images_cache = fdom.images_cache().stats();
+
+                self.profiler.push_frame(frame);
+            }
+            _ => {}
+        }
+    }
+}
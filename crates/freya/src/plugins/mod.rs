@@ -1,3 +1,7 @@
+mod accessibility_audit;
+mod frame_profiler;
 mod performance_overlay;
 
+pub use accessibility_audit::*;
+pub use frame_profiler::*;
 pub use performance_overlay::*;
@@ -102,6 +102,7 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                 let rdom = fdom.rdom();
                 let layout = fdom.layout();
                 let animation_clock = fdom.animation_clock();
+                let layer_cache_stats = fdom.layer_cache().stats();
 
                 let now = Instant::now();
 
@@ -182,6 +183,16 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                     14.0,
                 );
 
+                // Layer cache usage
+                add_text(
+                    &mut paragraph_builder,
+                    format!(
+                        "Layer cache: {} hits / {} misses \n",
+                        layer_cache_stats.hits, layer_cache_stats.misses
+                    ),
+                    14.0,
+                );
+
                 let mut paragraph = paragraph_builder.build();
                 paragraph.layout(f32::MAX);
                 paragraph.paint(canvas, (5.0, 0.0));
@@ -16,6 +16,7 @@ mod use_canvas;
 mod use_editable;
 mod use_focus;
 mod use_id;
+mod use_image_status;
 mod use_init_native_platform;
 mod use_node;
 mod use_platform;
@@ -41,6 +42,7 @@ pub use use_canvas::*;
 pub use use_editable::*;
 pub use use_focus::*;
 pub use use_id::*;
+pub use use_image_status::*;
 pub use use_init_native_platform::*;
 pub use use_node::*;
 pub use use_platform::*;
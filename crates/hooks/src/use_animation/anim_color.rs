@@ -7,10 +7,12 @@ use freya_core::{
 
 use super::{
     apply_value,
+    interpolate_value,
     AnimDirection,
     AnimatedValue,
     Ease,
     Function,
+    Interpolable,
 };
 
 /// Animate a color.
@@ -156,3 +158,23 @@ impl AnimatedValue for AnimColor {
         self.advance(self.time.as_millis(), direction);
     }
 }
+
+impl Interpolable for Color {
+    fn interpolate(
+        origin: &Self,
+        destination: &Self,
+        progress: f32,
+        ease: Ease,
+        function: Function,
+    ) -> Self {
+        let channel = |origin: u8, destination: u8| {
+            interpolate_value(origin as f32, destination as f32, progress, ease, function) as u8
+        };
+        Color::from_argb(
+            channel(origin.a(), destination.a()),
+            channel(origin.r(), destination.r()),
+            channel(origin.g(), destination.g()),
+            channel(origin.b(), destination.b()),
+        )
+    }
+}
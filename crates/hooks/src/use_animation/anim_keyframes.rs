@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use freya_core::values::Color;
+
+use super::{
+    AnimDirection,
+    AnimatedValue,
+    Ease,
+    Function,
+    Interpolable,
+    Transform,
+};
+
+/// A value to reach at a certain point of an [AnimKeyframes] animation.
+#[derive(Clone, PartialEq)]
+pub struct Keyframe<T: Interpolable> {
+    /// Fraction of the total duration, from `0.0` to `1.0`.
+    pub offset: f32,
+    pub value: T,
+    /// Easing type used to reach this keyframe from the previous one.
+    pub ease: Ease,
+    /// Easing function used to reach this keyframe from the previous one.
+    pub function: Function,
+}
+
+/// Animate a value through multiple keyframes, each one with its own easing.
+///
+/// Works with any [Interpolable] value, such as numbers, [Color]s or [Transform]s.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// let animation: AnimKeyframes<f32> = AnimKeyframes::new(0.)
+///     .time(500)
+///     .keyframe(0.4, 80.)
+///     .keyframe_with(
+///         1.0,
+///         100.,
+///         Ease::Out,
+///         Function::CubicBezier(0.34, 1.56, 0.64, 1.),
+///     );
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct AnimKeyframes<T: Interpolable> {
+    keyframes: Vec<Keyframe<T>>,
+    time: Duration,
+
+    value: T,
+}
+
+impl<T: Interpolable> AnimKeyframes<T> {
+    /// Create a new [AnimKeyframes] starting with the given value.
+    pub fn new(initial: T) -> Self {
+        Self {
+            keyframes: vec![Keyframe {
+                offset: 0.,
+                value: initial.clone(),
+                ease: Ease::default(),
+                function: Function::default(),
+            }],
+            time: Duration::default(),
+
+            value: initial,
+        }
+    }
+
+    /// Add a keyframe at the given `offset` (fraction of the total duration) using the default easing.
+    pub fn keyframe(self, offset: f32, value: T) -> Self {
+        self.keyframe_with(offset, value, Ease::default(), Function::default())
+    }
+
+    /// Add a keyframe at the given `offset` (fraction of the total duration) reached with the given easing.
+    pub fn keyframe_with(mut self, offset: f32, value: T, ease: Ease, function: Function) -> Self {
+        let offset = offset.clamp(0., 1.);
+        let keyframe = Keyframe {
+            offset,
+            value,
+            ease,
+            function,
+        };
+
+        if offset == 0. {
+            self.value = keyframe.value.clone();
+            self.keyframes[0] = keyframe;
+        } else {
+            let index = self
+                .keyframes
+                .partition_point(|keyframe| keyframe.offset <= offset);
+            self.keyframes.insert(index, keyframe);
+        }
+
+        self
+    }
+
+    /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set the animation duration using milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Get the keyframes of this animation, sorted by their offset.
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Get the current value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    fn first(&self) -> &T {
+        &self.keyframes[0].value
+    }
+
+    fn last(&self) -> &T {
+        &self.keyframes[self.keyframes.len() - 1].value
+    }
+
+    /// Get the value at the given fraction of the total duration.
+    fn value_at(&self, position: f32) -> T {
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.offset < position);
+
+        // Hold the last value once all the keyframes have been reached
+        let Some(to) = self.keyframes.get(next) else {
+            return self.last().clone();
+        };
+        let Some(from) = next.checked_sub(1).map(|prev| &self.keyframes[prev]) else {
+            return to.value.clone();
+        };
+
+        let progress = (position - from.offset) / (to.offset - from.offset);
+        T::interpolate(&from.value, &to.value, progress, to.ease, to.function)
+    }
+}
+
+impl AnimKeyframes<f32> {
+    /// Read the value of the [AnimKeyframes] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+}
+
+impl AnimKeyframes<Color> {
+    /// Read the value of the [AnimKeyframes] as a String.
+    pub fn read(&self) -> String {
+        format!(
+            "rgb({}, {}, {}, {})",
+            self.value.r(),
+            self.value.g(),
+            self.value.b(),
+            self.value.a()
+        )
+    }
+}
+
+impl AnimKeyframes<Transform> {
+    /// Read the value of the [AnimKeyframes] as a [Transform].
+    pub fn read(&self) -> Transform {
+        self.value
+    }
+}
+
+impl<T: Interpolable> AnimatedValue for AnimKeyframes<T> {
+    fn prepare(&mut self, direction: AnimDirection) {
+        self.value = match direction {
+            AnimDirection::Forward => self.first().clone(),
+            AnimDirection::Reverse => self.last().clone(),
+        };
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        index >= self.time.as_millis()
+            && match direction {
+                AnimDirection::Forward => &self.value == self.last(),
+                AnimDirection::Reverse => &self.value == self.first(),
+            }
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let progress = if self.time.is_zero() {
+            1.0
+        } else {
+            (index as f32 / self.time.as_millis() as f32).min(1.0)
+        };
+
+        // Reversed animations run the timeline backwards
+        let position = match direction {
+            AnimDirection::Forward => progress,
+            AnimDirection::Reverse => 1.0 - progress,
+        };
+
+        self.value = self.value_at(position);
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.advance(self.time.as_millis(), direction);
+    }
+}
@@ -2,10 +2,12 @@ use std::time::Duration;
 
 use super::{
     apply_value,
+    interpolate_value,
     AnimDirection,
     AnimatedValue,
     Ease,
     Function,
+    Interpolable,
 };
 
 /// Animate a numeric value.
@@ -116,3 +118,15 @@ impl AnimatedValue for AnimNum {
         self.advance(self.time.as_millis(), direction);
     }
 }
+
+impl Interpolable for f32 {
+    fn interpolate(
+        origin: &Self,
+        destination: &Self,
+        progress: f32,
+        ease: Ease,
+        function: Function,
+    ) -> Self {
+        interpolate_value(*origin, *destination, progress, ease, function)
+    }
+}
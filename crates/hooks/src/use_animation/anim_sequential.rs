@@ -65,4 +65,10 @@ impl<Animated: AnimatedValue, const N: usize> AnimatedValue for AnimSequential<A
             value.finish(direction);
         }
     }
+
+    fn continue_from(&mut self, previous: &Self) {
+        for (value, previous) in self.values.iter_mut().zip(&previous.values) {
+            value.continue_from(previous);
+        }
+    }
 }
@@ -0,0 +1,180 @@
+use super::{
+    AnimDirection,
+    AnimatedValue,
+};
+
+/// Animate a numeric value with spring physics.
+///
+/// Unlike [crate::AnimNum], a spring has no fixed duration. It finishes once it has settled in its target,
+/// and if it gets retargeted while running it continues from its current value and velocity.
+#[derive(Clone, PartialEq)]
+pub struct AnimSpring {
+    origin: f32,
+    destination: f32,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    initial_velocity: f32,
+    rest_delta: f32,
+    rest_speed: f32,
+
+    target: f32,
+    value: f32,
+    velocity: f32,
+    last_index: u128,
+}
+
+impl AnimSpring {
+    pub fn new(origin: f32, destination: f32) -> Self {
+        Self {
+            origin,
+            destination,
+            stiffness: 170.,
+            damping: 26.,
+            mass: 1.,
+            initial_velocity: 0.,
+            rest_delta: 0.01,
+            rest_speed: 0.1,
+
+            target: origin,
+            value: origin,
+            velocity: 0.,
+            last_index: 0,
+        }
+    }
+
+    /// Reverses the `origin` and the `destination` of the [AnimSpring].
+    pub fn into_reversed(self) -> Self {
+        Self {
+            origin: self.destination,
+            destination: self.origin,
+            ..self
+        }
+    }
+
+    /// Set how strongly the spring pulls towards its target. Defaults to `170`.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Set how much the spring motion gets slowed down. Defaults to `26`.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Set the mass of the object attached to the spring. Defaults to `1`.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Set the velocity (in units per second) the spring starts with, e.g the speed of a drag gesture.
+    pub fn initial_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = initial_velocity;
+        self.velocity = initial_velocity;
+        self
+    }
+
+    /// Set the maximum distance to the target for the spring to be considered settled. Defaults to `0.01`.
+    pub fn rest_delta(mut self, rest_delta: f32) -> Self {
+        self.rest_delta = rest_delta;
+        self
+    }
+
+    /// Set the maximum speed (in units per second) for the spring to be considered settled. Defaults to `0.1`.
+    pub fn rest_speed(mut self, rest_speed: f32) -> Self {
+        self.rest_speed = rest_speed;
+        self
+    }
+
+    /// Read the value of the [AnimSpring] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+
+    /// Read the current velocity of the [AnimSpring], in units per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    fn target_of(&self, direction: AnimDirection) -> f32 {
+        match direction {
+            AnimDirection::Forward => self.destination,
+            AnimDirection::Reverse => self.origin,
+        }
+    }
+
+    fn is_settled(&self, target: f32) -> bool {
+        (self.value - target).abs() <= self.rest_delta && self.velocity.abs() <= self.rest_speed
+    }
+}
+
+impl From<&AnimSpring> for f32 {
+    fn from(value: &AnimSpring) -> Self {
+        value.read()
+    }
+}
+
+impl AnimatedValue for AnimSpring {
+    fn prepare(&mut self, direction: AnimDirection) {
+        let target = self.target_of(direction);
+
+        // Start over when there is nothing to animate, otherwise keep moving from the current state
+        if self.is_settled(target) {
+            self.value = match direction {
+                AnimDirection::Forward => self.origin,
+                AnimDirection::Reverse => self.destination,
+            };
+            self.velocity = self.initial_velocity;
+        }
+
+        self.target = target;
+        self.last_index = 0;
+    }
+
+    fn is_finished(&self, _index: u128, direction: AnimDirection) -> bool {
+        let target = self.target_of(direction);
+        self.value == target && self.is_settled(target)
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        self.target = self.target_of(direction);
+
+        // Integrate in steps of one millisecond so stiff springs stay stable
+        let steps = index.saturating_sub(self.last_index);
+        self.last_index = index;
+
+        for _ in 0..steps {
+            let spring_force = -self.stiffness * (self.value - self.target);
+            let damping_force = -self.damping * self.velocity;
+            let acceleration = (spring_force + damping_force) / self.mass;
+
+            self.velocity += acceleration * 0.001;
+            self.value += self.velocity * 0.001;
+
+            if self.is_settled(self.target) {
+                break;
+            }
+        }
+
+        if self.is_settled(self.target) {
+            self.value = self.target;
+            self.velocity = 0.;
+        }
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.target = self.target_of(direction);
+        self.value = self.target;
+        self.velocity = 0.;
+    }
+
+    fn continue_from(&mut self, previous: &Self) {
+        self.value = previous.value;
+        self.velocity = previous.velocity;
+        self.target = previous.target;
+        self.last_index = previous.last_index;
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fmt,
+    time::Duration,
+};
+
+use super::{
+    interpolate_value,
+    AnimDirection,
+    AnimatedValue,
+    Ease,
+    Function,
+    Interpolable,
+};
+
+/// 2D transformation of an element, combined in the same order as CSS `translate() rotate() scale() skew()`.
+///
+/// Its [fmt::Display] produces a `matrix(...)` that can be used in the `transform` attribute.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub translate_x: f32,
+    pub translate_y: f32,
+    /// Rotation in degrees.
+    pub rotate: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    /// Skew in the x axis, in degrees.
+    pub skew_x: f32,
+    /// Skew in the y axis, in degrees.
+    pub skew_y: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translate_x: 0.,
+            translate_y: 0.,
+            rotate: 0.,
+            scale_x: 1.,
+            scale_y: 1.,
+            skew_x: 0.,
+            skew_y: 0.,
+        }
+    }
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn translate(mut self, x: f32, y: f32) -> Self {
+        self.translate_x = x;
+        self.translate_y = y;
+        self
+    }
+
+    pub fn rotate(mut self, degrees: f32) -> Self {
+        self.rotate = degrees;
+        self
+    }
+
+    pub fn scale(mut self, x: f32, y: f32) -> Self {
+        self.scale_x = x;
+        self.scale_y = y;
+        self
+    }
+
+    pub fn skew(mut self, x_degrees: f32, y_degrees: f32) -> Self {
+        self.skew_x = x_degrees;
+        self.skew_y = y_degrees;
+        self
+    }
+
+    /// Get the `[a, b, c, d, e, f]` values of this transformation matrix, as in CSS `matrix()`.
+    pub fn to_matrix(&self) -> [f32; 6] {
+        let (sin, cos) = self.rotate.to_radians().sin_cos();
+        let (skew_x, skew_y) = (
+            self.skew_x.to_radians().tan(),
+            self.skew_y.to_radians().tan(),
+        );
+
+        [
+            cos * self.scale_x - sin * self.scale_y * skew_y,
+            sin * self.scale_x + cos * self.scale_y * skew_y,
+            cos * self.scale_x * skew_x - sin * self.scale_y,
+            sin * self.scale_x * skew_x + cos * self.scale_y,
+            self.translate_x,
+            self.translate_y,
+        ]
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, f_] = self.to_matrix();
+        write!(f, "matrix({a}, {b}, {c}, {d}, {e}, {f_})")
+    }
+}
+
+impl Interpolable for Transform {
+    fn interpolate(
+        origin: &Self,
+        destination: &Self,
+        progress: f32,
+        ease: Ease,
+        function: Function,
+    ) -> Self {
+        let value = |origin: f32, destination: f32| {
+            interpolate_value(origin, destination, progress, ease, function)
+        };
+        Self {
+            translate_x: value(origin.translate_x, destination.translate_x),
+            translate_y: value(origin.translate_y, destination.translate_y),
+            rotate: value(origin.rotate, destination.rotate),
+            scale_x: value(origin.scale_x, destination.scale_x),
+            scale_y: value(origin.scale_y, destination.scale_y),
+            skew_x: value(origin.skew_x, destination.skew_x),
+            skew_y: value(origin.skew_y, destination.skew_y),
+        }
+    }
+}
+
+/// Animate a [Transform].
+#[derive(Clone, PartialEq)]
+pub struct AnimTransform {
+    origin: Transform,
+    destination: Transform,
+    time: Duration,
+    ease: Ease,
+    function: Function,
+
+    value: Transform,
+}
+
+impl AnimTransform {
+    pub fn new(origin: Transform, destination: Transform) -> Self {
+        Self {
+            origin,
+            destination,
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+
+            value: origin,
+        }
+    }
+
+    /// Reverses the `origin` and the `destination` of the [AnimTransform].
+    pub fn into_reversed(self) -> Self {
+        Self {
+            origin: self.destination,
+            destination: self.origin,
+            ..self
+        }
+    }
+
+    /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set the animation duration using milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Read the value of the [AnimTransform].
+    pub fn read(&self) -> Transform {
+        self.value
+    }
+}
+
+impl AnimatedValue for AnimTransform {
+    fn prepare(&mut self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.value = self.origin,
+            AnimDirection::Reverse => {
+                self.value = self.destination;
+            }
+        }
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        match direction {
+            AnimDirection::Forward => {
+                index >= self.time.as_millis() && self.value == self.destination
+            }
+            AnimDirection::Reverse => index >= self.time.as_millis() && self.value == self.origin,
+        }
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let (origin, destination) = match direction {
+            AnimDirection::Forward => (self.origin, self.destination),
+            AnimDirection::Reverse => (self.destination, self.origin),
+        };
+        let progress = if self.time.is_zero() {
+            1.0
+        } else {
+            index as f32 / self.time.as_millis() as f32
+        };
+        self.value =
+            Transform::interpolate(&origin, &destination, progress, self.ease, self.function);
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.advance(self.time.as_millis(), direction);
+    }
+}
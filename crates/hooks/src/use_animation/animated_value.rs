@@ -15,6 +15,23 @@ pub trait AnimatedValue: Clone + 'static {
     fn advance(&mut self, index: u128, direction: AnimDirection);
 
     fn finish(&mut self, direction: AnimDirection);
+
+    /// Called when this value replaces a `previous` one, e.g. because the dependencies changed.
+    ///
+    /// Values with inner state such as [crate::AnimSpring] can continue from where the previous one was.
+    fn continue_from(&mut self, _previous: &Self) {}
+}
+
+/// Values that can be interpolated, so they can be used in animations such as [crate::AnimKeyframes].
+pub trait Interpolable: Clone + PartialEq + 'static {
+    /// Get the value between `origin` and `destination` at the given `progress`, from `0.0` to `1.0`.
+    fn interpolate(
+        origin: &Self,
+        destination: &Self,
+        progress: f32,
+        ease: Ease,
+        function: Function,
+    ) -> Self;
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
@@ -45,6 +62,32 @@ pub fn apply_value(
         return destination;
     }
 
+    ease_value(t, b, c, d, ease, function)
+}
+
+/// Get the value between `origin` and `destination` at the given `progress`, from `0.0` to `1.0`.
+pub fn interpolate_value(
+    origin: f32,
+    destination: f32,
+    progress: f32,
+    ease: Ease,
+    function: Function,
+) -> f32 {
+    if progress >= 1.0 {
+        return destination;
+    }
+
+    ease_value(
+        progress.max(0.0),
+        origin,
+        destination - origin,
+        1.0,
+        ease,
+        function,
+    )
+}
+
+fn ease_value(t: f32, b: f32, c: f32, d: f32, ease: Ease, function: Function) -> f32 {
     match function {
         Function::Back => match ease {
             Ease::In => Back::ease_in(t, b, c, d),
@@ -96,10 +139,61 @@ pub fn apply_value(
             Ease::InOut => Sine::ease_in_out(t, b, c, d),
             Ease::Out => Sine::ease_out(t, b, c, d),
         },
+        // Bezier curves define their own easing
+        Function::CubicBezier(x1, y1, x2, y2) => b + c * cubic_bezier(x1, y1, x2, y2, t / d),
     }
 }
 
-#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+/// Solve the `y` of a CSS-like cubic bezier curve for the given `x`.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+
+    // Coefficients of the polynomial form of the curve
+    let cx = 3.0 * x1;
+    let bx = 3.0 * (x2 - x1) - cx;
+    let ax = 1.0 - cx - bx;
+    let cy = 3.0 * y1;
+    let by = 3.0 * (y2 - y1) - cy;
+    let ay = 1.0 - cy - by;
+
+    let sample_x = |t: f32| ((ax * t + bx) * t + cx) * t;
+    let sample_y = |t: f32| ((ay * t + by) * t + cy) * t;
+    let sample_x_derivative = |t: f32| (3.0 * ax * t + 2.0 * bx) * t + cx;
+
+    // Newton's method converges fast for most curves
+    let mut t = x;
+    for _ in 0..8 {
+        let error = sample_x(t) - x;
+        if error.abs() < 1e-6 {
+            return sample_y(t);
+        }
+        let derivative = sample_x_derivative(t);
+        if derivative.abs() < 1e-6 {
+            break;
+        }
+        t -= error / derivative;
+    }
+
+    // Fallback to bisection for the flat parts of the curve
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x.clamp(0.0, 1.0);
+    for _ in 0..32 {
+        let value = sample_x(t);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+
+    sample_y(t)
+}
+
+#[derive(Default, Clone, Copy, Debug)]
 pub enum Function {
     Back,
     Bounce,
@@ -112,8 +206,25 @@ pub enum Function {
     Quad,
     Quart,
     Sine,
+    /// CSS-like `cubic-bezier(x1, y1, x2, y2)` curve. The [Ease] is ignored for these.
+    CubicBezier(f32, f32, f32, f32),
 }
 
+// The curve points are compared by their bits so `Function` can still be `Eq`
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::CubicBezier(x1, y1, x2, y2), Self::CubicBezier(x3, y3, x4, y4)) => {
+                [x1, y1, x2, y2].map(|value| value.to_bits())
+                    == [x3, y3, x4, y4].map(|value| value.to_bits())
+            }
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Function {}
+
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
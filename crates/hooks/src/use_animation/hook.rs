@@ -290,6 +290,11 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 ///
 /// For animations where you want to animate a value after one another you may use [crate::AnimSequential].
 ///
+/// For physics-based animations you may use [crate::AnimSpring], which keeps its velocity when retargeted while running.
+///
+/// For animations through multiple values, each one with its own easing, you may use [crate::AnimKeyframes].
+/// It works with numbers, colors and [crate::Transform]s, which can also be animated on their own with [crate::AnimTransform].
+///
 /// # Example
 ///
 /// Here is an example that animates a value from `0.0` to `100.0` in `50` milliseconds.
@@ -375,7 +380,10 @@ pub fn use_animation<Animated: AnimatedValue>(
 
     use_memo(move || {
         let mut anim_conf = AnimConfiguration::default();
-        let value = run(&mut anim_conf);
+        let mut value = run(&mut anim_conf);
+        if let Some(previous) = &*animated_value.peek() {
+            value.continue_from(previous);
+        }
         conf.replace(anim_conf);
         animated_value.replace(Some(value));
     });
@@ -435,7 +443,10 @@ where
 
     use_memo(use_reactive(deps, move |deps| {
         let mut anim_conf = AnimConfiguration::default();
-        let value = run(&mut anim_conf, deps);
+        let mut value = run(&mut anim_conf, deps);
+        if let Some(previous) = &*animated_value.peek() {
+            value.continue_from(previous);
+        }
         conf.replace(anim_conf);
         animated_value.replace(Some(value));
     }));
@@ -482,7 +493,7 @@ pub fn use_animation_clock() -> CopyValue<AnimationClock> {
 }
 
 macro_rules! impl_tuple_call {
-    ($(($($type:ident),*)),*) => {
+    ($(($($type:ident: $index:tt),*)),*) => {
         $(
             impl<$($type,)*> AnimatedValue for ($($type,)*)
             where
@@ -522,28 +533,35 @@ macro_rules! impl_tuple_call {
                         $type.finish(direction);
                     )*
                 }
+
+                fn continue_from(&mut self, previous: &Self) {
+                    // Values are paired by their position in the tuples
+                    $(
+                        self.$index.continue_from(&previous.$index);
+                    )*
+                }
             }
         )*
     };
 }
 
 impl_tuple_call!(
-    (T1),
-    (T1, T2),
-    (T1, T2, T3),
-    (T1, T2, T3, T4),
-    (T1, T2, T3, T4, T5),
-    (T1, T2, T3, T4, T5, T6),
-    (T1, T2, T3, T4, T5, T6, T7),
-    (T1, T2, T3, T4, T5, T6, T7, T8),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17),
-    (T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18)
+    (T1: 0),
+    (T1: 0, T2: 1),
+    (T1: 0, T2: 1, T3: 2),
+    (T1: 0, T2: 1, T3: 2, T4: 3),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12, T14: 13),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12, T14: 13, T15: 14),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12, T14: 13, T15: 14, T16: 15),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12, T14: 13, T15: 14, T16: 15, T17: 16),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11, T13: 12, T14: 13, T15: 14, T16: 15, T17: 16, T18: 17)
 );
@@ -1,11 +1,17 @@
 mod anim_color;
+mod anim_keyframes;
 mod anim_num;
 mod anim_sequential;
+mod anim_spring;
+mod anim_transform;
 mod animated_value;
 mod hook;
 
 pub use anim_color::*;
+pub use anim_keyframes::*;
 pub use anim_num::*;
 pub use anim_sequential::*;
+pub use anim_spring::*;
+pub use anim_transform::*;
 pub use animated_value::*;
 pub use hook::*;
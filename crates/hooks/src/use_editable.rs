@@ -31,6 +31,7 @@ use freya_core::{
 use freya_elements::{
     events::{
         Code,
+        ImeData,
         KeyboardData,
         MouseData,
     },
@@ -57,6 +58,30 @@ pub enum EditableEvent {
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
     KeyUp(Rc<KeyboardData>),
+    Ime(Rc<ImeData>),
+}
+
+/// Text being composed with an Input Method Editor, not yet part of the editor content.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ImePreedit {
+    /// Text being composed.
+    pub text: String,
+    /// Cursor inside the composed text, in UTF-16 code units.
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl ImePreedit {
+    /// Create a [`ImePreedit`] from a text and a cursor range in bytes, as given by the IME.
+    pub fn new(text: String, cursor: Option<(usize, usize)>) -> Self {
+        let to_utf16 = |idx: usize| text.get(..idx).map(|t| t.encode_utf16().count());
+        let cursor = cursor.and_then(|(from, to)| Some((to_utf16(from)?, to_utf16(to)?)));
+        Self { text, cursor }
+    }
+
+    /// Length of the composed text in UTF-16 code units.
+    pub fn utf16_len(&self) -> usize {
+        self.text.encode_utf16().count()
+    }
 }
 
 /// How the editable content must behave.
@@ -133,7 +158,9 @@ pub struct UseEditable {
     pub(crate) editor: Signal<RopeEditor>,
     pub(crate) cursor_reference: Signal<CursorReference>,
     pub(crate) dragging: Signal<TextDragging>,
+    pub(crate) preedit: Signal<Option<ImePreedit>>,
     pub(crate) platform: UsePlatform,
+    pub(crate) mode: EditableMode,
     pub(crate) allow_tabs: bool,
     pub(crate) allow_changes: bool,
     pub(crate) allow_clipboard: bool,
@@ -157,6 +184,7 @@ impl UseEditable {
             EditorHistory::new(Duration::from_secs(1)),
         ));
         let dragging = Signal::new(TextDragging::None);
+        let preedit = Signal::new(None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
             text_id,
@@ -209,6 +237,18 @@ impl UseEditable {
                             *text_editor.cursor_mut() = maybe_new_cursor;
                         }
                     }
+                    // Place the IME candidates window next to the cursor
+                    #[cfg(feature = "winit")]
+                    CursorLayoutResponse::CursorArea { area, .. } => {
+                        platform.with_window(move |window| {
+                            window.set_ime_cursor_area(
+                                winit::dpi::PhysicalPosition::new(area.min_x(), area.min_y()),
+                                winit::dpi::PhysicalSize::new(area.width(), area.height()),
+                            );
+                        });
+                    }
+                    #[cfg(not(feature = "winit"))]
+                    CursorLayoutResponse::CursorArea { .. } => {}
                 }
             }
         });
@@ -217,7 +257,9 @@ impl UseEditable {
             editor,
             cursor_reference: Signal::new(cursor_reference.clone()),
             dragging,
+            preedit,
             platform,
+            mode,
             allow_tabs: config.allow_tabs,
             allow_changes: config.allow_changes,
             allow_clipboard: config.allow_clipboard,
@@ -252,6 +294,64 @@ impl UseEditable {
         ))
     }
 
+    /// Text currently being composed with an Input Method Editor.
+    pub fn preedit(&self) -> &Signal<Option<ImePreedit>> {
+        &self.preedit
+    }
+
+    /// Position of the cursor relative to the given editor, in UTF-16 code units.
+    fn editor_cursor_pos(&self, editor_id: usize) -> Option<usize> {
+        let editor = self.editor.read();
+        match self.mode {
+            EditableMode::MultipleLinesSingleEditor => Some(editor.cursor_pos()),
+            EditableMode::SingleLineMultipleEditors => {
+                let (row, col) = editor.cursor_row_and_col();
+                (row == editor_id).then_some(col)
+            }
+        }
+    }
+
+    /// Get the text of the given editor with the text being composed inserted at the cursor.
+    pub fn text_with_preedit(&self, editor_id: usize, text: &str) -> String {
+        let preedit = self.preedit.read();
+        let Some((preedit, cursor_pos)) = preedit.as_ref().zip(self.editor_cursor_pos(editor_id))
+        else {
+            return text.to_string();
+        };
+
+        let utf16 = text.encode_utf16().collect::<Vec<u16>>();
+        let cursor_pos = cursor_pos.min(utf16.len());
+        let mut text = String::from_utf16_lossy(&utf16[..cursor_pos]);
+        text.push_str(&preedit.text);
+        text.push_str(&String::from_utf16_lossy(&utf16[cursor_pos..]));
+        text
+    }
+
+    /// Get the cursor position of the given editor, moved inside the text being composed if any.
+    pub fn cursor_pos_with_preedit(&self, editor_id: usize) -> Option<usize> {
+        let cursor_pos = self.editor_cursor_pos(editor_id)?;
+        let preedit_cursor = self
+            .preedit
+            .read()
+            .as_ref()
+            .and_then(|preedit| preedit.cursor)
+            .map(|(_, to)| to)
+            .unwrap_or_default();
+        Some(cursor_pos + preedit_cursor)
+    }
+
+    /// Create a preedit attribute, underlining the text being composed in the given editor.
+    pub fn preedit_attr(&self, editor_id: usize) -> AttributeValue {
+        let preedit = self.preedit.read();
+        AttributeValue::any_value(CustomAttributeValues::TextHighlights(
+            preedit
+                .as_ref()
+                .zip(self.editor_cursor_pos(editor_id))
+                .map(|(preedit, cursor_pos)| vec![(cursor_pos, cursor_pos + preedit.utf16_len())])
+                .unwrap_or_default(),
+        ))
+    }
+
     /// Process a [`EditableEvent`] event.
     pub fn process_event(&mut self, edit_event: &EditableEvent) {
         let res = match edit_event {
@@ -286,6 +386,8 @@ impl UseEditable {
                 }
                 None
             }
+            // Keys are handled by the IME while composing
+            EditableEvent::KeyDown(_) if self.preedit.peek().is_some() => None,
             EditableEvent::KeyDown(e) => {
                 match e.code {
                     // Handle dragging
@@ -340,6 +442,40 @@ impl UseEditable {
 
                 None
             }
+            EditableEvent::Ime(e) => {
+                match &**e {
+                    ImeData::Preedit { text, cursor } if !text.is_empty() => {
+                        *self.preedit.write() = Some(ImePreedit::new(text.clone(), *cursor));
+
+                        let cursor_id = match self.mode {
+                            EditableMode::SingleLineMultipleEditors => {
+                                self.editor.peek().cursor_row()
+                            }
+                            EditableMode::MultipleLinesSingleEditor => 0,
+                        };
+
+                        // Measure the cursor area so the IME candidates window can follow it
+                        self.platform
+                            .send_app_event(EventLoopMessageAction::RemeasureTextGroup(
+                                TextGroupMeasurement {
+                                    text_id: self.cursor_reference.peek().text_id,
+                                    cursor_id,
+                                    cursor_position: None,
+                                    cursor_selection: None,
+                                    cursor_area: true,
+                                },
+                            ));
+                    }
+                    // The committed text is received as a key down right after
+                    _ => {
+                        if self.preedit.peek().is_some() {
+                            *self.preedit.write() = None;
+                        }
+                    }
+                }
+
+                None
+            }
             _ => None,
         };
 
@@ -352,6 +488,7 @@ impl UseEditable {
                             cursor_id,
                             cursor_position,
                             cursor_selection,
+                            cursor_area: false,
                         },
                     ));
             }
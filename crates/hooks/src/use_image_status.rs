@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use dioxus_core::{
+    prelude::spawn,
+    use_hook,
+    AttributeValue,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    custom_attributes::{
+        CustomAttributeValues,
+        ImageStatusReference,
+    },
+    dom::ImageDecodeStatus,
+};
+use tokio::sync::watch::channel;
+
+/// Get a signal to read the decoding status of an `image` element, through its `image_status` attribute.
+/// It stays `None` until the image starts being decoded.
+pub fn use_image_status() -> (AttributeValue, ReadOnlySignal<Option<ImageDecodeStatus>>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<Option<ImageDecodeStatus>>(None);
+        let mut signal = Signal::new(None);
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                let status = *rx.borrow();
+                if *signal.peek() != status {
+                    signal.set(status);
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::ImageStatus(ImageStatusReference(tx))),
+        signal.into(),
+    )
+}
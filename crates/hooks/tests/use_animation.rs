@@ -271,3 +271,217 @@ pub async fn sequential() {
     assert_eq!(width_a, 100.0);
     assert_eq!(width_b, 100.0);
 }
+
+#[tokio::test]
+pub async fn spring_progress() {
+    fn use_animation_app() -> Element {
+        let animation =
+            use_animation(|_conf| AnimSpring::new(0., 100.).stiffness(1000.).damping(63.));
+
+        let progress = animation.get().read().read();
+
+        use_hook(|| {
+            animation.start();
+        });
+
+        rsx!(rect {
+            width: "{progress}",
+        })
+    }
+
+    let mut utils = launch_test(use_animation_app);
+
+    // Disable event loop ticker
+    utils.config().event_loop_ticker = false;
+
+    // Initial state
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 0.0);
+
+    // State somewhere in the middle
+    sleep(Duration::from_millis(15)).await;
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert!(width > 0.0);
+    assert!(width < 100.0);
+
+    // Enable event loop ticker
+    utils.config().event_loop_ticker = true;
+
+    // Already settled
+    sleep(Duration::from_millis(700)).await;
+
+    // State in the end
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert_eq!(width, 100.0);
+}
+
+#[tokio::test]
+pub async fn virtual_time_progress() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|conf| {
+            conf.on_creation(OnCreation::Run);
+            AnimNum::new(0., 100.).time(100).function(Function::Linear)
+        });
+
+        let progress = animation.get().read().read();
+        let is_running = animation.is_running();
+
+        rsx!(rect {
+            width: "{progress}",
+            label {
+                "{is_running}"
+            }
+        })
+    }
+
+    let mut utils = launch_test_with_config(
+        use_animation_app,
+        TestingConfig::<()> {
+            virtual_time: true,
+            ..TestingConfig::default()
+        },
+    );
+
+    // Initial state
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 0.0);
+
+    // Every frame advances exactly as much as the clock
+    utils.advance_time(Duration::from_millis(25)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 25.0);
+
+    utils.advance_time(Duration::from_millis(35)).await;
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 60.0);
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("true"));
+
+    // Run the rest of the animation
+    utils.run_until_idle().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("false"));
+}
+
+#[test]
+pub fn spring_retarget_keeps_velocity() {
+    let mut spring = AnimSpring::new(0., 100.);
+    spring.prepare(AnimDirection::Forward);
+    spring.advance(50, AnimDirection::Forward);
+
+    let (value, velocity) = (spring.read(), spring.velocity());
+    assert!(value > 0.0);
+    assert!(velocity > 0.0);
+
+    // Retarget while in flight
+    let mut retargeted = AnimSpring::new(0., 50.);
+    retargeted.continue_from(&spring);
+    retargeted.prepare(AnimDirection::Forward);
+
+    assert_eq!(retargeted.read(), value);
+    assert_eq!(retargeted.velocity(), velocity);
+
+    // Settles in the new target
+    retargeted.advance(5000, AnimDirection::Forward);
+    assert!(retargeted.is_finished(5000, AnimDirection::Forward));
+    assert_eq!(retargeted.read(), 50.0);
+}
+
+#[test]
+pub fn cubic_bezier_function() {
+    let linear = apply_value(
+        0.,
+        100.,
+        50,
+        Duration::from_millis(100),
+        Ease::default(),
+        Function::CubicBezier(0., 0., 1., 1.),
+    );
+    assert!((linear - 50.).abs() < 0.01);
+
+    // Same as CSS `ease`
+    let ease = apply_value(
+        0.,
+        100.,
+        50,
+        Duration::from_millis(100),
+        Ease::default(),
+        Function::CubicBezier(0.25, 0.1, 0.25, 1.),
+    );
+    assert!((ease - 80.24).abs() < 0.1);
+
+    assert_eq!(
+        Function::CubicBezier(0.25, 0.1, 0.25, 1.),
+        Function::CubicBezier(0.25, 0.1, 0.25, 1.)
+    );
+    assert_ne!(
+        Function::CubicBezier(0.25, 0.1, 0.25, 1.),
+        Function::CubicBezier(0.42, 0., 1., 1.)
+    );
+    assert_ne!(Function::CubicBezier(0., 0., 1., 1.), Function::Linear);
+}
+
+#[test]
+pub fn keyframes_progress() {
+    let mut keyframes = AnimKeyframes::new(0f32)
+        .time(100)
+        .keyframe(1.0, 100.)
+        .keyframe(0.4, 80.);
+
+    let offsets = keyframes
+        .keyframes()
+        .iter()
+        .map(|keyframe| keyframe.offset)
+        .collect::<Vec<f32>>();
+    assert_eq!(offsets, vec![0.0, 0.4, 1.0]);
+
+    keyframes.prepare(AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 0.0);
+
+    keyframes.advance(20, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 40.0);
+
+    keyframes.advance(40, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 80.0);
+
+    keyframes.advance(100, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 100.0);
+    assert!(keyframes.is_finished(100, AnimDirection::Forward));
+
+    // Reverse runs the timeline backwards
+    keyframes.prepare(AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 100.0);
+
+    keyframes.advance(60, AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 80.0);
+}
+
+#[test]
+pub fn keyframes_colors_and_transforms() {
+    let mut colors = AnimKeyframes::new(Color::RED)
+        .time(100)
+        .keyframe(0.5, Color::BLUE)
+        .keyframe(1.0, Color::GREEN);
+
+    colors.prepare(AnimDirection::Forward);
+    colors.advance(50, AnimDirection::Forward);
+    assert_eq!(*colors.value(), Color::BLUE);
+
+    let mut transforms = AnimKeyframes::new(Transform::new())
+        .time(100)
+        .keyframe(1.0, Transform::new().rotate(90.).translate(10., 0.));
+
+    transforms.prepare(AnimDirection::Forward);
+    transforms.finish(AnimDirection::Forward);
+
+    let [a, b, c, d, e, f] = transforms.read().to_matrix();
+    assert!(a.abs() < 0.001);
+    assert!((b - 1.).abs() < 0.001);
+    assert!((c + 1.).abs() < 0.001);
+    assert!(d.abs() < 0.001);
+    assert_eq!((e, f), (10., 0.));
+}
@@ -14,6 +14,8 @@ pub enum EventName {
 
     Wheel,
 
+    Pinch,
+
     PointerOver,
     PointerDown,
     PointerEnter,
@@ -26,6 +28,8 @@ pub enum EventName {
     GlobalKeyDown,
     GlobalKeyUp,
 
+    Ime,
+
     TouchCancel,
     TouchStart,
     TouchMove,
@@ -58,6 +62,7 @@ impl FromStr for EventName {
             "mouseenter" => Ok(EventName::MouseEnter),
             "mouseleave" => Ok(EventName::MouseLeave),
             "wheel" => Ok(EventName::Wheel),
+            "pinch" => Ok(EventName::Pinch),
             "pointermove" => Ok(EventName::PointerOver),
             "pointerdown" => Ok(EventName::PointerDown),
             "pointerenter" => Ok(EventName::PointerEnter),
@@ -68,6 +73,7 @@ impl FromStr for EventName {
             "keyup" => Ok(EventName::KeyUp),
             "globalkeydown" => Ok(EventName::GlobalKeyDown),
             "globalkeyup" => Ok(EventName::GlobalKeyUp),
+            "ime" => Ok(EventName::Ime),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
@@ -98,6 +104,7 @@ impl From<EventName> for &str {
             EventName::MouseEnter => "mouseenter",
             EventName::MouseLeave => "mouseleave",
             EventName::Wheel => "wheel",
+            EventName::Pinch => "pinch",
             EventName::PointerOver => "pointermove",
             EventName::PointerDown => "pointerdown",
             EventName::PointerEnter => "pointerenter",
@@ -108,6 +115,7 @@ impl From<EventName> for &str {
             EventName::KeyDown => "keydown",
             EventName::GlobalKeyDown => "globalkeydown",
             EventName::GlobalKeyUp => "globalkeyup",
+            EventName::Ime => "ime",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
@@ -298,7 +306,7 @@ impl ragnarok::NameOfEvent for EventName {
     fn does_go_through_solid(&self) -> bool {
         matches!(
             self,
-            Self::KeyDown | Self::KeyUp | Self::GlobalKeyDown | Self::GlobalKeyUp
+            Self::KeyDown | Self::KeyUp | Self::GlobalKeyDown | Self::GlobalKeyUp | Self::Ime
         )
     }
 
@@ -0,0 +1,11 @@
+use std::str::FromStr;
+
+use freya_native_core::attributes::AttributeName;
+
+#[test]
+fn attribute_names_round_trip() {
+    for attribute in AttributeName::ALL {
+        assert_eq!(AttributeName::from_str(attribute.as_str()), Ok(*attribute));
+    }
+    assert!(AttributeName::from_str("unknown").is_err());
+}
@@ -15,6 +15,7 @@ pub struct ProcessedEvents<
     Emmitable: EmmitableEvent,
     Source: SourceEvent,
 > {
+    pub source_events: Vec<Source>,
     pub emmitable_events: Vec<Emmitable>,
     pub flattened_potential_events: Vec<PotentialEvent<Key, Name, Source>>,
     pub nodes_states_update: NodesStatesUpdate<Key>,
@@ -25,6 +26,7 @@ impl<Key: NodeKey, Name: NameOfEvent, Emmitable: EmmitableEvent, Source: SourceE
 {
     fn default() -> Self {
         Self {
+            source_events: Vec::default(),
             emmitable_events: Vec::default(),
             flattened_potential_events: Vec::default(),
             nodes_states_update: NodesStatesUpdate::default(),
@@ -32,6 +34,39 @@ impl<Key: NodeKey, Name: NameOfEvent, Emmitable: EmmitableEvent, Source: SourceE
     }
 }
 
+/// What happened when the handlers of an [EmmitableEvent] were called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmitOutcome {
+    /// Whether the default action of the event is still enabled. Otherwise the events it can cancel are discarded.
+    pub default_action_enabled: bool,
+    /// Whether a handler stopped the event from bubbling up.
+    pub propagation_stopped: bool,
+}
+
+/// An [EmmitableEvent] that was emitted by the executor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmittedEvent<Emmitable: EmmitableEvent> {
+    pub event: Emmitable,
+    pub outcome: EmitOutcome,
+    /// Yet-to-emit events discarded because this event was cancelled.
+    pub cancelled_events: Vec<Emmitable>,
+}
+
+/// Everything that happened in a run of the executor.
+///
+/// `emitted_events` is only filled if [EventsExecutor::report_emitted_events] is enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutedEvents<
+    Key: NodeKey,
+    Name: NameOfEvent,
+    Emmitable: EmmitableEvent,
+    Source: SourceEvent,
+> {
+    pub source_events: Vec<Source>,
+    pub potential_events: Vec<PotentialEvent<Key, Name, Source>>,
+    pub emitted_events: Vec<EmittedEvent<Emmitable>>,
+}
+
 pub trait EventsExecutor
 where
     Self: std::marker::Sized,
@@ -42,10 +77,15 @@ where
     type Source: SourceEvent;
 
     /// Call the event handler of the given [Self::Emmitable].
-    fn emit_event(&mut self, event: Self::Emmitable) -> bool;
+    fn emit_event(&mut self, event: Self::Emmitable) -> EmitOutcome;
 
     // All events have been emitted
     fn emitted_events(&mut self) {}
+
+    /// Whether to report the emitted events in [ExecutedEvents], which requires cloning the cancelled events.
+    fn report_emitted_events(&self) -> bool {
+        false
+    }
 }
 
 impl<T: EventsExecutor> private::Sealed for T {}
@@ -59,12 +99,15 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
         mut self,
         nodes_state: &mut NodesState<Self::Key>,
         ProcessedEvents {
+            source_events,
             mut emmitable_events,
             flattened_potential_events,
             mut nodes_states_update,
         }: ProcessedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source>,
-    ) {
+    ) -> ExecutedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source> {
+        let report_emitted_events = self.report_emitted_events();
         let mut processed_events = Vec::<Self::Emmitable>::new();
+        let mut outcomes = Vec::<(EmitOutcome, Vec<Self::Emmitable>)>::new();
 
         #[cfg(debug_assertions)]
         tracing::info!("Processing {} DOM events", emmitable_events.len());
@@ -72,14 +115,21 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
         while !emmitable_events.is_empty() {
             let emmitable_event = emmitable_events.remove(0);
 
-            let default_action_enabled = self.emit_event(emmitable_event.clone());
+            let outcome = self.emit_event(emmitable_event.clone());
+            let mut cancelled_events = Vec::new();
 
-            if !default_action_enabled {
+            if !outcome.default_action_enabled {
                 // Get the events that this event can cancel
                 let cancellable_events = emmitable_event.name().get_cancellable_events();
 
                 // Remove the rest of emmitable events that are cancellable
-                emmitable_events.retain(|event| !cancellable_events.contains(&event.name()));
+                emmitable_events.retain(|event| {
+                    let is_cancellable = cancellable_events.contains(&event.name());
+                    if is_cancellable && report_emitted_events {
+                        cancelled_events.push(event.clone());
+                    }
+                    !is_cancellable
+                });
 
                 // Discard the potential events that dont find a matching emmitable event
                 // So for instance, a cancelled potential mousemove event wont be discarded if a emmitable mousenter was processed before
@@ -102,11 +152,28 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
             }
 
             processed_events.push(emmitable_event);
+            if report_emitted_events {
+                outcomes.push((outcome, cancelled_events));
+            }
         }
 
         self.emitted_events();
 
         nodes_state.apply_update(nodes_states_update);
+
+        ExecutedEvents {
+            source_events,
+            potential_events: flattened_potential_events,
+            emitted_events: processed_events
+                .into_iter()
+                .zip(outcomes)
+                .map(|(event, (outcome, cancelled_events))| EmittedEvent {
+                    event,
+                    outcome,
+                    cancelled_events,
+                })
+                .collect(),
+        }
     }
 }
 
@@ -123,7 +190,7 @@ where
         self,
         nodes_state: &mut NodesState<Self::Key>,
         processed_events: ProcessedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source>,
-    );
+    ) -> ExecutedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source>;
 }
 
 #[doc(hidden)]
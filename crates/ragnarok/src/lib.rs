@@ -23,7 +23,7 @@ pub(crate) use measurement::*;
 pub use measurer::*;
 pub use name::*;
 pub use nodes_state::*;
-pub(crate) use potential_event::*;
+pub use potential_event::*;
 pub use source::*;
 
 pub type CursorPoint = euclid::Point2D<f64, ()>;
@@ -90,10 +90,11 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         let mut flattened_potential_events = potential_events.into_values().flatten().collect_vec();
         flattened_potential_events.sort_unstable();
 
-        // Clear the source events vec as all events have been processed
-        source_events.clear();
+        // Take the source events as all of them have been processed
+        let source_events = std::mem::take(source_events);
 
         ProcessedEvents {
+            source_events,
             emmitable_events,
             flattened_potential_events,
             nodes_states_update,
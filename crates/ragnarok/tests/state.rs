@@ -8,6 +8,8 @@ use std::collections::{
 use ragnarok::{
     Area,
     CursorPoint,
+    EmitOutcome,
+    EmittedEvent,
     EmmitableEvent,
     EventsExecutor,
     EventsExecutorRunner,
@@ -201,24 +203,43 @@ impl EmmitableEvent for TestEmmitableEvent {
     }
 }
 
+const ENABLED: EmitOutcome = EmitOutcome {
+    default_action_enabled: true,
+    propagation_stopped: false,
+};
+
+const PREVENTED: EmitOutcome = EmitOutcome {
+    default_action_enabled: false,
+    propagation_stopped: false,
+};
+
+const STOPPED: EmitOutcome = EmitOutcome {
+    default_action_enabled: true,
+    propagation_stopped: true,
+};
+
 struct TestExecutor {
     emmited: Vec<TestEmmitableEvent>,
-    handler: fn(&TestEmmitableEvent) -> bool,
+    handler: fn(&TestEmmitableEvent) -> EmitOutcome,
+    report: bool,
 }
 
 impl TestExecutor {
-    pub fn new(handler: fn(&TestEmmitableEvent) -> bool) -> Self {
+    pub fn new(handler: fn(&TestEmmitableEvent) -> EmitOutcome) -> Self {
         Self {
             emmited: Vec::default(),
             handler,
+            report: false,
         }
     }
 
     pub fn without_handler() -> Self {
-        Self {
-            emmited: Vec::default(),
-            handler: |_| true,
-        }
+        Self::new(|_| ENABLED)
+    }
+
+    pub fn reporting(mut self) -> Self {
+        self.report = true;
+        self
     }
 }
 
@@ -231,10 +252,14 @@ impl EventsExecutor for TestExecutor {
 
     type Source = TestSourceEvent;
 
-    fn emit_event(&mut self, event: Self::Emmitable) -> bool {
-        let allowed = (self.handler)(&event);
+    fn emit_event(&mut self, event: Self::Emmitable) -> EmitOutcome {
+        let outcome = (self.handler)(&event);
         self.emmited.push(event);
-        allowed
+        outcome
+    }
+
+    fn report_emitted_events(&self) -> bool {
+        self.report
     }
 }
 
@@ -560,7 +585,162 @@ fn cancel_emmited_events_on_capture() {
         ]
     );
     // Apply the processed events
-    TestExecutor::new(|_| false).run(&mut nodes_state, processed_events);
+    TestExecutor::new(|_| PREVENTED).run(&mut nodes_state, processed_events);
     // Assert that the node is not being hovvered as the event was can cancelled
     assert!(!nodes_state.is_hovered(0));
 }
+
+/// A node listening to the captured mouse move and another one listening to the mouse enter.
+fn capture_measurer() -> TestMeasurer {
+    let mut test_measurer = TestMeasurer::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.listen_to(0, EventName::MouseEnter);
+
+    test_measurer.add(
+        1,
+        None,
+        0,
+        Area::new((200., 200.).into(), (100., 100.).into()),
+    );
+    test_measurer.listen_to(1, EventName::CaptureGlobalMouseMove);
+
+    test_measurer
+}
+
+const CAPTURE_EVENT: TestEmmitableEvent = TestEmmitableEvent {
+    key: 1,
+    name: EventName::CaptureGlobalMouseMove,
+    source: EventName::MouseMove,
+};
+
+const ENTER_EVENT: TestEmmitableEvent = TestEmmitableEvent {
+    key: 0,
+    name: EventName::MouseEnter,
+    source: EventName::MouseMove,
+};
+
+#[test]
+fn report_emitted_events() {
+    let mut test_measurer = capture_measurer();
+    let mut nodes_state = NodesState::default();
+
+    let source_events = vec![TestSourceEvent::MouseMove {
+        cursor: (25., 25.).into(),
+    }];
+    let processed_events = test_measurer.run(&mut source_events.clone(), &mut nodes_state, None);
+    let potential_events = processed_events.flattened_potential_events.clone();
+
+    let executed_events = TestExecutor::without_handler()
+        .reporting()
+        .run(&mut nodes_state, processed_events);
+
+    assert_eq!(executed_events.source_events, source_events);
+    assert_eq!(executed_events.potential_events, potential_events);
+    assert_eq!(
+        executed_events.emitted_events,
+        vec![
+            EmittedEvent {
+                event: CAPTURE_EVENT,
+                outcome: ENABLED,
+                cancelled_events: vec![],
+            },
+            EmittedEvent {
+                event: ENTER_EVENT,
+                outcome: ENABLED,
+                cancelled_events: vec![],
+            },
+        ]
+    );
+    assert!(nodes_state.is_hovered(0));
+}
+
+#[test]
+fn report_cancelled_events() {
+    let mut test_measurer = capture_measurer();
+    let mut nodes_state = NodesState::default();
+
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (25., 25.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+
+    let executed_events = TestExecutor::new(|event| {
+        if event.name == EventName::CaptureGlobalMouseMove {
+            PREVENTED
+        } else {
+            ENABLED
+        }
+    })
+    .reporting()
+    .run(&mut nodes_state, processed_events);
+
+    // The mouse enter event is never emitted, it is reported as cancelled by the capture event instead
+    assert_eq!(
+        executed_events.emitted_events,
+        vec![EmittedEvent {
+            event: CAPTURE_EVENT,
+            outcome: PREVENTED,
+            cancelled_events: vec![ENTER_EVENT],
+        }]
+    );
+    assert!(!nodes_state.is_hovered(0));
+}
+
+#[test]
+fn report_stopped_propagation() {
+    let mut test_measurer = capture_measurer();
+    let mut nodes_state = NodesState::default();
+
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (25., 25.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+
+    let executed_events = TestExecutor::new(|_| STOPPED)
+        .reporting()
+        .run(&mut nodes_state, processed_events);
+
+    // Stopping the propagation does not cancel other events
+    assert_eq!(
+        executed_events.emitted_events,
+        vec![
+            EmittedEvent {
+                event: CAPTURE_EVENT,
+                outcome: STOPPED,
+                cancelled_events: vec![],
+            },
+            EmittedEvent {
+                event: ENTER_EVENT,
+                outcome: STOPPED,
+                cancelled_events: vec![],
+            },
+        ]
+    );
+    assert!(nodes_state.is_hovered(0));
+}
+
+#[test]
+fn emitted_events_are_not_reported_by_default() {
+    let mut test_measurer = capture_measurer();
+    let mut nodes_state = NodesState::default();
+
+    let processed_events = test_measurer.run(
+        &mut vec![TestSourceEvent::MouseMove {
+            cursor: (25., 25.).into(),
+        }],
+        &mut nodes_state,
+        None,
+    );
+
+    let executed_events = TestExecutor::new(|_| PREVENTED).run(&mut nodes_state, processed_events);
+
+    assert!(executed_events.emitted_events.is_empty());
+    assert_eq!(executed_events.source_events.len(), 1);
+}
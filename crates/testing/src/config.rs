@@ -1,14 +1,27 @@
 use std::time::Duration;
 
+use freya_core::values::Color;
 use torin::geometry::Size2D;
 
+use crate::snapshot::SnapshotTolerance;
+
 /// Configuration for [`crate::test_handler::TestingHandler`].
 #[derive(Clone)]
 pub struct TestingConfig<T: 'static + Clone> {
     pub vdom_timeout: Duration,
     pub size: Size2D,
     pub event_loop_ticker: bool,
+    /// Pause the tokio clock when launching the test, so animations and timers only move forward with
+    /// [`crate::test_handler::TestingHandler::advance_time`] and [`crate::test_handler::TestingHandler::run_until_idle`].
+    ///
+    /// Requires a current-thread tokio runtime, like the one of `#[tokio::test]`, launching the test
+    /// panics otherwise. The clock stays paused if another handler of the same runtime already paused it.
+    pub virtual_time: bool,
     pub state: Option<T>,
+    /// Background color of the window, used for snapshots and to check the contrast of texts.
+    pub background: Color,
+    /// Tolerance used by [`crate::test_handler::TestingHandler::assert_snapshot`].
+    pub snapshot_tolerance: SnapshotTolerance,
 }
 
 impl<T: 'static + Clone> Default for TestingConfig<T> {
@@ -17,7 +30,10 @@ impl<T: 'static + Clone> Default for TestingConfig<T> {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
+            virtual_time: false,
             state: None,
+            background: Color::WHITE,
+            snapshot_tolerance: SnapshotTolerance::default(),
         }
     }
 }
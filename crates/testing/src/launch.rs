@@ -1,6 +1,7 @@
 use std::{
     future::Future,
     path::PathBuf,
+    time::Duration,
 };
 
 use accesskit::{
@@ -47,7 +48,11 @@ use freya_elements as dioxus_elements;
 use freya_engine::prelude::*;
 use ragnarok::NodesState;
 use tokio::{
-    runtime::Runtime,
+    runtime::{
+        Handle,
+        Runtime,
+        RuntimeFlavor,
+    },
     sync::{
         broadcast,
         mpsc::unbounded_channel,
@@ -141,12 +146,33 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         platform_receiver,
     };
 
+    if handler.config.virtual_time {
+        pause_time();
+    }
+
     handler.init_doms();
     handler.resize(handler.config.size);
 
     handler
 }
 
+/// Pause the tokio clock of the current runtime, unless another test handler already paused it.
+fn pause_time() {
+    let is_current_thread = Handle::try_current()
+        .is_ok_and(|handle| handle.runtime_flavor() == RuntimeFlavor::CurrentThread);
+    assert!(
+        is_current_thread,
+        "`TestingConfig::virtual_time` requires a current-thread tokio runtime, like the one of `#[tokio::test]`"
+    );
+
+    // A paused clock does not move forward by itself
+    let now = tokio::time::Instant::now();
+    std::thread::sleep(Duration::from_millis(1));
+    if tokio::time::Instant::now() != now {
+        tokio::time::pause();
+    }
+}
+
 fn with_accessibility(app: AppComponent) -> VirtualDom {
     #[derive(Clone)]
     struct RootProps {
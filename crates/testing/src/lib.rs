@@ -159,10 +159,78 @@
 //! assert_eq!(label_text.text(), Some("Hello World!"));
 //! # }
 //! ````
+//!
+//! ## Snapshot example
+//!
+//! The `assert_snapshot!` macro renders the app without a GPU and compares it against a golden image stored in the `snapshots` folder next to the test file.
+//! When they differ, the output and an image highlighting the different pixels are saved next to the golden image.
+//!
+//! Run the tests with `FREYA_UPDATE_SNAPSHOTS=1` to create or update the golden images.
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! # use freya_testing::prelude::*;
+//! # async fn test() {
+//! fn our_component() -> Element {
+//!     rsx!(
+//!         rect {
+//!             width: "100",
+//!             height: "100",
+//!             background: "red",
+//!         }
+//!     )
+//! }
+//!
+//! let mut utils = launch_test(our_component);
+//! utils.wait_for_update().await;
+//!
+//! assert_snapshot!(utils, "red_rect");
+//! # }
+//! ```
+//!
+//! ## Virtual time example
+//!
+//! With `virtual_time` enabled, animations and tokio timers inside the app only move forward when the test advances the clock,
+//! so their intermediate states can be asserted exactly and without waiting in real time.
+//!
+//! ```rust, no_run
+//! # use std::time::Duration;
+//! # use freya::prelude::*;
+//! # use freya_testing::prelude::*;
+//! # async fn test() {
+//! fn our_component() -> Element {
+//!     let animation = use_animation(|conf| {
+//!         conf.on_creation(OnCreation::Run);
+//!         AnimNum::new(0., 100.).time(100).function(Function::Linear)
+//!     });
+//!     let width = animation.get().read().read();
+//!
+//!     rsx!(rect { width: "{width}" })
+//! }
+//!
+//! let mut utils = launch_test_with_config(
+//!     our_component,
+//!     TestingConfig::<()> {
+//!         virtual_time: true,
+//!         ..TestingConfig::default()
+//!     },
+//! );
+//! utils.wait_for_update().await;
+//!
+//! utils.advance_time(Duration::from_millis(50)).await;
+//! assert_eq!(utils.root().get(0).area().unwrap().width(), 50.0);
+//!
+//! // Run the rest of the animation
+//! utils.run_until_idle().await;
+//! assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+//! # }
+//! ```
 
 pub mod config;
 pub mod event;
 pub mod launch;
+pub mod locator;
+pub mod snapshot;
 pub mod test_handler;
 pub mod test_node;
 pub mod test_utils;
@@ -178,11 +246,20 @@ pub mod prelude {
         states::*,
         values::*,
     };
+    pub use regex::Regex;
 
     pub use crate::{
+        assert_snapshot,
         config::*,
         event::*,
         launch::*,
+        locator::*,
+        snapshot::{
+            SnapshotDiff,
+            SnapshotError,
+            SnapshotTolerance,
+            UPDATE_SNAPSHOTS_ENV,
+        },
         test_handler::*,
         test_node::*,
         test_utils::*,
@@ -0,0 +1,337 @@
+use std::{
+    fmt::{
+        Display,
+        Write,
+    },
+    str::FromStr,
+};
+
+use accesskit::{
+    NodeId as AccessibilityId,
+    Role,
+};
+use freya_core::{
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+    },
+    parsing::Parse,
+    states::AccessibilityState,
+};
+use freya_native_core::{
+    node::NodeType,
+    real_dom::NodeImmutable,
+    tags::TagName,
+    tree::TreeRef,
+    NodeId,
+};
+use regex::Regex;
+
+use crate::{
+    test_node::TestNode,
+    test_utils::TestUtils,
+};
+
+/// How a text is matched by a [`Locator`].
+#[derive(Clone, Debug)]
+pub enum TextMatch {
+    Exact(String),
+    Contains(String),
+    Regex(Regex),
+}
+
+impl TextMatch {
+    pub fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Exact(exact) => text == exact,
+            Self::Contains(contained) => text.contains(contained.as_str()),
+            Self::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+impl From<&str> for TextMatch {
+    fn from(text: &str) -> Self {
+        Self::Exact(text.to_string())
+    }
+}
+
+impl From<String> for TextMatch {
+    fn from(text: String) -> Self {
+        Self::Exact(text)
+    }
+}
+
+impl From<Regex> for TextMatch {
+    fn from(regex: Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+impl Display for TextMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(exact) => write!(f, "{exact:?}"),
+            Self::Contains(contained) => write!(f, "*{contained:?}*"),
+            Self::Regex(regex) => write!(f, "/{regex}/"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum LocatorFilter {
+    Role(Role),
+    A11yName(TextMatch),
+    A11yId(AccessibilityId),
+    Text(TextMatch),
+    Tag(TagName),
+}
+
+impl LocatorFilter {
+    fn matches(&self, node: &DioxusNode) -> bool {
+        let node_type = node.node_type();
+        let NodeType::Element(element) = &*node_type else {
+            return false;
+        };
+        let accessibility = node.get::<AccessibilityState>();
+        let builder = accessibility
+            .as_ref()
+            .and_then(|accessibility| accessibility.builder.as_ref());
+
+        match self {
+            Self::Role(role) => builder.is_some_and(|builder| builder.role() == *role),
+            Self::A11yName(name) => builder
+                .and_then(|builder| builder.label().or(builder.class_name()))
+                .is_some_and(|node_name| name.matches(node_name)),
+            Self::A11yId(a11y_id) => accessibility
+                .as_ref()
+                .is_some_and(|accessibility| accessibility.a11y_id == Some(*a11y_id)),
+            Self::Text(text) => {
+                let inner_text = if element.tag == TagName::Paragraph {
+                    descendants_text(node)
+                } else {
+                    children_text(node)
+                };
+                inner_text.is_some_and(|inner_text| text.matches(&inner_text))
+            }
+            Self::Tag(tag) => element.tag == *tag,
+        }
+    }
+}
+
+impl Display for LocatorFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Role(role) => write!(f, "role = {role:?}"),
+            Self::A11yName(name) => write!(f, "a11y_name = {name}"),
+            Self::A11yId(a11y_id) => write!(f, "a11y_id = {}", a11y_id.0),
+            Self::Text(text) => write!(f, "text = {text}"),
+            Self::Tag(tag) => write!(f, "tag = {tag}"),
+        }
+    }
+}
+
+/// Text of the direct text children of a node.
+fn children_text(node: &DioxusNode) -> Option<String> {
+    let mut text = None::<String>;
+    for child in node.children() {
+        if let NodeType::Text(child_text) = &*child.node_type() {
+            text.get_or_insert_with(String::new).push_str(child_text);
+        }
+    }
+    text
+}
+
+/// Text of all the descendant text nodes of a node, e.g the `text` spans of a `paragraph`.
+fn descendants_text(node: &DioxusNode) -> Option<String> {
+    let mut text = None::<String>;
+    for child in node.children() {
+        if let NodeType::Text(child_text) = &*child.node_type() {
+            text.get_or_insert_with(String::new).push_str(child_text);
+        } else if let Some(child_text) = descendants_text(&child) {
+            text.get_or_insert_with(String::new).push_str(&child_text);
+        }
+    }
+    text
+}
+
+/// Find nodes by their accessibility role, `a11y_name`, `a11y_id`, text or tag.
+///
+/// Locators are lazy, they search the DOM every time they are resolved with [`Locator::all`], [`Locator::first`] or [`Locator::only`],
+/// so the same locator can be reused after every [`crate::test_handler::TestingHandler::wait_for_update`].
+///
+/// ```rust, no_run
+/// # use freya_testing::prelude::*;
+/// # use freya::prelude::*;
+/// # async fn test() {
+/// # let mut utils = launch_test(|| rsx!( rect { } ));
+/// let counter = utils
+///     .locator()
+///     .tag("label")
+///     .text(Regex::new(r"^Count: \d+$").unwrap());
+/// assert_eq!(counter.count(), 1);
+///
+/// let button = utils.locator().role("button").a11y_name("Increase");
+/// let area = button.only().area().unwrap();
+/// utils.click_cursor(area.center().to_f64()).await;
+///
+/// assert_eq!(counter.only().get(0).text(), Some("Count: 1"));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Locator {
+    utils: TestUtils,
+    root_id: NodeId,
+    filters: Vec<LocatorFilter>,
+}
+
+impl Locator {
+    pub(crate) fn new(utils: TestUtils, root_id: NodeId) -> Self {
+        Self {
+            utils,
+            root_id,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Only match nodes with the given accessibility role, in the same format as the `a11y_role` attribute, e.g `"button"`.
+    #[track_caller]
+    pub fn role(mut self, role: &str) -> Self {
+        let role =
+            Role::parse(role).unwrap_or_else(|_| panic!("Unknown accessibility role {role:?}"));
+        self.filters.push(LocatorFilter::Role(role));
+        self
+    }
+
+    /// Only match nodes whose `a11y_name` matches.
+    pub fn a11y_name(mut self, name: impl Into<TextMatch>) -> Self {
+        self.filters.push(LocatorFilter::A11yName(name.into()));
+        self
+    }
+
+    /// Only match the node with the given `a11y_id`.
+    pub fn a11y_id(mut self, a11y_id: AccessibilityId) -> Self {
+        self.filters.push(LocatorFilter::A11yId(a11y_id));
+        self
+    }
+
+    /// Only match elements whose text matches, that is their text children or the text spans of a `paragraph`.
+    pub fn text(mut self, text: impl Into<TextMatch>) -> Self {
+        self.filters.push(LocatorFilter::Text(text.into()));
+        self
+    }
+
+    /// Only match elements with the given tag, e.g `"label"`.
+    #[track_caller]
+    pub fn tag(mut self, tag: &str) -> Self {
+        let tag = TagName::from_str(tag).unwrap_or_else(|_| panic!("Unknown tag {tag:?}"));
+        self.filters.push(LocatorFilter::Tag(tag));
+        self
+    }
+
+    /// Get all the matching nodes, in the DOM order.
+    pub fn all(&self) -> Vec<TestNode> {
+        self.utils
+            .get_node_matching_inside_id(self.root_id, |node| {
+                self.filters.iter().all(|filter| filter.matches(node))
+            })
+    }
+
+    /// Get the first matching node.
+    pub fn try_first(&self) -> Option<TestNode> {
+        self.all().into_iter().next()
+    }
+
+    /// Get the first matching node. Will panic with a dump of the DOM if there is none.
+    #[track_caller]
+    pub fn first(&self) -> TestNode {
+        self.try_first()
+            .unwrap_or_else(|| panic!("No node found for {self}.\n\n{}", self.dump()))
+    }
+
+    /// Get the only matching node. Will panic with a dump of the DOM if there is none or more than one.
+    #[track_caller]
+    pub fn only(&self) -> TestNode {
+        let mut nodes = self.all();
+        match nodes.len() {
+            1 => nodes.remove(0),
+            0 => panic!("No node found for {self}.\n\n{}", self.dump()),
+            count => panic!(
+                "Expected a single node for {self} but found {count}.\n\n{}",
+                self.dump()
+            ),
+        }
+    }
+
+    /// Get how many nodes match.
+    pub fn count(&self) -> usize {
+        self.all().len()
+    }
+
+    /// Check if any node matches.
+    pub fn exists(&self) -> bool {
+        self.try_first().is_some()
+    }
+
+    /// Readable dump of the searched DOM.
+    pub fn dump(&self) -> String {
+        let sdom = self.utils.sdom();
+        let fdom = sdom.get();
+        dump_dom(fdom.rdom(), self.root_id)
+    }
+}
+
+impl Display for Locator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.filters.is_empty() {
+            return f.write_str("any node");
+        }
+        for (i, filter) in self.filters.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{filter}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Readable dump of the DOM starting from the given node, one node per line.
+pub fn dump_dom(rdom: &DioxusDOM, root_id: NodeId) -> String {
+    fn dump_node(rdom: &DioxusDOM, node_id: NodeId, depth: usize, output: &mut String) {
+        let Some(node) = rdom.get(node_id) else {
+            return;
+        };
+        let indent = "  ".repeat(depth);
+
+        match &*node.node_type() {
+            NodeType::Element(element) => {
+                write!(output, "{indent}{} [id: {}", element.tag, node_id.index()).ok();
+                if let Some(accessibility) = node.get::<AccessibilityState>() {
+                    if let Some(builder) = &accessibility.builder {
+                        write!(output, ", role: {:?}", builder.role()).ok();
+                        if let Some(name) = builder.label().or(builder.class_name()) {
+                            write!(output, ", a11y_name: {name:?}").ok();
+                        }
+                    }
+                    if let Some(a11y_id) = accessibility.a11y_id {
+                        write!(output, ", a11y_id: {}", a11y_id.0).ok();
+                    }
+                }
+                output.push_str("]\n");
+            }
+            NodeType::Text(text) => {
+                writeln!(output, "{indent}{text:?}").ok();
+            }
+            NodeType::Placeholder => {}
+        }
+
+        for child_id in rdom.tree_ref().children_ids(node_id) {
+            dump_node(rdom, child_id, depth + 1, output);
+        }
+    }
+
+    let mut output = String::new();
+    dump_node(rdom, root_id, 0, &mut output);
+    output
+}
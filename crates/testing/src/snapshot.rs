@@ -0,0 +1,260 @@
+use std::{
+    fmt::Display,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// Set this environment variable to overwrite the golden images with the current output,
+/// e.g `FREYA_UPDATE_SNAPSHOTS=1 cargo test`.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "FREYA_UPDATE_SNAPSHOTS";
+
+/// How different a snapshot can be from its golden image and still be considered equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotTolerance {
+    /// Maximum difference of any color channel of a pixel for it to be considered equal, from `0` to `255`.
+    pub max_channel_difference: u8,
+    /// Maximum percentage of different pixels, from `0.0` to `100.0`.
+    pub max_different_pixels: f64,
+}
+
+impl Default for SnapshotTolerance {
+    fn default() -> Self {
+        Self {
+            max_channel_difference: 0,
+            max_different_pixels: 0.0,
+        }
+    }
+}
+
+impl SnapshotTolerance {
+    /// Tolerance that only accepts identical snapshots.
+    pub fn exact() -> Self {
+        Self::default()
+    }
+}
+
+/// Differences found between a snapshot and its golden image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotDiff {
+    pub different_pixels: usize,
+    pub total_pixels: usize,
+    /// Biggest difference found in a color channel.
+    pub max_channel_difference: u8,
+}
+
+impl SnapshotDiff {
+    /// Percentage of different pixels, from `0.0` to `100.0`.
+    pub fn percentage(&self) -> f64 {
+        if self.total_pixels == 0 {
+            0.0
+        } else {
+            self.different_pixels as f64 / self.total_pixels as f64 * 100.0
+        }
+    }
+
+    /// Check if the differences are within the given tolerance.
+    pub fn is_within(&self, tolerance: &SnapshotTolerance) -> bool {
+        self.percentage() <= tolerance.max_different_pixels
+    }
+}
+
+/// Reason why a snapshot does not match its golden image.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// There is no golden image yet, the output was saved in `actual_path`.
+    MissingGolden {
+        golden_path: PathBuf,
+        actual_path: PathBuf,
+    },
+    /// The golden image could not be read or decoded.
+    InvalidGolden {
+        golden_path: PathBuf,
+    },
+    /// The golden image and the output have different sizes.
+    SizeMismatch {
+        golden_path: PathBuf,
+        actual_path: PathBuf,
+        expected: (i32, i32),
+        actual: (i32, i32),
+    },
+    /// Too many pixels are different, the output was saved in `actual_path` and the differences in `diff_path`.
+    Mismatch {
+        golden_path: PathBuf,
+        actual_path: PathBuf,
+        diff_path: PathBuf,
+        diff: SnapshotDiff,
+    },
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingGolden {
+                golden_path,
+                actual_path,
+            } => write!(
+                f,
+                "Missing golden image {}, the output was saved in {}. Run with {UPDATE_SNAPSHOTS_ENV}=1 to accept it.",
+                golden_path.display(),
+                actual_path.display()
+            ),
+            Self::InvalidGolden { golden_path } => {
+                write!(f, "Failed to decode the golden image {}.", golden_path.display())
+            }
+            Self::SizeMismatch {
+                golden_path,
+                actual_path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Snapshot size {}x{} does not match the size {}x{} of {}, the output was saved in {}.",
+                actual.0,
+                actual.1,
+                expected.0,
+                expected.1,
+                golden_path.display(),
+                actual_path.display()
+            ),
+            Self::Mismatch {
+                golden_path,
+                actual_path,
+                diff_path,
+                diff,
+            } => write!(
+                f,
+                "Snapshot does not match {}: {} of {} pixels ({:.2}%) are different, with a max channel difference of {}. The output was saved in {} and the differences in {}.",
+                golden_path.display(),
+                diff.different_pixels,
+                diff.total_pixels,
+                diff.percentage(),
+                diff.max_channel_difference,
+                actual_path.display(),
+                diff_path.display()
+            ),
+            Self::Io(err) => write!(f, "Failed to access the snapshot files: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Check if the golden images must be overwritten, see [`UPDATE_SNAPSHOTS_ENV`].
+pub fn should_update_snapshots() -> bool {
+    std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Path where the output of a failed snapshot is saved, e.g `button.actual.png`.
+pub fn actual_path(golden_path: &Path) -> PathBuf {
+    golden_path.with_extension("actual.png")
+}
+
+/// Path where the differences of a failed snapshot are saved, e.g `button.diff.png`.
+pub fn diff_path(golden_path: &Path) -> PathBuf {
+    golden_path.with_extension("diff.png")
+}
+
+/// Path of the golden image `name` in the `snapshots` folder next to `source_file`.
+///
+/// Used by [`assert_snapshot!`](crate::assert_snapshot!), `source_file` comes from `file!()`,
+/// which can either be relative to the crate or to the workspace.
+pub fn golden_path(manifest_dir: &str, source_file: &str, name: &str) -> PathBuf {
+    let manifest_dir = Path::new(manifest_dir);
+    let source_path = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join(source_file))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| manifest_dir.join(source_file));
+
+    source_path
+        .parent()
+        .unwrap_or(manifest_dir)
+        .join("snapshots")
+        .join(format!("{name}.png"))
+}
+
+/// Compare two buffers of 4 bytes pixels with the same layout.
+///
+/// Returns the differences and a buffer with the same layout highlighting the different pixels.
+pub fn compare_pixels(
+    actual: &[u8],
+    expected: &[u8],
+    max_channel_difference: u8,
+    highlight: [u8; 4],
+) -> (SnapshotDiff, Vec<u8>) {
+    let mut diff = SnapshotDiff {
+        different_pixels: 0,
+        total_pixels: actual.len() / 4,
+        max_channel_difference: 0,
+    };
+    let mut diff_pixels = Vec::with_capacity(actual.len());
+
+    for (actual, expected) in actual.chunks_exact(4).zip(expected.chunks_exact(4)) {
+        let difference = actual
+            .iter()
+            .zip(expected)
+            .map(|(a, b)| a.abs_diff(*b))
+            .max()
+            .unwrap_or_default();
+        diff.max_channel_difference = diff.max_channel_difference.max(difference);
+
+        if difference > max_channel_difference {
+            diff.different_pixels += 1;
+            diff_pixels.extend(highlight);
+        } else {
+            // Faded version of the pixel so the differences stand out
+            let luma = (actual[0] as u16 + actual[1] as u16 + actual[2] as u16) / 3;
+            let faded = (255 - (255 - luma) / 4) as u8;
+            diff_pixels.extend([faded, faded, faded, 255]);
+        }
+    }
+
+    (diff, diff_pixels)
+}
+
+/// Assert that the app matches the golden image `name`, stored in the `snapshots` folder next to the test file.
+///
+/// On failure, the output and the differences are saved next to the golden image.
+/// Set [`UPDATE_SNAPSHOTS_ENV`] to overwrite the golden images instead.
+///
+/// ```rust, no_run
+/// # use freya_testing::prelude::*;
+/// # use freya::prelude::*;
+/// # async fn test() {
+/// # let mut utils = launch_test(|| rsx!( rect { } ));
+/// utils.wait_for_update().await;
+/// assert_snapshot!(utils, "empty_rect");
+///
+/// // With a custom tolerance
+/// assert_snapshot!(
+///     utils,
+///     "empty_rect",
+///     SnapshotTolerance {
+///         max_channel_difference: 2,
+///         max_different_pixels: 0.5,
+///     }
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($utils:expr, $name:expr) => {{
+        let tolerance = $utils.config().snapshot_tolerance;
+        $crate::assert_snapshot!($utils, $name, tolerance)
+    }};
+    ($utils:expr, $name:expr, $tolerance:expr) => {
+        $utils.assert_snapshot_with_tolerance(
+            $crate::snapshot::golden_path(env!("CARGO_MANIFEST_DIR"), file!(), $name),
+            $tolerance,
+        )
+    };
+}
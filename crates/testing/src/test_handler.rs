@@ -1,7 +1,15 @@
 use std::{
-    fs::File,
-    io::Write,
+    fs::{
+        self,
+        File,
+    },
+    future::Future,
+    io::{
+        ErrorKind,
+        Write,
+    },
     path::PathBuf,
+    str::FromStr,
     sync::Arc,
     time::Duration,
 };
@@ -9,7 +17,11 @@ use std::{
 use accesskit::NodeId as AccessibilityId;
 use dioxus_core::VirtualDom;
 use freya_core::{
-    accessibility::AccessibilityTree,
+    accessibility::{
+        audit_accessibility,
+        AccessibilityFinding,
+        AccessibilityTree,
+    },
     dom::SafeDOM,
     event_loop_messages::{
         EventLoopMessage,
@@ -18,8 +30,11 @@ use freya_core::{
     events::{
         EventsExecutorAdapter,
         EventsMeasurerAdapter,
+        KeyboardEventName,
         MouseEventName,
         PlatformEvent,
+        TouchEventName,
+        WheelEventName,
     },
     layout::process_layout,
     platform::CursorIcon,
@@ -38,13 +53,26 @@ use freya_core::{
     },
     values::Color,
 };
-use freya_elements::MouseButton;
+use freya_elements::{
+    events::keyboard::{
+        Code,
+        Key,
+        Modifiers,
+    },
+    MouseButton,
+    TouchPhase,
+};
 use freya_engine::prelude::{
+    raster_from_data,
     raster_n32_premul,
+    ColorType,
     Data,
     EncodedImageFormat,
     FontCollection,
     FontMgr,
+    Image,
+    ImageInfo,
+    Surface,
 };
 use freya_native_core::{
     prelude::NodeImmutable,
@@ -56,6 +84,7 @@ use ragnarok::{
     NodesState,
 };
 use tokio::{
+    select,
     sync::{
         broadcast,
         mpsc::{
@@ -63,7 +92,9 @@ use tokio::{
             UnboundedSender,
         },
     },
+    task::yield_now,
     time::{
+        advance,
         interval,
         timeout,
     },
@@ -78,11 +109,36 @@ use torin::{
 
 use crate::{
     config::TestingConfig,
+    locator::{
+        dump_dom,
+        Locator,
+    },
+    snapshot::{
+        actual_path,
+        compare_pixels,
+        diff_path,
+        should_update_snapshots,
+        SnapshotDiff,
+        SnapshotError,
+        SnapshotTolerance,
+    },
     test_node::TestNode,
     test_utils::TestUtils,
     SCALE_FACTOR,
 };
 
+/// Duration of a frame when advancing the virtual clock in [`TestingHandler::run_until_idle`].
+pub const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+/// Maximum amount of frames that [`TestingHandler::run_until_idle`] will run.
+pub const MAX_IDLE_FRAMES: usize = 10_000;
+
+/// How long snapshots wait between renders for the images decoded in background.
+const IMAGE_DECODING_WAIT: Duration = Duration::from_millis(5);
+
+/// Maximum amount of times that snapshots wait for the images decoded in background.
+const MAX_IMAGE_DECODING_WAITS: usize = 1_000;
+
 /// Manages the lifecycle of your tests.
 pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) vdom: VirtualDom,
@@ -160,13 +216,21 @@ impl<T: 'static + Clone> TestingHandler<T> {
 
     /// Apply the latest changes of the virtual dom.
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
+        let (must_repaint, must_relayout, _) = self.update().await;
+        (must_repaint, must_relayout)
+    }
+
+    /// Apply the latest changes of the virtual dom, also returning whether it was idle,
+    /// that is, no work was pending and no animation frame was requested.
+    async fn update(&mut self) -> (bool, bool, bool) {
         self.wait_for_work(self.config.size());
 
-        let mut ticker = if self.config.event_loop_ticker {
+        let mut ticker = if self.config.event_loop_ticker && !self.config.virtual_time {
             Some(interval(Duration::from_millis(16)))
         } else {
             None
         };
+        let mut is_idle = true;
 
         // Handle platform and VDOM events
         loop {
@@ -180,12 +244,11 @@ impl<T: 'static + Clone> TestingHandler<T> {
             if let Ok(message) = platform_ev {
                 match message.action {
                     EventLoopMessageAction::RequestRerender => {
+                        is_idle = false;
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
-                            timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
-                                .await
-                                .ok();
+                            self.wait_for_vdom_work().await;
                         }
                     }
                     EventLoopMessageAction::FocusAccessibilityNode(strategy) => {
@@ -204,20 +267,22 @@ impl<T: 'static + Clone> TestingHandler<T> {
             }
 
             if let Ok(processed_events) = vdom_events {
+                is_idle = false;
                 let sdom = self.utils.sdom();
                 let fdom = sdom.get();
                 let rdom = fdom.rdom();
                 let events_executor_adapter = EventsExecutorAdapter {
                     rdom,
                     vdom: &mut self.vdom,
+                    report_emitted_events: false,
                 };
                 events_executor_adapter.run(&mut self.nodes_state, processed_events);
             }
         }
 
-        timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
-            .await
-            .ok();
+        if self.wait_for_vdom_work().await {
+            is_idle = false;
+        }
 
         let (must_repaint, must_relayout) = self
             .utils
@@ -229,7 +294,84 @@ impl<T: 'static + Clone> TestingHandler<T> {
 
         self.ticker_sender.send(()).unwrap();
 
-        (must_repaint, must_relayout)
+        (must_repaint, must_relayout, is_idle)
+    }
+
+    /// Wait for the VirtualDOM to have pending work, returning whether it had any.
+    ///
+    /// With a virtual clock the pending tasks only get a chance to run, as waiting for a timeout would advance the clock.
+    async fn wait_for_vdom_work(&mut self) -> bool {
+        if self.config.virtual_time {
+            select! {
+                biased;
+                _ = self.vdom.wait_for_work() => true,
+                _ = yield_now() => false,
+            }
+        } else {
+            timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
+                .await
+                .is_ok()
+        }
+    }
+
+    /// Advance the virtual clock by the given duration and apply the changes it caused,
+    /// so animations move exactly that much and tokio timers that were due fire.
+    ///
+    /// Pending work is applied before advancing the clock so the new tasks, like just started animations, count the time from now.
+    /// Requires [`TestingConfig::virtual_time`].
+    ///
+    /// ```rust, no_run
+    /// # use std::time::Duration;
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # fn app() -> Element { rsx!( rect { } ) }
+    /// let mut utils = launch_test_with_config(
+    ///     app,
+    ///     TestingConfig::<()> {
+    ///         virtual_time: true,
+    ///         ..TestingConfig::default()
+    ///     },
+    /// );
+    /// utils.wait_for_update().await;
+    ///
+    /// utils.advance_time(Duration::from_millis(100)).await;
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn advance_time(&mut self, duration: Duration) -> impl Future<Output = ()> + '_ {
+        assert!(
+            self.config.virtual_time,
+            "advance_time requires TestingConfig::virtual_time"
+        );
+        async move {
+            self.wait_for_update().await;
+            advance(duration).await;
+            self.wait_for_update().await;
+        }
+    }
+
+    /// Advance the virtual clock one frame at a time until the VirtualDOM has no pending work and no animation requests a new frame.
+    /// Timers that are due after that are not awaited, use [`TestingHandler::advance_time`] for those.
+    ///
+    /// Will panic if the app is still busy after [`MAX_IDLE_FRAMES`] frames, e.g. because of an infinite animation.
+    /// Requires [`TestingConfig::virtual_time`].
+    #[track_caller]
+    pub fn run_until_idle(&mut self) -> impl Future<Output = ()> + '_ {
+        assert!(
+            self.config.virtual_time,
+            "run_until_idle requires TestingConfig::virtual_time"
+        );
+        async move {
+            for _ in 0..MAX_IDLE_FRAMES {
+                let (must_repaint, must_relayout, is_idle) = self.update().await;
+                if is_idle && !must_repaint && !must_relayout {
+                    return;
+                }
+                advance(FRAME_DURATION).await;
+            }
+            panic!("The app was still busy after {MAX_IDLE_FRAMES} frames");
+        }
     }
 
     /// Wait for layout and events to be processed
@@ -243,6 +385,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
         let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
         let mut compositor_dirty_nodes = fdom.compositor_dirty_nodes();
         let mut compositor_dirty_area = fdom.compositor_dirty_area();
+        let mut frame_stats = fdom.frame_stats();
 
         // Process layout
         process_layout(
@@ -252,6 +395,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             &mut dirty_accessibility_tree,
             &mut compositor_dirty_nodes,
             &mut compositor_dirty_area,
+            &mut frame_stats,
             Area {
                 origin: (0.0, 0.0).into(),
                 size,
@@ -327,6 +471,63 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .get(0)
     }
 
+    /// Create a [`Locator`] to find nodes anywhere in the DOM.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( label { "Hello" } ));
+    /// let label = utils.locator().tag("label").text("Hello").only();
+    /// ```
+    pub fn locator(&self) -> Locator {
+        let root_id = {
+            let sdom = self.utils.sdom();
+            let fdom = sdom.get();
+            let rdom = fdom.rdom();
+            rdom.root_id()
+        };
+
+        Locator::new(self.utils.clone(), root_id)
+    }
+
+    /// Look for common accessibility mistakes in the app, see [`audit_accessibility`].
+    /// Texts are checked over the [`TestingConfig::background`].
+    pub fn audit_accessibility(&self) -> Vec<AccessibilityFinding> {
+        let sdom = self.utils.sdom();
+        let fdom = sdom.get();
+        audit_accessibility(fdom.rdom(), self.config.background)
+    }
+
+    /// Assert that the app has no accessibility issues. Will panic with the findings and a dump of the DOM otherwise.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.wait_for_update().await;
+    /// utils.assert_accessible();
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn assert_accessible(&self) {
+        let findings = self.audit_accessibility();
+        if !findings.is_empty() {
+            let sdom = self.utils.sdom();
+            let fdom = sdom.get();
+            let rdom = fdom.rdom();
+            let findings = findings
+                .iter()
+                .map(|finding| format!("- {finding}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            panic!(
+                "Found accessibility issues:\n{findings}\n\n{}",
+                dump_dom(rdom, rdom.root_id())
+            );
+        }
+    }
+
     /// Resize the simulated canvas.
     ///
     /// ```rust, no_run
@@ -357,19 +558,48 @@ impl<T: 'static + Clone> TestingHandler<T> {
     /// utils.save_snapshot("./snapshot.png");
     /// ```
     pub fn create_snapshot(&mut self) -> Data {
+        let mut surface = self.render_snapshot();
+        encode_surface(&mut surface)
+    }
+
+    /// Render the app into a raster surface.
+    ///
+    /// Images are decoded in background, so this renders again until they are all decoded (or fail to),
+    /// to make snapshots of the same app always look the same.
+    fn render_snapshot(&mut self) -> Surface {
+        let mut surface = self.render_surface();
+        for _ in 0..MAX_IMAGE_DECODING_WAITS {
+            let is_decoding = self
+                .utils
+                .sdom()
+                .get()
+                .images_cache()
+                .decoder()
+                .is_decoding();
+            if !is_decoding {
+                break;
+            }
+            // The decoder threads don't depend on the tokio clock, so this waits in real time
+            std::thread::sleep(IMAGE_DECODING_WAIT);
+            surface = self.render_surface();
+        }
+        surface
+    }
+
+    fn render_surface(&mut self) -> Surface {
         let fdom = self.utils.sdom.get();
         let (width, height) = self.config.size.to_i32().to_tuple();
 
         // Create the main surface
         let mut surface =
             raster_n32_premul((width, height)).expect("Failed to create the surface.");
-        surface.canvas().clear(Color::WHITE);
+        surface.canvas().clear(self.config.background);
 
         // Create the dirty surface
         let mut dirty_surface = surface
             .new_surface_with_dimensions((width, height))
             .expect("Failed to create the dirty surface.");
-        dirty_surface.canvas().clear(Color::WHITE);
+        dirty_surface.canvas().clear(self.config.background);
 
         let mut compositor = Compositor::default();
 
@@ -382,7 +612,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             compositor_cache: &mut fdom.compositor_cache(),
             layers: &mut fdom.layers(),
             layout: &mut fdom.layout(),
-            background: Color::WHITE,
+            background: self.config.background,
             surface: &mut surface,
             dirty_surface: &mut dirty_surface,
             compositor: &mut compositor,
@@ -391,15 +621,12 @@ impl<T: 'static + Clone> TestingHandler<T> {
             font_manager: &self.font_mgr,
             fallback_fonts: &["Fira Sans".to_string()],
             images_cache: &mut fdom.images_cache(),
+            layer_cache: &mut fdom.layer_cache(),
+            frame_stats: &mut fdom.frame_stats(),
         };
         render_pipeline.run();
 
-        // Capture snapshot
-        let image = surface.image_snapshot();
-        let mut context = surface.direct_context();
-        image
-            .encode(context.as_mut(), EncodedImageFormat::PNG, None)
-            .expect("Failed to encode the snapshot.")
+        surface
     }
 
     /// Render the app into a canvas and save it into a file.
@@ -420,6 +647,148 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .expect("Failed to save the snapshot file.");
     }
 
+    /// Render the app and compare it against the golden image in `golden_path`.
+    ///
+    /// When the snapshot does not match, the output is saved next to the golden image with the `.actual.png` extension,
+    /// and an image highlighting the different pixels in red with the `.diff.png` extension.
+    /// If [`UPDATE_SNAPSHOTS_ENV`](crate::snapshot::UPDATE_SNAPSHOTS_ENV) is set the golden image is overwritten instead.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// let diff = utils
+    ///     .compare_snapshot("./snapshots/rect.png", SnapshotTolerance::exact())
+    ///     .unwrap();
+    /// assert_eq!(diff.different_pixels, 0);
+    /// ```
+    pub fn compare_snapshot(
+        &mut self,
+        golden_path: impl Into<PathBuf>,
+        tolerance: SnapshotTolerance,
+    ) -> Result<SnapshotDiff, SnapshotError> {
+        let golden_path = golden_path.into();
+        let actual_path = actual_path(&golden_path);
+        let diff_path = diff_path(&golden_path);
+        let (width, height) = self.config.size.to_i32().to_tuple();
+
+        let mut surface = self.render_snapshot();
+        let actual_data = encode_surface(&mut surface);
+
+        if let Some(dir) = golden_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        if should_update_snapshots() {
+            fs::write(&golden_path, &*actual_data)?;
+            remove_stale_files(&[&actual_path, &diff_path]);
+            return Ok(SnapshotDiff {
+                different_pixels: 0,
+                total_pixels: (width * height) as usize,
+                max_channel_difference: 0,
+            });
+        }
+
+        let golden_data = match fs::read(&golden_path) {
+            Ok(golden_data) => golden_data,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                fs::write(&actual_path, &*actual_data)?;
+                return Err(SnapshotError::MissingGolden {
+                    golden_path,
+                    actual_path,
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let Some(golden) = Image::from_encoded(Data::new_copy(&golden_data)) else {
+            return Err(SnapshotError::InvalidGolden { golden_path });
+        };
+
+        if (golden.width(), golden.height()) != (width, height) {
+            fs::write(&actual_path, &*actual_data)?;
+            return Err(SnapshotError::SizeMismatch {
+                golden_path,
+                actual_path,
+                expected: (golden.width(), golden.height()),
+                actual: (width, height),
+            });
+        }
+
+        // Draw the golden image in the same kind of surface so the pixels share the same layout
+        let mut golden_surface =
+            raster_n32_premul((width, height)).expect("Failed to create the surface.");
+        golden_surface.canvas().clear(Color::WHITE);
+        golden_surface
+            .canvas()
+            .draw_image(&golden, (0.0, 0.0), None);
+
+        let (actual_pixels, is_bgra) = read_pixels(&mut surface);
+        let (golden_pixels, _) = read_pixels(&mut golden_surface);
+        let highlight = if is_bgra {
+            [0, 0, 255, 255]
+        } else {
+            [255, 0, 0, 255]
+        };
+        let (diff, diff_pixels) = compare_pixels(
+            &actual_pixels,
+            &golden_pixels,
+            tolerance.max_channel_difference,
+            highlight,
+        );
+
+        if diff.is_within(&tolerance) {
+            remove_stale_files(&[&actual_path, &diff_path]);
+            return Ok(diff);
+        }
+
+        let diff_image = raster_from_data(
+            &ImageInfo::new_n32_premul((width, height), None),
+            Data::new_copy(&diff_pixels),
+            width as usize * 4,
+        )
+        .expect("Failed to create the diff image.");
+        let diff_data = diff_image
+            .encode(None, EncodedImageFormat::PNG, None)
+            .expect("Failed to encode the diff image.");
+
+        fs::write(&actual_path, &*actual_data)?;
+        fs::write(&diff_path, &*diff_data)?;
+
+        Err(SnapshotError::Mismatch {
+            golden_path,
+            actual_path,
+            diff_path,
+            diff,
+        })
+    }
+
+    /// Assert that the app matches the golden image in `golden_path`, using the tolerance of the [`TestingConfig`].
+    ///
+    /// See [`TestingHandler::compare_snapshot`], or the [`assert_snapshot!`](crate::assert_snapshot!) macro to store the golden images next to the test.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.assert_snapshot("./snapshots/rect.png");
+    /// ```
+    pub fn assert_snapshot(&mut self, golden_path: impl Into<PathBuf>) {
+        let tolerance = self.config.snapshot_tolerance;
+        self.assert_snapshot_with_tolerance(golden_path, tolerance);
+    }
+
+    /// Assert that the app matches the golden image in `golden_path` within the given tolerance.
+    pub fn assert_snapshot_with_tolerance(
+        &mut self,
+        golden_path: impl Into<PathBuf>,
+        tolerance: SnapshotTolerance,
+    ) {
+        if let Err(err) = self.compare_snapshot(golden_path, tolerance) {
+            panic!("{err}");
+        }
+    }
+
     /// Shorthand to simulate a cursor move to the given location.
     ///
     /// ```rust
@@ -459,4 +828,402 @@ impl<T: 'static + Clone> TestingHandler<T> {
         });
         self.wait_for_update().await;
     }
+
+    /// Simulate dragging with the left mouse button pressed from `from` to `to`, moving the cursor in the given amount of steps.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.drag((5., 5.), (100., 5.), 10).await;
+    /// # }
+    /// ```
+    pub async fn drag(
+        &mut self,
+        from: impl Into<CursorPoint>,
+        to: impl Into<CursorPoint>,
+        steps: usize,
+    ) {
+        let from = from.into();
+        let to = to.into();
+
+        self.push_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: from,
+            button: Some(MouseButton::Left),
+        });
+        self.wait_for_update().await;
+
+        for cursor in interpolate(from, to, steps) {
+            self.push_event(PlatformEvent::Mouse {
+                name: MouseEventName::MouseMove,
+                cursor,
+                button: Some(MouseButton::Left),
+            });
+            self.wait_for_update().await;
+        }
+
+        self.push_event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: to,
+            button: Some(MouseButton::Left),
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Simulate scrolling the mouse wheel with the cursor in the given location. A negative `delta.y` scrolls down.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.scroll((5., 5.), (0., -100.)).await;
+    /// # }
+    /// ```
+    pub async fn scroll(&mut self, cursor: impl Into<CursorPoint>, delta: impl Into<CursorPoint>) {
+        self.push_event(PlatformEvent::Wheel {
+            name: WheelEventName::Wheel,
+            scroll: delta.into(),
+            cursor: cursor.into(),
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Simulate pressing and releasing a key with the given modifiers.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.press_key(Key::Enter, Modifiers::empty()).await;
+    /// # }
+    /// ```
+    pub async fn press_key(&mut self, key: Key, modifiers: Modifiers) {
+        self.send_key(KeyboardEventName::KeyDown, key.clone(), modifiers)
+            .await;
+        self.send_key(KeyboardEventName::KeyUp, key, modifiers)
+            .await;
+    }
+
+    async fn send_key(&mut self, name: KeyboardEventName, key: Key, modifiers: Modifiers) {
+        self.push_event(PlatformEvent::Keyboard {
+            name,
+            code: code_for_key(&key),
+            key,
+            modifiers,
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Simulate typing a text, one key at a time.
+    /// Characters that need shift in a US layout, such as uppercase letters or `!`, are typed while holding the shift key.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.type_text("Hello, World!\n").await;
+    /// # }
+    /// ```
+    pub async fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            let key = match character {
+                '\n' => Key::Enter,
+                '\t' => Key::Tab,
+                character => Key::Character(character.to_string()),
+            };
+            if unshifted_character(character).is_some() {
+                self.send_key(KeyboardEventName::KeyDown, Key::Shift, Modifiers::SHIFT)
+                    .await;
+                self.press_key(key, Modifiers::SHIFT).await;
+                self.send_key(KeyboardEventName::KeyUp, Key::Shift, Modifiers::SHIFT)
+                    .await;
+            } else {
+                self.press_key(key, Modifiers::empty()).await;
+            }
+        }
+    }
+
+    /// Simulate a keyboard shortcut such as `Ctrl+Shift+Z`.
+    ///
+    /// The modifier keys are pressed in order, then the key, and then they are all released in reverse order.
+    /// Supported modifiers are `Ctrl`, `Shift`, `Alt` and `Meta` (or `Cmd`), the key is either a character or a named key such as `Enter`.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.press_shortcut("Ctrl+Shift+Z").await;
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn press_shortcut(&mut self, shortcut: &str) -> impl Future<Output = ()> + '_ {
+        let (modifier_keys, key) = parse_shortcut(shortcut);
+        async move {
+            let mut modifiers = Modifiers::empty();
+            let mut events = Vec::new();
+
+            for (modifier_key, modifier) in &modifier_keys {
+                modifiers.insert(*modifier);
+                events.push((KeyboardEventName::KeyDown, modifier_key.clone(), modifiers));
+            }
+            events.push((KeyboardEventName::KeyDown, key.clone(), modifiers));
+            events.push((KeyboardEventName::KeyUp, key, modifiers));
+            for (modifier_key, modifier) in modifier_keys.iter().rev() {
+                events.push((KeyboardEventName::KeyUp, modifier_key.clone(), modifiers));
+                modifiers.remove(*modifier);
+            }
+
+            for (name, key, modifiers) in events {
+                self.send_key(name, key, modifiers).await;
+            }
+        }
+    }
+
+    /// Simulate a touch event of a finger in the given location.
+    pub async fn touch(
+        &mut self,
+        finger_id: u64,
+        location: impl Into<CursorPoint>,
+        phase: TouchPhase,
+    ) {
+        let name = match phase {
+            TouchPhase::Started => TouchEventName::TouchStart,
+            TouchPhase::Moved => TouchEventName::TouchMove,
+            TouchPhase::Ended => TouchEventName::TouchEnd,
+            TouchPhase::Cancelled => TouchEventName::TouchCancel,
+        };
+        self.push_event(PlatformEvent::Touch {
+            name,
+            location: location.into(),
+            finger_id,
+            phase,
+            force: None,
+        });
+        self.wait_for_update().await;
+    }
+
+    /// Simulate tapping with a finger in the given location.
+    pub async fn tap(&mut self, location: impl Into<CursorPoint>) {
+        let location = location.into();
+        self.touch(0, location, TouchPhase::Started).await;
+        self.touch(0, location, TouchPhase::Ended).await;
+    }
+
+    /// Simulate a gesture with one or more fingers at the same time, each one moving from its start to its end location in the given amount of steps.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// // Two fingers swiping to the right
+    /// utils
+    ///     .touch_gesture(
+    ///         &[
+    ///             ((50., 50.).into(), (200., 50.).into()),
+    ///             ((50., 100.).into(), (200., 100.).into()),
+    ///         ],
+    ///         5,
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn touch_gesture(&mut self, fingers: &[(CursorPoint, CursorPoint)], steps: usize) {
+        for (finger_id, (from, _)) in fingers.iter().enumerate() {
+            self.touch(finger_id as u64, *from, TouchPhase::Started)
+                .await;
+        }
+
+        let paths = fingers
+            .iter()
+            .map(|(from, to)| interpolate(*from, *to, steps))
+            .collect::<Vec<_>>();
+        for step in 0..steps {
+            for (finger_id, path) in paths.iter().enumerate() {
+                self.push_event(PlatformEvent::Touch {
+                    name: TouchEventName::TouchMove,
+                    location: path[step],
+                    finger_id: finger_id as u64,
+                    phase: TouchPhase::Moved,
+                    force: None,
+                });
+            }
+            self.wait_for_update().await;
+        }
+
+        for (finger_id, (_, to)) in fingers.iter().enumerate() {
+            self.touch(finger_id as u64, *to, TouchPhase::Ended).await;
+        }
+    }
+
+    /// Simulate pinching with two fingers placed horizontally around `center`, going from `start_distance` to `end_distance` between them.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// // Zoom in
+    /// utils.pinch((250., 250.), 100., 200., 5).await;
+    /// # }
+    /// ```
+    pub async fn pinch(
+        &mut self,
+        center: impl Into<CursorPoint>,
+        start_distance: f64,
+        end_distance: f64,
+        steps: usize,
+    ) {
+        let center = center.into();
+        let finger =
+            |distance: f64, side: f64| CursorPoint::new(center.x + distance / 2.0 * side, center.y);
+        self.touch_gesture(
+            &[
+                (finger(start_distance, -1.0), finger(end_distance, -1.0)),
+                (finger(start_distance, 1.0), finger(end_distance, 1.0)),
+            ],
+            steps,
+        )
+        .await;
+    }
+}
+
+/// Encode the content of the surface as a PNG.
+fn encode_surface(surface: &mut Surface) -> Data {
+    let image = surface.image_snapshot();
+    let mut context = surface.direct_context();
+    image
+        .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+        .expect("Failed to encode the snapshot.")
+}
+
+/// Read the pixels of the surface without any row padding, and whether they are in the BGRA order.
+fn read_pixels(surface: &mut Surface) -> (Vec<u8>, bool) {
+    let pixmap = surface
+        .peek_pixels()
+        .expect("Failed to read the snapshot pixels.");
+    let is_bgra = pixmap.color_type() == ColorType::BGRA8888;
+    let width = pixmap.width() as usize;
+    let bytes = pixmap.bytes().expect("Failed to read the snapshot pixels.");
+
+    let pixels = bytes
+        .chunks_exact(pixmap.row_bytes())
+        .flat_map(|row| &row[..width * 4])
+        .copied()
+        .collect();
+
+    (pixels, is_bgra)
+}
+
+/// Remove the outputs of a previous failed snapshot.
+fn remove_stale_files(paths: &[&PathBuf]) {
+    for path in paths {
+        fs::remove_file(path).ok();
+    }
+}
+
+/// Points between `from` and `to` in the given amount of steps, `to` included.
+fn interpolate(from: CursorPoint, to: CursorPoint, steps: usize) -> Vec<CursorPoint> {
+    let steps = steps.max(1);
+    (1..=steps)
+        .map(|step| from.lerp(to, step as f64 / steps as f64))
+        .collect()
+}
+
+/// Physical key most likely to produce the given key in a US layout.
+fn code_for_key(key: &Key) -> Code {
+    match key {
+        Key::Character(character) => {
+            let mut chars = character
+                .chars()
+                .map(|character| unshifted_character(character).unwrap_or(character));
+            match (chars.next(), chars.next()) {
+                (Some(' '), None) => Code::Space,
+                (Some(character), None) if character.is_ascii_alphabetic() => {
+                    Code::from_str(&format!("Key{}", character.to_ascii_uppercase()))
+                        .unwrap_or(Code::Unidentified)
+                }
+                (Some(character), None) if character.is_ascii_digit() => {
+                    Code::from_str(&format!("Digit{character}")).unwrap_or(Code::Unidentified)
+                }
+                (Some('-'), None) => Code::Minus,
+                (Some('='), None) => Code::Equal,
+                (Some('['), None) => Code::BracketLeft,
+                (Some(']'), None) => Code::BracketRight,
+                (Some('\\'), None) => Code::Backslash,
+                (Some(';'), None) => Code::Semicolon,
+                (Some('\''), None) => Code::Quote,
+                (Some(','), None) => Code::Comma,
+                (Some('.'), None) => Code::Period,
+                (Some('/'), None) => Code::Slash,
+                (Some('`'), None) => Code::Backquote,
+                _ => Code::Unidentified,
+            }
+        }
+        Key::Shift => Code::ShiftLeft,
+        Key::Control => Code::ControlLeft,
+        Key::Alt => Code::AltLeft,
+        Key::Meta => Code::MetaLeft,
+        key => Code::from_str(&key.to_string()).unwrap_or(Code::Unidentified),
+    }
+}
+
+/// Character typed by the same key without shift in a US layout, if the given one needs shift.
+fn unshifted_character(character: char) -> Option<char> {
+    const SHIFTED: &str = "!@#$%^&*()_+{}|:\"<>?~";
+    const UNSHIFTED: &str = "1234567890-=[]\\;',./`";
+
+    if character.is_uppercase() {
+        character.to_lowercase().next()
+    } else {
+        SHIFTED
+            .chars()
+            .position(|shifted| shifted == character)
+            .and_then(|index| UNSHIFTED.chars().nth(index))
+    }
+}
+
+/// Split a shortcut such as `Ctrl+Shift+Z` into its modifier keys and its main key.
+#[track_caller]
+fn parse_shortcut(shortcut: &str) -> (Vec<(Key, Modifiers)>, Key) {
+    let mut parts = shortcut.split('+').map(str::trim).collect::<Vec<_>>();
+    let key = parts
+        .pop()
+        .filter(|key| !key.is_empty())
+        .unwrap_or_else(|| panic!("Missing key in shortcut {shortcut:?}"));
+
+    let modifier_keys = parts
+        .into_iter()
+        .map(|modifier| match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => (Key::Control, Modifiers::CONTROL),
+            "shift" => (Key::Shift, Modifiers::SHIFT),
+            "alt" | "option" => (Key::Alt, Modifiers::ALT),
+            "meta" | "cmd" | "super" => (Key::Meta, Modifiers::META),
+            _ => panic!("Unknown modifier {modifier:?} in shortcut {shortcut:?}"),
+        })
+        .collect::<Vec<_>>();
+    let is_shift = modifier_keys
+        .iter()
+        .any(|(_, modifier)| *modifier == Modifiers::SHIFT);
+
+    let key = if key.chars().count() == 1 {
+        // Letters are uppercase while shift is pressed
+        let key = if is_shift {
+            key.to_uppercase()
+        } else {
+            key.to_lowercase()
+        };
+        Key::Character(key)
+    } else {
+        Key::from_str(key)
+            .unwrap_or_else(|_| panic!("Unknown key {key:?} in shortcut {shortcut:?}"))
+    };
+
+    (modifier_keys, key)
 }
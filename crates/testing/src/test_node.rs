@@ -13,10 +13,16 @@ use freya_native_core::{
 };
 use torin::{
     geometry::Area,
-    prelude::LayoutNode,
+    prelude::{
+        CursorPoint,
+        LayoutNode,
+    },
 };
 
-use crate::test_utils::TestUtils;
+use crate::{
+    locator::Locator,
+    test_utils::TestUtils,
+};
 
 /// Represents a `Node` in the DOM.
 #[derive(Clone)]
@@ -70,6 +76,16 @@ impl TestNode {
         self.layout().map(|l| l.area)
     }
 
+    /// Get the center of the Node layout Area, useful to target it with the cursor or a finger.
+    /// Will panic if the Node has no layout.
+    #[track_caller]
+    pub fn center(&self) -> CursorPoint {
+        self.area()
+            .unwrap_or_else(|| panic!("Node {:?} has no layout", self.node_id))
+            .center()
+            .to_f64()
+    }
+
     /// Get the Node style
     pub fn style(&self) -> StyleState {
         self.utils
@@ -88,6 +104,11 @@ impl TestNode {
         &self.utils
     }
 
+    /// Get the Node ID
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Get the NodeId from the parent
     pub fn parent_id(&self) -> Option<NodeId> {
         let sdom = self.utils().sdom();
@@ -138,6 +159,11 @@ impl TestNode {
         self.node_type.is_placeholder()
     }
 
+    /// Create a [`Locator`] to find nodes inside this Node, including itself.
+    pub fn locator(&self) -> Locator {
+        Locator::new(self.utils.clone(), self.node_id)
+    }
+
     /// Get a descendant Node of this Node that matches a certain text.
     pub fn get_by_text(&self, matching_text: &str) -> Option<Self> {
         self.utils()
@@ -83,7 +83,7 @@ impl TestUtils {
                 let node_type = node.node_type().clone();
 
                 nodes.push(TestNode {
-                    node_id,
+                    node_id: node.id(),
                     utils,
                     children_ids,
                     height,
@@ -0,0 +1,188 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn type_text() {
+    fn input_app() -> Element {
+        let mut value = use_signal(String::new);
+
+        rsx!(
+            Input {
+                value,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            }
+            label {
+                "{value}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(input_app);
+    utils.wait_for_update().await;
+
+    // Focus the input by clicking in its center
+    let input = utils.locator().role("text-input").only();
+    utils.click_cursor(input.center()).await;
+    utils.wait_for_update().await;
+
+    utils.type_text("Hello World 42").await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(label.get(0).text(), Some("Hello World 42"));
+}
+
+fn describe(e: &KeyboardEvent) -> String {
+    format!(
+        "{} {:?} ctrl={} shift={}",
+        e.key,
+        e.code,
+        e.modifiers.ctrl(),
+        e.modifiers.shift()
+    )
+}
+
+fn keys_app() -> Element {
+    let mut events = use_signal(Vec::<String>::new);
+    let text = events.read().join("\n");
+
+    rsx!(
+        rect {
+            onglobalkeydown: move |e: KeyboardEvent| {
+                events.push(format!("down {}", describe(&e)));
+            },
+            onglobalkeyup: move |e: KeyboardEvent| {
+                events.push(format!("up {}", describe(&e)));
+            },
+            label {
+                "{text}"
+            }
+        }
+    )
+}
+
+#[tokio::test]
+async fn type_shifted_characters() {
+    let mut utils = launch_test(keys_app);
+    utils.wait_for_update().await;
+
+    utils.type_text("aA!").await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            [
+                "down a KeyA ctrl=false shift=false",
+                "up a KeyA ctrl=false shift=false",
+                "down Shift ShiftLeft ctrl=false shift=true",
+                "down A KeyA ctrl=false shift=true",
+                "up A KeyA ctrl=false shift=true",
+                "up Shift ShiftLeft ctrl=false shift=true",
+                "down Shift ShiftLeft ctrl=false shift=true",
+                "down ! Digit1 ctrl=false shift=true",
+                "up ! Digit1 ctrl=false shift=true",
+                "up Shift ShiftLeft ctrl=false shift=true",
+            ]
+            .join("\n")
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+async fn press_shortcut() {
+    let mut utils = launch_test(keys_app);
+    utils.wait_for_update().await;
+
+    utils.press_shortcut("Ctrl+Shift+Z").await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            [
+                "down Control ControlLeft ctrl=true shift=false",
+                "down Shift ShiftLeft ctrl=true shift=true",
+                "down Z KeyZ ctrl=true shift=true",
+                "up Z KeyZ ctrl=true shift=true",
+                "up Shift ShiftLeft ctrl=true shift=true",
+                "up Control ControlLeft ctrl=true shift=false",
+            ]
+            .join("\n")
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+async fn drag_and_scroll() {
+    fn pointer_app() -> Element {
+        let mut moves = use_signal(|| 0);
+        let mut released_at = use_signal(|| None);
+        let mut scrolled = use_signal(|| 0.);
+
+        let text = format!("{moves} {:?} {scrolled}", released_at());
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onmousemove: move |_| moves += 1,
+                onmouseup: move |e: MouseEvent| released_at.set(Some(e.get_screen_coordinates())),
+                onwheel: move |e: WheelEvent| scrolled += e.get_delta_y(),
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(pointer_app);
+    utils.wait_for_update().await;
+
+    utils.drag((10., 10.), (110., 60.), 4).await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(label.get(0).text(), Some("4 Some((110.0, 60.0)) 0"));
+
+    let rect = utils.locator().tag("rect").first();
+    utils.scroll(rect.center(), (0., -50.)).await;
+    utils.scroll(rect.center(), (0., -50.)).await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(label.get(0).text(), Some("4 Some((110.0, 60.0)) -100"));
+}
+
+#[tokio::test]
+async fn pinch() {
+    fn pinch_app() -> Element {
+        let mut value = use_signal(|| "EMPTY".to_string());
+
+        rsx!(
+            GestureArea {
+                ongesture: move |e: Gesture| {
+                    if let Gesture::Pinch { scale, .. } = e {
+                        value.set(format!("{scale:.2}"));
+                    }
+                },
+                rect {
+                    width: "fill",
+                    height: "fill",
+                }
+            }
+            label {
+                "{value}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(pinch_app);
+    utils.wait_for_update().await;
+
+    utils.pinch((250., 250.), 100., 200., 5).await;
+
+    let label = utils.locator().tag("label").only();
+    assert_eq!(label.get(0).text(), Some("2.00"));
+}
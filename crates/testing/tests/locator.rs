@@ -0,0 +1,113 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn counter_app() -> Element {
+    let mut count = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            rect {
+                a11y_role: "button",
+                a11y_name: "Increase",
+                width: "100",
+                height: "50",
+                onclick: move |_| count += 1,
+                label {
+                    "+"
+                }
+            }
+            rect {
+                a11y_role: "button",
+                a11y_name: "Decrease",
+                width: "100",
+                height: "50",
+                onclick: move |_| count -= 1,
+                label {
+                    "-"
+                }
+            }
+            label {
+                "Count: {count}"
+            }
+            paragraph {
+                text {
+                    "Hello, "
+                }
+                text {
+                    "World"
+                }
+            }
+        }
+    )
+}
+
+#[tokio::test]
+async fn locate_nodes() {
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    // By role
+    assert_eq!(utils.locator().role("button").count(), 2);
+
+    // By role and name
+    let increase = utils.locator().role("button").a11y_name("Increase").only();
+    assert_eq!(increase.area().unwrap().min_y(), 0.0);
+
+    // By text
+    let counter = utils.locator().tag("label").text("Count: 0").only();
+    assert_eq!(counter.get(0).text(), Some("Count: 0"));
+    assert!(utils.locator().text("Hello, World").exists());
+    assert!(utils
+        .locator()
+        .text(TextMatch::Contains("World".to_string()))
+        .exists());
+
+    // Inside a node
+    let minus = utils
+        .locator()
+        .a11y_name("Decrease")
+        .only()
+        .locator()
+        .tag("label")
+        .only();
+    assert_eq!(minus.get(0).text(), Some("-"));
+}
+
+#[tokio::test]
+async fn locators_are_resolved_after_updates() {
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    let counter = utils
+        .locator()
+        .tag("label")
+        .text(Regex::new(r"^Count: \d+$").unwrap());
+    let increase = utils.locator().role("button").a11y_name("Increase");
+
+    assert_eq!(counter.only().get(0).text(), Some("Count: 0"));
+
+    let center = increase.only().area().unwrap().center().to_f64();
+    utils.click_cursor(center).await;
+
+    assert_eq!(counter.only().get(0).text(), Some("Count: 1"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "No node found for role = Slider")]
+async fn missing_node_panics_with_dump() {
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    utils.locator().role("slider").first();
+}
+
+#[tokio::test]
+async fn dump_dom() {
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    let dump = utils.locator().role("button").a11y_name("Increase").dump();
+    assert!(dump.contains("rect [id: "));
+    assert!(dump.contains("role: Button, a11y_name: \"Increase\""));
+    assert!(dump.contains("\"Count: 0\""));
+}
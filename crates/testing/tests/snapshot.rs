@@ -0,0 +1,83 @@
+use std::fs;
+
+use freya::prelude::*;
+use freya_testing::{
+    prelude::*,
+    snapshot::golden_path,
+};
+
+#[tokio::test]
+async fn compare_snapshots() {
+    fn snapshot_app() -> Element {
+        let mut enabled = use_signal(|| false);
+        let background = if enabled() { "red" } else { "blue" };
+
+        rsx!(rect {
+            width: "50%",
+            height: "50%",
+            background,
+            onclick: move |_| enabled.toggle(),
+        })
+    }
+
+    let dir = std::env::temp_dir().join("freya-testing-snapshots");
+    fs::remove_dir_all(&dir).ok();
+    let golden = dir.join("rect.png");
+
+    let mut utils = launch_test(snapshot_app);
+    utils.wait_for_update().await;
+
+    // There is no golden image yet
+    let result = utils.compare_snapshot(&golden, SnapshotTolerance::exact());
+    assert!(matches!(result, Err(SnapshotError::MissingGolden { .. })));
+    assert!(dir.join("rect.actual.png").exists());
+
+    // Accept the output as the golden image
+    fs::rename(dir.join("rect.actual.png"), &golden).unwrap();
+    let diff = utils
+        .compare_snapshot(&golden, SnapshotTolerance::exact())
+        .unwrap();
+    assert_eq!(diff.different_pixels, 0);
+    assert_eq!(diff.total_pixels, 500 * 500);
+
+    // A quarter of the pixels change
+    utils.click_cursor((5., 5.)).await;
+    let result = utils.compare_snapshot(&golden, SnapshotTolerance::exact());
+    let Err(SnapshotError::Mismatch { diff, .. }) = result else {
+        panic!("Expected a mismatch");
+    };
+    assert_eq!(diff.percentage(), 25.0);
+    assert!(dir.join("rect.actual.png").exists());
+    assert!(dir.join("rect.diff.png").exists());
+
+    // Which is accepted with a higher tolerance
+    utils
+        .compare_snapshot(
+            &golden,
+            SnapshotTolerance {
+                max_channel_difference: 0,
+                max_different_pixels: 30.0,
+            },
+        )
+        .unwrap();
+    assert!(!dir.join("rect.diff.png").exists());
+
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn golden_path_next_to_the_test() {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+
+    // Relative to the crate
+    assert_eq!(
+        golden_path(manifest_dir, "tests/snapshot.rs", "button"),
+        std::path::Path::new(manifest_dir).join("tests/snapshots/button.png")
+    );
+
+    // Relative to the workspace
+    assert_eq!(
+        golden_path(manifest_dir, "crates/testing/tests/snapshot.rs", "button"),
+        std::path::Path::new(manifest_dir).join("tests/snapshots/button.png")
+    );
+}
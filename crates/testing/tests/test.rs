@@ -130,3 +130,24 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn matched_nodes_keep_their_id() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                label {
+                    "Hello, Rust!"
+                }
+            }
+        )
+    }
+
+    let utils = launch_test(app);
+    let root = utils.root();
+
+    // The matched node is the text node itself, not the node where the search started
+    let text = root.get_by_text("Hello, Rust!").unwrap();
+    assert_ne!(text.id(), root.id());
+    assert_eq!(text.id(), root.get(0).get(0).get(0).id());
+}
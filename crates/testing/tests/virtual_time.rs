@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+fn launch_virtual(app: fn() -> Element) -> TestingHandler<()> {
+    launch_test_with_config(
+        app,
+        TestingConfig {
+            virtual_time: true,
+            ..TestingConfig::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn timers_follow_the_virtual_clock() {
+    fn delayed_app() -> Element {
+        let mut is_ready = use_signal(|| false);
+
+        use_hook(|| {
+            spawn(async move {
+                sleep(Duration::from_secs(60)).await;
+                is_ready.set(true);
+            })
+        });
+
+        rsx!(label {
+            "{is_ready}"
+        })
+    }
+
+    let mut utils = launch_virtual(delayed_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("false"));
+
+    utils.advance_time(Duration::from_millis(59_900)).await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("false"));
+
+    utils.advance_time(Duration::from_millis(200)).await;
+    assert_eq!(utils.root().get(0).get(0).text(), Some("true"));
+}
+
+#[tokio::test]
+async fn run_until_idle_finishes_animations() {
+    fn animated_app() -> Element {
+        let animation = use_animation(|conf| {
+            conf.on_creation(OnCreation::Run);
+            AnimNum::new(0., 100.).time(1000)
+        });
+        let width = animation.get().read().read();
+
+        rsx!(rect { width: "{width}" })
+    }
+
+    let mut utils = launch_virtual(animated_app);
+    utils.wait_for_update().await;
+
+    utils.run_until_idle().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+}
+
+#[tokio::test]
+#[should_panic(expected = "advance_time requires TestingConfig::virtual_time")]
+async fn advance_time_requires_virtual_time() {
+    let mut utils = launch_test(|| rsx!(rect {}));
+    utils.advance_time(Duration::from_millis(16)).await;
+}
+
+#[tokio::test]
+async fn virtual_time_in_several_handlers() {
+    fn app() -> Element {
+        rsx!(rect {})
+    }
+
+    let mut first = launch_virtual(app);
+    let mut second = launch_virtual(app);
+
+    first.advance_time(Duration::from_millis(16)).await;
+    second.advance_time(Duration::from_millis(16)).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[should_panic(expected = "requires a current-thread tokio runtime")]
+async fn virtual_time_requires_current_thread_runtime() {
+    launch_virtual(|| rsx!(rect {}));
+}
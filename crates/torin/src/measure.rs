@@ -1,3 +1,5 @@
+use std::mem;
+
 pub use euclid::Rect;
 use rustc_hash::FxHashMap;
 
@@ -10,6 +12,7 @@ use crate::{
     },
     geometry::{
         Area,
+        Point2D,
         Size2D,
     },
     node::Node,
@@ -34,6 +37,149 @@ pub enum Phase {
     Final,
 }
 
+/// Line of children of a Node with wrapped content
+struct WrapLine<Key> {
+    /// Children in this line with their main and cross sizes
+    children: Vec<(Key, f32, f32)>,
+    /// Accumulated size in the main axis, including the spacing
+    main_size: f32,
+    /// Biggest size in the cross axis
+    cross_size: f32,
+}
+
+impl<Key> Default for WrapLine<Key> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            main_size: 0.,
+            cross_size: 0.,
+        }
+    }
+}
+
+/// Child of a Node with grid content, placed in its cells
+struct GridCell<Key> {
+    node_id: Key,
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+    /// Size measured in the initial phase
+    size: Size2D,
+    /// Whether its size depends on the size of the cell, in each axis
+    is_cell_sized: (bool, bool),
+}
+
+/// Find the first column and row where a child of the given spans can be placed.
+/// Only automatically placed children move the cursor forward.
+fn place_grid_cell(
+    occupied_cells: &[Vec<bool>],
+    cursor: &mut (usize, usize),
+    columns_len: usize,
+    column_start: Option<usize>,
+    column_span: usize,
+    row_start: Option<usize>,
+    row_span: usize,
+) -> (usize, usize) {
+    let last_column = columns_len - column_span;
+    let is_free = |row: usize, column: usize| {
+        occupied_cells
+            .iter()
+            .skip(row)
+            .take(row_span)
+            .all(|row_cells| !row_cells[column..column + column_span].contains(&true))
+    };
+
+    match (row_start, column_start) {
+        (Some(row), Some(column)) => (row, column.min(last_column)),
+        (Some(row), None) => {
+            let column = (0..=last_column)
+                .find(|column| is_free(row, *column))
+                .unwrap_or_default();
+            (row, column)
+        }
+        (None, Some(column)) => {
+            let column = column.min(last_column);
+            // Rows past the occupied ones are always free
+            let row = (0..=occupied_cells.len())
+                .find(|row| is_free(*row, column))
+                .unwrap_or_default();
+            (row, column)
+        }
+        (None, None) => {
+            let (mut row, mut column) = *cursor;
+            loop {
+                if column > last_column {
+                    row += 1;
+                    column = 0;
+                } else if is_free(row, column) {
+                    break;
+                } else {
+                    column += 1;
+                }
+            }
+            *cursor = (row, column + column_span);
+            (row, column)
+        }
+    }
+}
+
+/// Resolve the sizes of the tracks of a grid in one axis.
+/// Auto tracks take the size of their biggest non-spanning child that isn't sized by the cell itself,
+/// and flex tracks share the remaining space,
+/// unless the grid is sized by its content, in which case they behave as auto tracks.
+fn resolve_grid_tracks(
+    tracks: &[Size],
+    tracks_len: usize,
+    available_length: f32,
+    spacing: f32,
+    is_inner_sized: bool,
+    root_length: f32,
+    children: impl Iterator<Item = (usize, usize, f32)>,
+) -> Vec<f32> {
+    let mut sizes = vec![0.; tracks_len];
+    let mut flex_grows = vec![None; tracks_len];
+    let mut auto_tracks = vec![false; tracks_len];
+
+    for (i, track) in (0..tracks_len).map(|i| (i, tracks.get(i).unwrap_or(&Size::Inner))) {
+        match track {
+            Size::Flex(flex_grow) if !is_inner_sized => flex_grows[i] = Some(flex_grow.get()),
+            Size::Fill if !is_inner_sized => flex_grows[i] = Some(1.),
+            Size::Inner | Size::FillMinimum | Size::Flex(_) | Size::Fill => auto_tracks[i] = true,
+            track => {
+                sizes[i] = track
+                    .eval(
+                        available_length,
+                        available_length,
+                        0.,
+                        root_length,
+                        Phase::Final,
+                    )
+                    .unwrap_or_default();
+            }
+        }
+    }
+
+    for (start, span, size) in children {
+        if span == 1 && auto_tracks[start] {
+            sizes[start] = sizes[start].max(size);
+        }
+    }
+
+    let total_flex_grow = flex_grows.iter().flatten().sum::<f32>();
+    if total_flex_grow > 0. {
+        let used_length = sizes.iter().sum::<f32>() + spacing * tracks_len.saturating_sub(1) as f32;
+        let free_length = (available_length - used_length).max(0.);
+        for (size, flex_grow) in sizes.iter_mut().zip(flex_grows) {
+            if let Some(flex_grow) = flex_grow {
+                *size = free_length / total_flex_grow * flex_grow;
+            }
+        }
+    }
+
+    sizes
+}
+
 pub struct MeasureContext<'a, Key, L, D>
 where
     Key: NodeKey,
@@ -71,6 +217,15 @@ where
         // Current phase of measurement
         phase: Phase,
     ) -> (bool, LayoutNode) {
+        // Right-to-left Nodes are measured as left-to-right ones with their horizontal sides mirrored
+        let mirrored_node;
+        let node = if node.layout_direction.is_rtl() {
+            mirrored_node = node.mirrored();
+            &mirrored_node
+        } else {
+            node
+        };
+
         // 1. If parent is dirty
         // 2. If this Node has been marked as dirty
         // 3. If there is no know cached data about this Node.
@@ -366,7 +521,40 @@ where
         // Parent Node is dirty.
         parent_is_dirty: bool,
     ) {
-        let children = self.dom_adapter.children_of(parent_node_id);
+        let mut children = self.dom_adapter.children_of(parent_node_id);
+
+        if parent_node.content.is_grid() {
+            self.measure_grid_children(
+                children,
+                parent_node,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                area,
+                inner_area,
+                parent_is_dirty,
+            );
+            return;
+        }
+
+        if parent_node.content.is_wrap() {
+            self.measure_wrapped_children(
+                children,
+                parent_node,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                area,
+                inner_area,
+                parent_is_dirty,
+            );
+            return;
+        }
+
+        // Horizontally stacked children of right-to-left Nodes are laid out starting from the right
+        if parent_node.layout_direction.is_rtl() && parent_node.direction == Direction::Horizontal {
+            children.reverse();
+        }
 
         let mut initial_phase_flex_grows = FxHashMap::default();
         let mut initial_phase_sizes = FxHashMap::default();
@@ -648,6 +836,444 @@ where
         }
     }
 
+    /// Measure the children layouts of a Node whose content wraps into multiple lines
+    #[allow(clippy::too_many_arguments)]
+    fn measure_wrapped_children(
+        &mut self,
+        children: Vec<Key>,
+        parent_node: &Node,
+        // Area available inside the Node
+        available_area: &Area,
+        // Accumulated sizes in both axis in the Node
+        inner_sizes: &mut Size2D,
+        // Whether to cache the measurements of this Node's children
+        must_cache_children: bool,
+        // Parent area.
+        area: &mut Area,
+        // Inner area of the parent.
+        inner_area: &mut Area,
+        // Parent Node is dirty.
+        parent_is_dirty: bool,
+    ) {
+        let spacing = parent_node.spacing.get();
+        let wrap_spacing = parent_node.wrap_spacing.get();
+
+        let main_length = match parent_node.direction {
+            Direction::Horizontal => available_area.width(),
+            Direction::Vertical => available_area.height(),
+        };
+
+        let mut lines = Vec::<WrapLine<Key>>::new();
+        let mut line = WrapLine::default();
+
+        // Initial phase: Measure the stacked children and break them into lines
+        for child_id in &children {
+            let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                continue;
+            };
+
+            // Non-stacked Nodes float on their own so they don't take part in any line
+            if !child_data.position.is_stacked() {
+                continue;
+            }
+
+            let (_, mut child_areas) = self.measure_node(
+                *child_id,
+                &child_data,
+                inner_area,
+                available_area,
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+
+            child_areas.area.adjust_size(&child_data);
+
+            let (child_main, child_cross) = match parent_node.direction {
+                Direction::Horizontal => (child_areas.area.width(), child_areas.area.height()),
+                Direction::Vertical => (child_areas.area.height(), child_areas.area.width()),
+            };
+
+            // Move to a new line if this child doesn't fit in the current one
+            if !line.children.is_empty() && line.main_size + spacing + child_main > main_length {
+                lines.push(mem::take(&mut line));
+            }
+
+            if !line.children.is_empty() {
+                line.main_size += spacing;
+            }
+            line.main_size += child_main;
+            line.cross_size = line.cross_size.max(child_cross);
+            line.children.push((*child_id, child_main, child_cross));
+        }
+
+        if !line.children.is_empty() {
+            lines.push(line);
+        }
+
+        let content_main_size = lines.iter().map(|line| line.main_size).fold(0., f32::max);
+        let content_cross_size = lines.iter().map(|line| line.cross_size).sum::<f32>()
+            + wrap_spacing * lines.len().saturating_sub(1) as f32;
+
+        let (content_width, content_height) = match parent_node.direction {
+            Direction::Horizontal => (content_main_size, content_cross_size),
+            Direction::Vertical => (content_cross_size, content_main_size),
+        };
+
+        // Shrink the parent to its content when unbounded
+        if parent_node.width.inner_sized() {
+            area.size.width =
+                content_width + parent_node.padding.horizontal() + parent_node.margin.horizontal();
+            inner_area.size.width = content_width;
+        }
+        if parent_node.height.inner_sized() {
+            area.size.height =
+                content_height + parent_node.padding.vertical() + parent_node.margin.vertical();
+            inner_area.size.height = content_height;
+        }
+
+        inner_sizes.width = content_width;
+        inner_sizes.height = content_height;
+
+        // Lines are aligned relatively to the content itself when the main axis is unbounded
+        let is_main_inner_sized = match parent_node.direction {
+            Direction::Horizontal => parent_node.width.inner_sized(),
+            Direction::Vertical => parent_node.height.inner_sized(),
+        };
+        let align_main_length = if is_main_inner_sized {
+            content_main_size
+        } else {
+            main_length
+        };
+
+        // Position every stacked child inside its line
+        let mut children_available_areas = FxHashMap::default();
+        let mut cross_offset = 0.;
+
+        // Lines of right-to-left vertical content are stacked starting from the right
+        if parent_node.layout_direction.is_rtl() && parent_node.direction == Direction::Vertical {
+            lines.reverse();
+            if !parent_node.width.inner_sized() {
+                cross_offset = available_area.width() - content_cross_size;
+            }
+        }
+
+        for mut line in lines {
+            let free_space = (align_main_length - line.main_size).max(0.);
+            let line_len = line.children.len() as f32;
+
+            let (mut main_offset, gap) = match parent_node.main_alignment {
+                Alignment::Center => (free_space / 2., spacing),
+                Alignment::End => (free_space, spacing),
+                Alignment::SpaceBetween if line_len > 1. => {
+                    (0., spacing + free_space / (line_len - 1.))
+                }
+                Alignment::Start | Alignment::SpaceBetween => (0., spacing),
+                Alignment::SpaceEvenly => {
+                    let gap_size = free_space / (line_len + 1.);
+                    (gap_size, spacing + gap_size)
+                }
+                Alignment::SpaceAround => {
+                    let gap_size = free_space / line_len;
+                    (gap_size / 2., spacing + gap_size)
+                }
+            };
+
+            // Children of right-to-left horizontal lines are placed starting from the right
+            if parent_node.layout_direction.is_rtl()
+                && parent_node.direction == Direction::Horizontal
+            {
+                line.children.reverse();
+            }
+
+            for (child_id, child_main, child_cross) in line.children {
+                let cross_align_offset = match parent_node.cross_alignment {
+                    Alignment::Center => (line.cross_size - child_cross) / 2.,
+                    Alignment::End => line.cross_size - child_cross,
+                    _ => 0.,
+                };
+
+                let mut child_available_area = *available_area;
+                let child_main_origin = main_offset;
+                let child_cross_origin = cross_offset + cross_align_offset;
+                let available_main_size = (main_length - main_offset).max(0.);
+                let available_cross_size = line.cross_size - cross_align_offset;
+
+                match parent_node.direction {
+                    Direction::Horizontal => {
+                        child_available_area.origin.x += child_main_origin;
+                        child_available_area.origin.y += child_cross_origin;
+                        child_available_area.size.width = available_main_size;
+                        child_available_area.size.height = available_cross_size;
+                    }
+                    Direction::Vertical => {
+                        child_available_area.origin.y += child_main_origin;
+                        child_available_area.origin.x += child_cross_origin;
+                        child_available_area.size.height = available_main_size;
+                        child_available_area.size.width = available_cross_size;
+                    }
+                }
+
+                children_available_areas.insert(child_id, child_available_area);
+
+                main_offset += child_main + gap;
+            }
+
+            cross_offset += line.cross_size + wrap_spacing;
+        }
+
+        // Final phase: measure the children in their final positions
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            let adapted_available_area = children_available_areas
+                .get(&child_id)
+                .copied()
+                .unwrap_or(*available_area);
+
+            let (child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                inner_area,
+                &adapted_available_area,
+                must_cache_children,
+                parent_is_dirty,
+                Phase::Final,
+            );
+
+            // Adjust the size of the area if needed
+            child_areas.area.adjust_size(&child_data);
+
+            // Cache the child layout if it was mutated and children must be cached
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+    }
+
+    /// Measure the children layouts of a Node whose content is a grid
+    #[allow(clippy::too_many_arguments)]
+    fn measure_grid_children(
+        &mut self,
+        children: Vec<Key>,
+        parent_node: &Node,
+        // Area available inside the Node
+        available_area: &Area,
+        // Accumulated sizes in both axis in the Node
+        inner_sizes: &mut Size2D,
+        // Whether to cache the measurements of this Node's children
+        must_cache_children: bool,
+        // Parent area.
+        area: &mut Area,
+        // Inner area of the parent.
+        inner_area: &mut Area,
+        // Parent Node is dirty.
+        parent_is_dirty: bool,
+    ) {
+        let spacing = parent_node.spacing.get();
+        let columns_len = parent_node.grid_columns.len().max(1);
+
+        let mut cells = Vec::<GridCell<Key>>::new();
+        let mut occupied_cells = Vec::<Vec<bool>>::new();
+        let mut cursor = (0, 0);
+
+        // Initial phase: Measure the stacked children and place them in the grid
+        for child_id in &children {
+            let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                continue;
+            };
+
+            // Non-stacked Nodes float on their own so they don't take part in the grid
+            if !child_data.position.is_stacked() {
+                continue;
+            }
+
+            let (_, mut child_areas) = self.measure_node(
+                *child_id,
+                &child_data,
+                inner_area,
+                available_area,
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+
+            child_areas.area.adjust_size(&child_data);
+
+            let column_span = child_data.grid_column.span.clamp(1, columns_len);
+            let row_span = child_data.grid_row.span.max(1);
+
+            let (row, column) = place_grid_cell(
+                &occupied_cells,
+                &mut cursor,
+                columns_len,
+                child_data.grid_column.start,
+                column_span,
+                child_data.grid_row.start,
+                row_span,
+            );
+
+            // Mark the cells as occupied
+            if occupied_cells.len() < row + row_span {
+                occupied_cells.resize(row + row_span, vec![false; columns_len]);
+            }
+            for row_cells in &mut occupied_cells[row..row + row_span] {
+                for cell in &mut row_cells[column..column + column_span] {
+                    *cell = true;
+                }
+            }
+
+            cells.push(GridCell {
+                node_id: *child_id,
+                column,
+                row,
+                column_span,
+                row_span,
+                size: child_areas.area.size,
+                is_cell_sized: (
+                    child_data.width.is_parent_sized(),
+                    child_data.height.is_parent_sized(),
+                ),
+            });
+        }
+
+        let rows_len = parent_node.grid_rows.len().max(occupied_cells.len());
+
+        let mut columns = resolve_grid_tracks(
+            &parent_node.grid_columns,
+            columns_len,
+            available_area.width(),
+            spacing,
+            parent_node.width.inner_sized(),
+            self.layout_metadata.root_area.width(),
+            cells
+                .iter()
+                .filter(|cell| !cell.is_cell_sized.0)
+                .map(|cell| (cell.column, cell.column_span, cell.size.width)),
+        );
+        let rows = resolve_grid_tracks(
+            &parent_node.grid_rows,
+            rows_len,
+            available_area.height(),
+            spacing,
+            parent_node.height.inner_sized(),
+            self.layout_metadata.root_area.height(),
+            cells
+                .iter()
+                .filter(|cell| !cell.is_cell_sized.1)
+                .map(|cell| (cell.row, cell.row_span, cell.size.height)),
+        );
+
+        // Columns of right-to-left grids are placed starting from the right
+        let is_rtl = parent_node.layout_direction.is_rtl();
+        if is_rtl {
+            columns.reverse();
+        }
+
+        let content_width =
+            columns.iter().sum::<f32>() + spacing * columns_len.saturating_sub(1) as f32;
+        let content_height = rows.iter().sum::<f32>() + spacing * rows_len.saturating_sub(1) as f32;
+
+        // Shrink the parent to its content when unbounded
+        if parent_node.width.inner_sized() {
+            area.size.width =
+                content_width + parent_node.padding.horizontal() + parent_node.margin.horizontal();
+            inner_area.size.width = content_width;
+        }
+        if parent_node.height.inner_sized() {
+            area.size.height =
+                content_height + parent_node.padding.vertical() + parent_node.margin.vertical();
+            inner_area.size.height = content_height;
+        }
+
+        inner_sizes.width = content_width;
+        inner_sizes.height = content_height;
+
+        // Calculate the area of every cell
+        let mut cells_areas = FxHashMap::default();
+        for cell in cells {
+            let column = if is_rtl {
+                columns_len - cell.column - cell.column_span
+            } else {
+                cell.column
+            };
+
+            let track_origin = |tracks: &[f32], start: usize| {
+                tracks[..start]
+                    .iter()
+                    .map(|track| track + spacing)
+                    .sum::<f32>()
+            };
+            let track_size = |tracks: &[f32], start: usize, span: usize| {
+                tracks[start..start + span].iter().sum::<f32>() + spacing * (span - 1) as f32
+            };
+
+            let cell_area = Area::new(
+                Point2D::new(
+                    available_area.min_x() + track_origin(&columns, column),
+                    available_area.min_y() + track_origin(&rows, cell.row),
+                ),
+                Size2D::new(
+                    track_size(&columns, column, cell.column_span),
+                    track_size(&rows, cell.row, cell.row_span),
+                ),
+            );
+
+            // Align the child inside its cell
+            let mut adapted_available_area = cell_area;
+            Self::align_content(
+                &mut adapted_available_area,
+                &cell_area,
+                cell.size,
+                &parent_node.main_alignment,
+                &parent_node.direction,
+                AlignmentDirection::Main,
+            );
+            Self::align_content(
+                &mut adapted_available_area,
+                &cell_area,
+                cell.size,
+                &parent_node.cross_alignment,
+                &parent_node.direction,
+                AlignmentDirection::Cross,
+            );
+
+            cells_areas.insert(cell.node_id, (cell_area, adapted_available_area));
+        }
+
+        // Final phase: measure the children inside their cells
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            let (parent_area, adapted_available_area) = cells_areas
+                .get(&child_id)
+                .copied()
+                .unwrap_or((*inner_area, *available_area));
+
+            let (child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                &parent_area,
+                &adapted_available_area,
+                must_cache_children,
+                parent_is_dirty,
+                Phase::Final,
+            );
+
+            // Adjust the size of the area if needed
+            child_areas.area.adjust_size(&child_data);
+
+            // Cache the child layout if it was mutated and children must be cached
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+    }
+
     /// Align the content of this node.
     fn align_content(
         available_area: &mut Area,
@@ -7,6 +7,8 @@ use crate::{
     geometry::Length,
     prelude::{
         Content,
+        GridPlacement,
+        LayoutDirection,
         Position,
         VisibleSize,
     },
@@ -62,6 +64,20 @@ pub struct Node {
     pub contains_text: bool,
 
     pub spacing: Length,
+
+    /// Space between the lines of a wrapped content
+    pub wrap_spacing: Length,
+
+    /// Sizes of the columns and rows of a grid content
+    pub grid_columns: Vec<Size>,
+    pub grid_rows: Vec<Size>,
+
+    /// Placement inside the parent grid
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
+    /// Horizontal direction in which this Node and its inner Nodes are laid out
+    pub layout_direction: LayoutDirection,
 }
 
 impl Scaled for Node {
@@ -78,6 +94,14 @@ impl Scaled for Node {
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
         self.spacing *= scale_factor;
+        self.wrap_spacing *= scale_factor;
+        for track in self
+            .grid_columns
+            .iter_mut()
+            .chain(self.grid_rows.iter_mut())
+        {
+            track.scale(scale_factor);
+        }
     }
 }
 
@@ -279,6 +303,91 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size, a direction and the spacings of a wrapped content
+    pub fn from_size_and_direction_and_wrap(
+        width: Size,
+        height: Size,
+        direction: Direction,
+        spacing: Length,
+        wrap_spacing: Length,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            spacing,
+            wrap_spacing,
+            content: Content::Wrap,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and the tracks of a grid content
+    pub fn from_size_and_grid(
+        width: Size,
+        height: Size,
+        grid_columns: Vec<Size>,
+        grid_rows: Vec<Size>,
+        spacing: Length,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_columns,
+            grid_rows,
+            spacing,
+            content: Content::Grid,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and a placement inside its parent grid
+    pub fn from_size_and_grid_placement(
+        width: Size,
+        height: Size,
+        grid_column: GridPlacement,
+        grid_row: GridPlacement,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_column,
+            grid_row,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size, a direction and a layout direction
+    pub fn from_size_and_direction_and_layout_direction(
+        width: Size,
+        height: Size,
+        direction: Direction,
+        layout_direction: LayoutDirection,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            layout_direction,
+            ..Default::default()
+        }
+    }
+
+    /// Get a copy of this Node with its horizontal sides and alignments mirrored,
+    /// so a right-to-left Node can be measured like a left-to-right one.
+    #[must_use]
+    pub fn mirrored(&self) -> Self {
+        let mut node = self.clone();
+        node.padding = self.padding.mirrored();
+        node.margin = self.margin.mirrored();
+        node.position.mirror();
+        match self.direction {
+            Direction::Horizontal => node.main_alignment = self.main_alignment.mirrored(),
+            Direction::Vertical => node.cross_alignment = self.cross_alignment.mirrored(),
+        }
+        node
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -292,5 +401,8 @@ impl Node {
         self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.has_layout_references
+            || self.content.is_wrap()
+            || self.content.is_grid()
+            || self.layout_direction.is_rtl()
     }
 }
@@ -22,6 +22,19 @@ impl Alignment {
         )
     }
 
+    /// Swap `Start` and `End`, used to mirror right-to-left layouts.
+    #[must_use]
+    pub const fn mirrored(&self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            Self::Center => Self::Center,
+            Self::SpaceBetween => Self::SpaceBetween,
+            Self::SpaceEvenly => Self::SpaceEvenly,
+            Self::SpaceAround => Self::SpaceAround,
+        }
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Self::Start => "start".to_string(),
@@ -5,6 +5,8 @@ pub enum Content {
     Normal,
     Fit,
     Flex,
+    Wrap,
+    Grid,
 }
 
 impl Content {
@@ -15,6 +17,14 @@ impl Content {
     pub fn is_flex(&self) -> bool {
         self == &Self::Flex
     }
+
+    pub fn is_wrap(&self) -> bool {
+        self == &Self::Wrap
+    }
+
+    pub fn is_grid(&self) -> bool {
+        self == &Self::Grid
+    }
 }
 
 impl Content {
@@ -23,6 +33,8 @@ impl Content {
             Self::Normal => "normal".to_owned(),
             Self::Fit => "fit".to_owned(),
             Self::Flex => "flex".to_owned(),
+            Self::Wrap => "wrap".to_owned(),
+            Self::Grid => "grid".to_owned(),
         }
     }
 }
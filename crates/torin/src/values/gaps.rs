@@ -63,6 +63,17 @@ impl Gaps {
         self.left.get()
     }
 
+    /// Swap the left and right gaps, used to mirror right-to-left layouts.
+    #[must_use]
+    pub const fn mirrored(&self) -> Self {
+        Self {
+            top: self.top,
+            right: self.left,
+            bottom: self.bottom,
+            left: self.right,
+        }
+    }
+
     pub fn pretty(&self) -> String {
         format!(
             "({}, {}, {}, {})",
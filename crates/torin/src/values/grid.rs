@@ -0,0 +1,36 @@
+/// Placement of a Node inside the tracks of its parent grid, along one axis.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GridPlacement {
+    /// Index of the first track, automatically placed when `None`
+    pub start: Option<usize>,
+    /// Number of tracks it spans
+    pub span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            start: None,
+            span: 1,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub fn new(start: Option<usize>, span: usize) -> Self {
+        Self {
+            start,
+            span: span.max(1),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self.start {
+            Some(start) if self.span > 1 => format!("{start} span {}", self.span),
+            Some(start) => format!("{start}"),
+            None if self.span > 1 => format!("span {}", self.span),
+            None => "auto".to_string(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum LayoutDirection {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl LayoutDirection {
+    pub const fn is_rtl(&self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Ltr => "ltr".to_string(),
+            Self::Rtl => "rtl".to_string(),
+        }
+    }
+}
@@ -129,6 +129,15 @@ impl Position {
         }
     }
 
+    /// Swap the left and right sides, used to mirror right-to-left layouts.
+    pub fn mirror(&mut self) {
+        match self {
+            Self::Absolute(positions) | Self::Global(positions) | Self::Stacked(positions) => {
+                std::mem::swap(&mut positions.left, &mut positions.right);
+            }
+        }
+    }
+
     pub fn get_origin(
         &self,
         available_parent_area: &Area,
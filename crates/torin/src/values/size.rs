@@ -52,6 +52,14 @@ impl Size {
         matches!(self, Self::Inner | Self::FillMinimum)
     }
 
+    /// Whether it depends on the size of the parent
+    pub fn is_parent_sized(&self) -> bool {
+        matches!(
+            self,
+            Self::Fill | Self::Percentage(_) | Self::Flex(_) | Self::DynamicCalculations(..)
+        )
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Self::Inner => "auto".to_string(),
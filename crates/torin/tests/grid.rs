@@ -0,0 +1,293 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn grid_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4, 5],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(200.0)),
+            vec![
+                Size::Pixels(Length::new(100.0)),
+                Size::Flex(Length::new(1.0)),
+                Size::Flex(Length::new(2.0)),
+            ],
+            vec![Size::Pixels(Length::new(50.0)), Size::Inner],
+            Length::new(10.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(30.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Fill,
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(40.0)),
+            Size::Pixels(Length::new(70.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        5,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::new(None, 2),
+            GridPlacement::default(),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 30.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(60.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 60.0), Size2D::new(40.0, 70.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(110.0, 60.0), Size2D::new(190.0, 70.0)),
+    );
+}
+
+#[test]
+pub fn grid_explicit_placement() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_grid(
+            Size::Inner,
+            Size::Inner,
+            vec![Size::Inner, Size::Inner],
+            vec![],
+            Length::new(0.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(20.0)),
+            GridPlacement::new(Some(1), 1),
+            GridPlacement::new(Some(0), 1),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(30.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(10.0)),
+            Size::Pixels(Length::new(10.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(80.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(30.0, 0.0), Size2D::new(50.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(30.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 40.0), Size2D::new(10.0, 10.0)),
+    );
+}
+
+#[test]
+pub fn grid_cell_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_grid(
+        Size::Pixels(Length::new(200.0)),
+        Size::Pixels(Length::new(100.0)),
+        vec![Size::Flex(Length::new(1.0)), Size::Flex(Length::new(1.0))],
+        vec![Size::Flex(Length::new(1.0))],
+        Length::new(0.0),
+    );
+    parent.main_alignment = Alignment::Center;
+    parent.cross_alignment = Alignment::End;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2], parent);
+    for id in 1..=2 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(20.0)),
+                Size::Pixels(Length::new(20.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(80.0, 40.0), Size2D::new(20.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(180.0, 40.0), Size2D::new(20.0, 20.0)),
+    );
+}
+
+#[test]
+pub fn grid_incremental() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![Size::Inner, Size::Inner],
+            vec![],
+            Length::new(0.0),
+        ),
+    );
+    for id in 1..=4 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(50.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+
+    // Growing the last child should move the whole second column and row
+    mocked_dom.set_node(
+        3,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(80.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(3);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(100.0, 50.0), Size2D::new(50.0, 50.0)),
+    );
+}
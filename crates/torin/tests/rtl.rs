@@ -0,0 +1,288 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn rtl_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction_and_layout_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Horizontal,
+            LayoutDirection::Rtl,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_alignments() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_direction_and_layout_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Direction::Horizontal,
+        LayoutDirection::Rtl,
+    );
+    parent.main_alignment = Alignment::End;
+
+    let mut column = Node::from_size_and_direction_and_layout_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Direction::Vertical,
+        LayoutDirection::Rtl,
+    );
+    column.cross_alignment = Alignment::Center;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 3], Node::default());
+    mocked_dom.add(1, Some(0), vec![2], parent);
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(3, Some(0), vec![4, 5], column);
+    mocked_dom.add(
+        4,
+        Some(3),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        5,
+        Some(3),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // End alignment in a right-to-left row means the left edge
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(100.0, 100.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(125.0, 150.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_gaps_and_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_direction_and_layout_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Vertical,
+        LayoutDirection::Rtl,
+    );
+    parent.padding = Gaps::new(10.0, 0.0, 0.0, 20.0);
+
+    let mut child = Node::from_size_and_direction_and_layout_direction(
+        Size::Pixels(Length::new(100.0)),
+        Size::Pixels(Length::new(50.0)),
+        Direction::Vertical,
+        LayoutDirection::Rtl,
+    );
+    child.margin = Gaps::new(0.0, 0.0, 0.0, 5.0);
+
+    let mut absolute_child = Node::from_size_and_direction_and_layout_direction(
+        Size::Pixels(Length::new(50.0)),
+        Size::Pixels(Length::new(50.0)),
+        Direction::Vertical,
+        LayoutDirection::Rtl,
+    );
+    absolute_child.position = Position::new_absolute();
+    absolute_child.position.set_left(30.0);
+    absolute_child.position.set_top(0.0);
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2], parent);
+    mocked_dom.add(1, Some(0), vec![], child);
+    mocked_dom.add(2, Some(0), vec![], absolute_child);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().inner_area,
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(280.0, 290.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(175.0, 10.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(200.0, 10.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_wrap() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_direction_and_wrap(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Horizontal,
+        Length::new(0.0),
+        Length::new(0.0),
+    );
+    parent.layout_direction = LayoutDirection::Rtl;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2, 3], parent);
+    for id in 1..=3 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(120.0)),
+                Size::Pixels(Length::new(50.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(60.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(180.0, 50.0), Size2D::new(120.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn rtl_grid() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_grid(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        vec![
+            Size::Pixels(Length::new(100.0)),
+            Size::Flex(Length::new(1.0)),
+        ],
+        vec![],
+        Length::new(0.0),
+    );
+    parent.layout_direction = LayoutDirection::Rtl;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2], parent);
+    for id in 1..=2 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(250.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+}
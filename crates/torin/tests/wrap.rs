@@ -0,0 +1,310 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn wrap_horizontal() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4, 5],
+        Node::from_size_and_direction_and_wrap(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            Direction::Horizontal,
+            Length::new(10.0),
+            Length::new(20.0),
+        ),
+    );
+    for id in 1..=5 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 70.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(110.0, 70.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 140.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn wrap_vertical() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_wrap(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(250.0)),
+            Direction::Vertical,
+            Length::new(0.0),
+            Length::new(10.0),
+        ),
+    );
+    for id in 1..=3 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn wrap_alignments() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_direction_and_wrap(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Horizontal,
+        Length::new(0.0),
+        Length::new(0.0),
+    );
+    parent.main_alignment = Alignment::Center;
+    parent.cross_alignment = Alignment::End;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2, 3], parent);
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(80.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(50.0, 30.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 0.0), Size2D::new(100.0, 80.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(50.0, 80.0), Size2D::new(200.0, 40.0)),
+    );
+}
+
+#[test]
+pub fn wrap_auto_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(250.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_direction_and_wrap(
+            Size::Inner,
+            Size::Inner,
+            Direction::Horizontal,
+            Length::new(10.0),
+            Length::new(5.0),
+        ),
+    );
+    for id in 2..=4 {
+        mocked_dom.add(
+            id,
+            Some(1),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(30.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(210.0, 65.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 35.0), Size2D::new(100.0, 30.0)),
+    );
+}
+
+#[test]
+pub fn wrap_incremental() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_wrap(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            Direction::Horizontal,
+            Length::new(0.0),
+            Length::new(0.0),
+        ),
+    );
+    for id in 1..=3 {
+        mocked_dom.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(50.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+
+    // Growing the second child should push the third one into a new line
+    mocked_dom.set_node(
+        2,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(150.0)),
+            Size::Pixels(Length::new(80.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(150.0, 80.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(100.0, 50.0)),
+    );
+}
@@ -9,6 +9,7 @@ use freya_core::{
     event_loop_messages::EventLoopMessage,
     states::AccessibilityState,
     types::{
+        AccessibilityId,
         EventEmitter,
         NativePlatformSender,
     },
@@ -57,6 +58,10 @@ impl WinitAcessibilityTree {
         self.accessibility_tree.focused_node_id()
     }
 
+    pub fn focused_id(&self) -> AccessibilityId {
+        self.accessibility_tree.focused_id
+    }
+
     /// Process an accessibility window event
     pub fn process_accessibility_event(&mut self, event: &WindowEvent, window: &Window) {
         self.accessibility_adapter.process_event(window, event)
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    rc::Rc,
+    sync::Arc,
+};
 
 use accesskit::{
     Node,
@@ -32,6 +35,7 @@ use freya_core::{
         PluginHandle,
         PluginsManager,
     },
+    profiling::FrameStats,
     render::{
         Compositor,
         RenderPipeline,
@@ -47,7 +51,10 @@ use freya_core::{
     window_config::WindowConfig,
 };
 use freya_engine::prelude::*;
-use freya_native_core::NodeId;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
 use futures_task::Waker;
 use futures_util::Future;
 use ragnarok::{
@@ -62,6 +69,8 @@ use tokio::{
         mpsc,
         watch,
     },
+    task::JoinHandle,
+    time::Instant,
 };
 use torin::geometry::Area;
 use winit::{
@@ -103,10 +112,12 @@ pub struct Application {
     pub(crate) surface: Surface,
     pub(crate) dirty_surface: Surface,
     pub(crate) graphics_driver: GraphicsDriver,
-    pub(crate) window: Window,
+    pub(crate) window: Rc<Window>,
     pub(crate) is_window_focused: bool,
     pub(crate) proxy: EventLoopProxy<EventLoopMessage>,
     pub(crate) plugins: PluginsManager,
+    /// Pending rerender for the next frame of the animated images.
+    pub(crate) scheduled_frame: Option<(Instant, JoinHandle<()>)>,
 
     pub(crate) window_config: WindowConfig,
 }
@@ -117,7 +128,7 @@ impl Application {
         sdom: SafeDOM,
         vdom: VirtualDom,
         proxy: &EventLoopProxy<EventLoopMessage>,
-        window: Window,
+        window: Rc<Window>,
         accessibility: WinitAcessibilityTree,
         surface: Surface,
         dirty_surface: Surface,
@@ -158,6 +169,7 @@ impl Application {
             window,
             proxy: proxy.clone(),
             plugins,
+            scheduled_frame: None,
             window_config,
         }
     }
@@ -216,12 +228,24 @@ impl Application {
                     Some(processed_events) = self.event_receiver.recv() => {
                         let fdom = self.sdom.get();
                         let rdom = fdom.rdom();
+                        let report_emitted_events = self.plugins.wants_emitted_events();
                         let events_executor_adapter = EventsExecutorAdapter {
                             rdom,
                             vdom: &mut self.vdom,
+                            report_emitted_events,
                         };
-                        events_executor_adapter.run(&mut self.nodes_state,
+                        let executed_events = events_executor_adapter.run(&mut self.nodes_state,
                             processed_events);
+                        if report_emitted_events {
+                            self.plugins.send(
+                                PluginEvent::EmittedEvents {
+                                    window: &self.window,
+                                    fdom: &fdom,
+                                    events: &executed_events,
+                                },
+                                PluginHandle::new(&self.proxy),
+                            );
+                        }
                     },
                     _ = self.vdom.wait_for_work() => {},
                 }
@@ -243,6 +267,11 @@ impl Application {
         let (must_repaint, must_relayout) =
             self.render_mutations(self.window.scale_factor() as f32);
 
+        self.process_dom_changes(must_repaint, must_relayout);
+    }
+
+    /// Schedule the layout and rendering required by the changes made to the RealDOM.
+    fn process_dom_changes(&mut self, must_repaint: bool, must_relayout: bool) {
         if must_relayout {
             self.process_layout_on_next_render = true;
             self.accessibility_tasks_for_next_render
@@ -254,6 +283,19 @@ impl Application {
         }
     }
 
+    /// Override an attribute of a node, see [freya_core::dom::FreyaDOM::override_attribute].
+    pub fn override_attribute(&mut self, node_id: NodeId, name: AttributeName, value: String) {
+        let (must_repaint, must_relayout) =
+            self.sdom.get_mut().override_attribute(node_id, name, value);
+        self.process_dom_changes(must_repaint, must_relayout);
+    }
+
+    /// Restore the overridden attributes of a node, see [freya_core::dom::FreyaDOM::reset_attribute_overrides].
+    pub fn reset_attribute_overrides(&mut self, node_id: NodeId) {
+        let (must_repaint, must_relayout) = self.sdom.get_mut().reset_attribute_overrides(node_id);
+        self.process_dom_changes(must_repaint, must_relayout);
+    }
+
     /// Process the events queue
     pub fn process_events(&mut self, scale_factor: f64) {
         let fdom = self.sdom.get();
@@ -315,6 +357,18 @@ impl Application {
             &mut dirty_accessibility_tree,
             &self.event_emitter,
         );
+        drop(dirty_accessibility_tree);
+        drop(layout);
+
+        self.plugins.send(
+            PluginEvent::FinishedUpdatingAccessibility {
+                window: &self.window,
+                fdom: &fdom,
+                focused_id: self.accessibility.focused_id(),
+                background: self.window_config.background,
+            },
+            PluginHandle::new(&self.proxy),
+        );
     }
 
     /// Send an event
@@ -358,6 +412,9 @@ impl Application {
             },
             PluginHandle::new(&self.proxy),
         );
+
+        // Plugins already had the chance to take the stats of this frame
+        *self.sdom.get().frame_stats() = FrameStats::default();
     }
 
     /// Resize the Window
@@ -442,6 +499,7 @@ impl Application {
             let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
             let mut compositor_dirty_nodes = fdom.compositor_dirty_nodes();
             let mut compositor_dirty_area = fdom.compositor_dirty_area();
+            let mut frame_stats = fdom.frame_stats();
 
             process_layout(
                 rdom,
@@ -450,6 +508,7 @@ impl Application {
                 &mut dirty_accessibility_tree,
                 &mut compositor_dirty_nodes,
                 &mut compositor_dirty_area,
+                &mut frame_stats,
                 Area::from_size(self.window.inner_size().to_torin()),
                 font_collection,
                 scale_factor as f32,
@@ -486,24 +545,57 @@ impl Application {
     ) {
         let fdom = self.sdom.get();
 
-        let mut render_pipeline = RenderPipeline {
-            canvas_area: Area::from_size(self.window.inner_size().to_torin()),
-            rdom: fdom.rdom(),
-            compositor_dirty_area: &mut fdom.compositor_dirty_area(),
-            compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
-            compositor_cache: &mut fdom.compositor_cache(),
-            layers: &mut fdom.layers(),
-            layout: &mut fdom.layout(),
-            background,
-            surface: &mut self.surface,
-            dirty_surface: &mut self.dirty_surface,
-            compositor: &mut self.compositor,
-            scale_factor,
-            font_collection,
-            font_manager,
-            fallback_fonts,
-            images_cache: &mut fdom.images_cache(),
+        let next_frame = {
+            let mut render_pipeline = RenderPipeline {
+                canvas_area: Area::from_size(self.window.inner_size().to_torin()),
+                rdom: fdom.rdom(),
+                compositor_dirty_area: &mut fdom.compositor_dirty_area(),
+                compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
+                compositor_cache: &mut fdom.compositor_cache(),
+                layers: &mut fdom.layers(),
+                layout: &mut fdom.layout(),
+                background,
+                surface: &mut self.surface,
+                dirty_surface: &mut self.dirty_surface,
+                compositor: &mut self.compositor,
+                scale_factor,
+                font_collection,
+                font_manager,
+                fallback_fonts,
+                images_cache: &mut fdom.images_cache(),
+                layer_cache: &mut fdom.layer_cache(),
+                frame_stats: &mut fdom.frame_stats(),
+            };
+            render_pipeline.run()
         };
-        render_pipeline.run();
+        drop(fdom);
+
+        self.schedule_frame(next_frame);
+    }
+
+    /// Render again once the next frame of the animated images is due.
+    fn schedule_frame(&mut self, next_frame: Option<Instant>) {
+        if self.scheduled_frame.as_ref().map(|(deadline, _)| *deadline) == next_frame {
+            return;
+        }
+
+        if let Some((_, task)) = self.scheduled_frame.take() {
+            task.abort();
+        }
+
+        self.scheduled_frame = next_frame.map(|deadline| {
+            let proxy = self.proxy.clone();
+            let window_id = self.window.id();
+            let task = tokio::spawn(async move {
+                tokio::time::sleep_until(deadline).await;
+                proxy
+                    .send_event(EventLoopMessage {
+                        window_id: Some(window_id),
+                        action: EventLoopMessageAction::RequestRerender,
+                    })
+                    .ok();
+            });
+            (deadline, task)
+        });
     }
 }
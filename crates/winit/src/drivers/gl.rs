@@ -22,6 +22,7 @@ use gl::{
 };
 use glutin::{
     config::{
+        Config,
         ConfigTemplateBuilder,
         GlConfig,
     },
@@ -33,6 +34,8 @@ use glutin::{
         PossiblyCurrentContext,
     },
     display::{
+        Display,
+        DisplayApiPreference,
         GetGlDisplay,
         GlDisplay,
     },
@@ -47,8 +50,10 @@ use glutin::{
         WindowSurface,
     },
 };
-use glutin_winit::DisplayBuilder;
-use raw_window_handle::HasWindowHandle;
+use raw_window_handle::{
+    HasDisplayHandle,
+    HasWindowHandle,
+};
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -79,37 +84,130 @@ impl Drop for OpenGLDriver {
     }
 }
 
+/// Reason why an [OpenGLDriver] could not be created.
+pub struct OpenGLDriverError {
+    /// The Window, in case it was created before failing.
+    pub window: Option<Window>,
+    pub reason: String,
+}
+
+impl OpenGLDriverError {
+    fn new(window: Option<Window>, reason: impl ToString) -> Self {
+        Self {
+            window,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Create the OpenGL display of the platform, preferring the same APIs as `glutin_winit`.
+fn create_display(
+    event_loop: &ActiveEventLoop,
+    window: Option<&Window>,
+) -> Result<Display, String> {
+    let display_handle = event_loop
+        .display_handle()
+        .map_err(|err| err.to_string())?
+        .as_raw();
+
+    #[cfg(target_os = "windows")]
+    let preference = DisplayApiPreference::WglThenEgl(
+        window
+            .and_then(|window| window.window_handle().ok())
+            .map(|window_handle| window_handle.as_raw()),
+    );
+    #[cfg(target_os = "macos")]
+    let preference = DisplayApiPreference::Cgl;
+    #[cfg(target_os = "android")]
+    let preference = DisplayApiPreference::Egl;
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "android")))]
+    let preference =
+        DisplayApiPreference::EglThenGlx(Box::new(winit::platform::x11::register_xlib_error_hook));
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = window;
+
+    unsafe { Display::new(display_handle, preference) }
+        .map_err(|err| format!("Could not create the OpenGL display: {err}"))
+}
+
 impl OpenGLDriver {
     pub fn new(
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
         window_config: &WindowConfig,
-    ) -> (Self, Window, SkiaSurface) {
-        let template = ConfigTemplateBuilder::new()
+    ) -> Result<(Self, Window, SkiaSurface), OpenGLDriverError> {
+        // WGL needs the window to create the display, other platforms need the config to create the window
+        #[cfg(target_os = "windows")]
+        let window = Some(
+            event_loop
+                .create_window(window_attributes.clone())
+                .map_err(|err| OpenGLDriverError::new(None, err))?,
+        );
+        #[cfg(not(target_os = "windows"))]
+        let window: Option<Window> = None;
+
+        let display = match create_display(event_loop, window.as_ref()) {
+            Ok(display) => display,
+            Err(reason) => return Err(OpenGLDriverError::new(window, reason)),
+        };
+
+        let mut template = ConfigTemplateBuilder::new()
             .with_alpha_size(8)
             .with_transparency(window_config.transparent);
+        if let Some(window_handle) = window
+            .as_ref()
+            .and_then(|window| window.window_handle().ok())
+        {
+            template = template.compatible_with_native_window(window_handle.as_raw());
+        }
 
-        let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
-        let (window, gl_config) = display_builder
-            .build(event_loop, template, |configs| {
-                configs
-                    .reduce(|accum, config| {
-                        let transparency_check = config.supports_transparency().unwrap_or(false)
-                            & !accum.supports_transparency().unwrap_or(false);
+        let configs = match unsafe { display.find_configs(template.build()) } {
+            Ok(configs) => configs,
+            Err(err) => {
+                return Err(OpenGLDriverError::new(
+                    window,
+                    format!("Could not find an OpenGL config: {err}"),
+                ))
+            }
+        };
+        let gl_config = configs.reduce(|accum, config| {
+            let transparency_check = config.supports_transparency().unwrap_or(false)
+                & !accum.supports_transparency().unwrap_or(false);
+
+            if transparency_check || config.num_samples() < accum.num_samples() {
+                config
+            } else {
+                accum
+            }
+        });
+        let Some(gl_config) = gl_config else {
+            return Err(OpenGLDriverError::new(
+                window,
+                "No OpenGL config matches the requested attributes",
+            ));
+        };
 
-                        if transparency_check || config.num_samples() < accum.num_samples() {
-                            config
-                        } else {
-                            accum
-                        }
-                    })
-                    .unwrap()
-            })
-            .unwrap();
+        let window = match window {
+            Some(window) => window,
+            None => glutin_winit::finalize_window(event_loop, window_attributes, &gl_config)
+                .map_err(|err| {
+                    OpenGLDriverError::new(None, format!("Could not create window: {err}"))
+                })?,
+        };
 
-        let window = window.expect("Could not create window with OpenGL context");
+        match Self::new_for_window(&window, gl_config, window_config) {
+            Ok((driver, skia_surface)) => Ok((driver, window, skia_surface)),
+            Err(reason) => Err(OpenGLDriverError::new(Some(window), reason)),
+        }
+    }
 
-        let window_handle = window.window_handle().unwrap();
+    fn new_for_window(
+        window: &Window,
+        gl_config: Config,
+        window_config: &WindowConfig,
+    ) -> Result<(Self, SkiaSurface), String> {
+        let window_handle = window.window_handle().map_err(|err| err.to_string())?;
 
         let context_attributes = ContextAttributesBuilder::new()
             .with_profile(GlProfile::Core)
@@ -124,12 +222,12 @@ impl OpenGLDriver {
             gl_config
                 .display()
                 .create_context(&gl_config, &context_attributes)
-                .unwrap_or_else(|_| {
+                .or_else(|_| {
                     gl_config
                         .display()
                         .create_context(&gl_config, &fallback_context_attributes)
-                        .expect("failed to create context")
                 })
+                .map_err(|err| format!("Could not create context: {err}"))?
         };
 
         let (width, height): (u32, u32) = window.inner_size().into();
@@ -144,12 +242,12 @@ impl OpenGLDriver {
             gl_config
                 .display()
                 .create_window_surface(&gl_config, &attrs)
-                .expect("Could not create gl window surface")
+                .map_err(|err| format!("Could not create gl window surface: {err}"))?
         };
 
         let gl_context = not_current_gl_context
             .make_current(&gl_surface)
-            .expect("Could not make GL context current when setting up skia renderer");
+            .map_err(|err| format!("Could not make GL context current: {err}"))?;
 
         // Try setting vsync.
         gl_surface
@@ -169,7 +267,7 @@ impl OpenGLDriver {
                 .display()
                 .get_proc_address(CString::new(name).unwrap().as_c_str())
         })
-        .expect("Could not create interface");
+        .ok_or("Could not create interface")?;
 
         let fb_info = {
             let mut fboid: GLint = 0;
@@ -187,7 +285,7 @@ impl OpenGLDriver {
         let size = window.inner_size();
 
         let mut gr_context =
-            direct_contexts::make_gl(interface, None).expect("Could not create direct context");
+            direct_contexts::make_gl(interface, None).ok_or("Could not create direct context")?;
 
         if let Some(max_gpu_resources_bytes) = window_config.max_gpu_resources_bytes {
             gr_context.set_resource_cache_limit(max_gpu_resources_bytes);
@@ -203,7 +301,7 @@ impl OpenGLDriver {
             None,
             None,
         )
-        .expect("Could not create skia surface");
+        .ok_or("Could not create skia surface")?;
 
         let driver = OpenGLDriver {
             gl_context,
@@ -214,7 +312,7 @@ impl OpenGLDriver {
             fb_info,
         };
 
-        (driver, window, skia_surface)
+        Ok((driver, skia_surface))
     }
 
     pub fn make_current(&mut self) {
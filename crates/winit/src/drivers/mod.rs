@@ -1,9 +1,16 @@
 mod gl;
+mod software;
 
-use freya_core::window_config::WindowConfig;
+use std::rc::Rc;
+
+use freya_core::window_config::{
+    GraphicsBackend,
+    WindowConfig,
+};
 use freya_engine::prelude::Surface as SkiaSurface;
 pub use gl::*;
 use glutin::surface::GlSurface;
+pub use software::*;
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -15,6 +22,7 @@ use winit::{
 
 pub enum GraphicsDriver {
     OpenGl(OpenGLDriver),
+    Software(SoftwareDriver),
 }
 
 impl GraphicsDriver {
@@ -22,30 +30,58 @@ impl GraphicsDriver {
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
         window_config: &WindowConfig,
-    ) -> (Self, Window, SkiaSurface) {
-        let (driver, window, surface) =
-            OpenGLDriver::new(event_loop, window_attributes, window_config);
-        (Self::OpenGl(driver), window, surface)
+    ) -> (Self, Rc<Window>, SkiaSurface) {
+        match window_config.graphics_backend {
+            GraphicsBackend::OpenGl => {
+                match OpenGLDriver::new(event_loop, window_attributes.clone(), window_config) {
+                    Ok((driver, window, surface)) => {
+                        (Self::OpenGl(driver), Rc::new(window), surface)
+                    }
+                    Err(OpenGLDriverError { window, reason }) => {
+                        tracing::warn!(
+                            "Could not use OpenGL, falling back to software rendering: {reason}"
+                        );
+                        let window = window.unwrap_or_else(|| {
+                            event_loop
+                                .create_window(window_attributes)
+                                .expect("Could not create window")
+                        });
+                        let (driver, window, surface) = SoftwareDriver::new(window);
+                        (Self::Software(driver), window, surface)
+                    }
+                }
+            }
+            GraphicsBackend::Software => {
+                let window = event_loop
+                    .create_window(window_attributes)
+                    .expect("Could not create window");
+                let (driver, window, surface) = SoftwareDriver::new(window);
+                (Self::Software(driver), window, surface)
+            }
+        }
     }
 
     pub fn make_current(&mut self) {
         match self {
             Self::OpenGl(gl) => gl.make_current(),
+            Self::Software(_) => {}
         }
     }
 
-    pub fn flush_and_submit(&mut self) {
+    pub fn flush_and_submit(&mut self, surface: &mut SkiaSurface) {
         match self {
             Self::OpenGl(gl) => {
                 gl.gr_context.flush_and_submit();
                 gl.gl_surface.swap_buffers(&gl.gl_context).unwrap();
             }
+            Self::Software(software) => software.present(surface),
         }
     }
 
     pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
         match self {
             Self::OpenGl(gl) => gl.resize(size),
+            Self::Software(software) => software.resize(size),
         }
     }
 }
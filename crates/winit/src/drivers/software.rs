@@ -0,0 +1,129 @@
+use std::rc::Rc;
+
+use freya_engine::prelude::{
+    raster_n32_premul,
+    ColorType,
+    Surface as SkiaSurface,
+};
+use softbuffer::{
+    Context,
+    Surface as SoftSurface,
+};
+use winit::{
+    dpi::PhysicalSize,
+    window::Window,
+};
+
+use crate::size::WinitSize;
+
+/// Graphics driver rasterizing in the CPU, for machines without OpenGL support.
+pub struct SoftwareDriver {
+    pub(crate) soft_surface: SoftSurface<Rc<Window>, Rc<Window>>,
+}
+
+impl SoftwareDriver {
+    pub fn new(window: Window) -> (Self, Rc<Window>, SkiaSurface) {
+        let window = Rc::new(window);
+
+        let context = Context::new(window.clone()).expect("Could not create software context");
+        let soft_surface =
+            SoftSurface::new(&context, window.clone()).expect("Could not create software surface");
+
+        let mut driver = SoftwareDriver { soft_surface };
+
+        let (skia_surface, _) = driver.resize(window.inner_size());
+
+        (driver, window, skia_surface)
+    }
+
+    /// Copy the rasterized pixels of the surface into the window framebuffer.
+    pub fn present(&mut self, surface: &mut SkiaSurface) {
+        let Ok(mut buffer) = self.soft_surface.buffer_mut() else {
+            return;
+        };
+
+        if copy_pixels(surface, &mut buffer) {
+            buffer.present().ok();
+        }
+    }
+
+    pub fn resize(&mut self, size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
+        self.soft_surface
+            .resize(size.as_gl_width(), size.as_gl_height())
+            .expect("Could not resize software surface");
+
+        raster_surfaces(size)
+    }
+}
+
+/// Create the main and dirty raster surfaces for the given window size.
+fn raster_surfaces(size: PhysicalSize<u32>) -> (SkiaSurface, SkiaSurface) {
+    let mut surface =
+        raster_n32_premul(size.to_skia()).expect("Could not create skia raster surface");
+
+    let dirty_surface = surface.new_surface_with_dimensions(size.to_skia()).unwrap();
+
+    (surface, dirty_surface)
+}
+
+/// Copy the pixels of the surface into a `0RGB` framebuffer of the same size.
+/// Returns `false` if the pixels of the surface could not be read.
+fn copy_pixels(surface: &mut SkiaSurface, buffer: &mut [u32]) -> bool {
+    let Some(pixmap) = surface.peek_pixels() else {
+        return false;
+    };
+    let Some(bytes) = pixmap.bytes() else {
+        return false;
+    };
+    let is_bgra = pixmap.color_type() == ColorType::BGRA8888;
+    let width = pixmap.width() as usize;
+
+    for (buffer_row, pixmap_row) in buffer
+        .chunks_exact_mut(width)
+        .zip(bytes.chunks_exact(pixmap.row_bytes()))
+    {
+        for (pixel, color) in buffer_row.iter_mut().zip(pixmap_row.chunks_exact(4)) {
+            let (r, g, b) = if is_bgra {
+                (color[2], color[1], color[0])
+            } else {
+                (color[0], color[1], color[2])
+            };
+            // The framebuffer expects `0RGB` pixels
+            *pixel = u32::from_be_bytes([0, r, g, b]);
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use freya_engine::prelude::SkColor;
+    use winit::dpi::PhysicalSize;
+
+    use super::{
+        copy_pixels,
+        raster_surfaces,
+    };
+
+    #[test]
+    fn resize_surfaces() {
+        let (surface, dirty_surface) = raster_surfaces(PhysicalSize::new(300, 200));
+        assert_eq!((surface.width(), surface.height()), (300, 200));
+        assert_eq!((dirty_surface.width(), dirty_surface.height()), (300, 200));
+
+        // Minimized windows have no size but the surfaces still need at least one pixel
+        let (surface, _) = raster_surfaces(PhysicalSize::new(0, 0));
+        assert_eq!((surface.width(), surface.height()), (1, 1));
+    }
+
+    #[test]
+    fn present_pixels() {
+        let (mut surface, _) = raster_surfaces(PhysicalSize::new(4, 2));
+        surface.canvas().clear(SkColor::from_rgb(0x11, 0x22, 0x33));
+
+        let mut buffer = vec![0; 4 * 2];
+        assert!(copy_pixels(&mut surface, &mut buffer));
+        assert!(buffer.iter().all(|pixel| *pixel == 0x00112233));
+    }
+}
@@ -1,4 +1,5 @@
 use freya_elements::{
+    events::ImeData,
     Force,
     MouseButton,
     TouchPhase,
@@ -38,3 +39,12 @@ pub fn map_winit_touch_phase(event: winit::event::TouchPhase) -> TouchPhase {
         winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
     }
 }
+
+pub fn map_winit_ime(event: winit::event::Ime) -> ImeData {
+    match event {
+        winit::event::Ime::Enabled => ImeData::Enabled,
+        winit::event::Ime::Preedit(text, cursor) => ImeData::Preedit { text, cursor },
+        winit::event::Ime::Commit(text) => ImeData::Commit(text),
+        winit::event::Ime::Disabled => ImeData::Disabled,
+    }
+}
@@ -8,8 +8,10 @@ use freya_core::{
     },
     events::{
         FileEventName,
+        ImeEventName,
         KeyboardEventName,
         MouseEventName,
+        PinchEventName,
         PlatformEvent,
         TouchEventName,
         WheelEventName,
@@ -45,6 +47,7 @@ use winit::{
 use crate::{
     app::AccessibilityTask,
     events::{
+        map_winit_ime,
         map_winit_mouse_button,
         map_winit_touch_force,
         map_winit_touch_phase,
@@ -132,7 +135,7 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
 
                 if let Some(on_setup) = on_setup {
                     let app = self.state.apps.get_mut(&window_id).unwrap();
-                    (on_setup)(&mut app.window)
+                    (on_setup)(&app.window)
                 }
             }
         }
@@ -221,6 +224,16 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                 EventLoopMessageAction::PollVDOM => {
                     app.poll_vdom();
                 }
+                EventLoopMessageAction::OverrideAttribute {
+                    node_id,
+                    name,
+                    value,
+                } => {
+                    app.override_attribute(node_id, name, value);
+                }
+                EventLoopMessageAction::ResetAttributeOverrides(node_id) => {
+                    app.reset_attribute_overrides(node_id);
+                }
 
                 _ => {}
             }
@@ -257,20 +270,37 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                     });
                 }
 
-                WindowEvent::Ime(Ime::Commit(text)) => {
+                WindowEvent::Ime(ime) => {
+                    let commit = if let Ime::Commit(text) = &ime {
+                        Some(text.clone())
+                    } else {
+                        None
+                    };
+
                     app.send_event(
-                        PlatformEvent::Keyboard {
-                            name: KeyboardEventName::KeyDown,
-                            key: Key::Character(text),
-                            code: Code::Unidentified,
-                            modifiers: map_winit_modifiers(self.modifiers_state),
+                        PlatformEvent::Ime {
+                            name: ImeEventName::Ime,
+                            data: map_winit_ime(ime),
                         },
                         scale_factor,
                     );
+
+                    // Committed texts are also sent as key presses for those elements that don't handle IME events
+                    if let Some(text) = commit {
+                        app.send_event(
+                            PlatformEvent::Keyboard {
+                                name: KeyboardEventName::KeyDown,
+                                key: Key::Character(text),
+                                code: Code::Unidentified,
+                                modifiers: map_winit_modifiers(self.modifiers_state),
+                            },
+                            scale_factor,
+                        );
+                    }
                 }
                 WindowEvent::CloseRequested => {
                     if let Some(on_close) = &mut app.window_config.on_close {
-                        let response = (on_close)(&mut app.window);
+                        let response = (on_close)(&app.window);
                         if response == OnCloseResponse::Close {
                             remove_app = true;
                         }
@@ -323,7 +353,7 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
 
                     app.event_loop_tick();
                     app.window.pre_present_notify();
-                    app.graphics_driver.flush_and_submit();
+                    app.graphics_driver.flush_and_submit(&mut app.surface);
                 }
                 WindowEvent::MouseInput {
                     state: mouse_state,
@@ -377,6 +407,17 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                         );
                     }
                 }
+                WindowEvent::PinchGesture { delta, phase, .. } => {
+                    app.send_event(
+                        PlatformEvent::Pinch {
+                            name: PinchEventName::Pinch,
+                            cursor: self.cursor_pos,
+                            delta,
+                            phase: map_winit_touch_phase(phase),
+                        },
+                        scale_factor,
+                    );
+                }
                 WindowEvent::ModifiersChanged(modifiers) => {
                     self.modifiers_state = modifiers.state();
                 }
@@ -10,7 +10,10 @@ use freya_core::{
         FreyaDOM,
         SafeDOM,
     },
-    event_loop_messages::EventLoopMessage,
+    event_loop_messages::{
+        EventLoopMessage,
+        EventLoopMessageAction,
+    },
     plugins::{
         PluginEvent,
         PluginHandle,
@@ -161,7 +164,22 @@ impl RendererState {
             .scale((scale_factor as f32, scale_factor as f32));
         dirty_surface.canvas().clear(window_config.background);
 
-        let sdom = SafeDOM::new(FreyaDOM::default());
+        let mut fdom = FreyaDOM::default();
+        fdom.set_profiling(self.plugins.wants_frame_stats());
+        {
+            let mut images_cache = fdom.images_cache();
+            images_cache.set_budget(window_config.images_cache_budget);
+
+            // Render again once the images decoded in background are ready
+            let proxy = self.proxy.clone();
+            images_cache.decoder_mut().set_on_decoded(move || {
+                _ = proxy.send_event(EventLoopMessage {
+                    window_id: Some(id),
+                    action: EventLoopMessageAction::RequestRerender,
+                });
+            });
+        }
+        let sdom = SafeDOM::new(fdom);
         let vdom = VirtualDom::new_with_props(
             FreyaApp,
             FreyaAppProps {